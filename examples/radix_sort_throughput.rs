@@ -0,0 +1,46 @@
+//! Chapter 8 – Radix sort throughput on a million 32-bit keys.
+//!
+//! `radix_sort_inplace` makes one stable counting pass per byte of the
+//! largest key and reuses its scratch buffers across passes. We sort a
+//! million random `u32` values, compare the running time with the standard
+//! library's comparison sort, and check that both give the same order.
+//!
+//! Run with `cargo run --release --example radix_sort_throughput`.
+
+use std::time::{Duration, Instant};
+
+use clrs::chapter_08::radix_sort_inplace;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+const N: usize = 1_000_000;
+
+fn main() {
+    let mut rng = StdRng::seed_from_u64(0x5EED);
+    let input: Vec<usize> = (0..N).map(|_| rng.gen::<u32>() as usize).collect();
+
+    println!("Sorting {N} random u32 values\n");
+
+    let mut expected = input.clone();
+    let std_time = time(|| expected.sort_unstable());
+    println!("{:<22} {:>10.1?}", "slice::sort_unstable", std_time);
+
+    let mut arr = input.clone();
+    let radix_time = time(|| radix_sort_inplace(&mut arr));
+    assert_eq!(
+        arr, expected,
+        "radix sort must agree with the standard sort"
+    );
+    println!(
+        "{:<22} {:>10.1?}   {:.1} M keys/s",
+        "radix_sort_inplace",
+        radix_time,
+        N as f64 / radix_time.as_secs_f64() / 1e6
+    );
+}
+
+fn time(f: impl FnOnce()) -> Duration {
+    let start = Instant::now();
+    f();
+    start.elapsed()
+}
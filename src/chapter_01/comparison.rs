@@ -3,6 +3,10 @@
 //! This module provides utilities for comparing different algorithms
 //! and determining when one outperforms another.
 
+use crate::chapter_02::{insertion_sort_stats, merge_sort_stats};
+use crate::chapter_06::heapsort_stats;
+use crate::chapter_07::quicksort_stats;
+
 /// Compares two algorithms to find when one beats the other
 ///
 /// Solves problems like: "For which values of n does algorithm A beat algorithm B?"
@@ -136,6 +140,43 @@ pub fn polynomial_vs_exponential() -> Option<f64> {
     find_crossover_point(polynomial, exponential, 50.0)
 }
 
+/// Runs the instrumented sorts on the same input and tabulates their work
+///
+/// Insertion sort, merge sort, heapsort and quicksort each sort their own
+/// copy of `input`, giving a concrete version of the Section 1.2 comparison
+/// of insertion sort against merge sort. Each row is `(name, comparisons,
+/// swaps_or_moves)`, where the last column counts exchanges, except for
+/// merge sort, which copies elements and reports moves instead.
+///
+/// # Arguments
+/// * `input` - The array every algorithm sorts
+///
+/// # Returns
+/// One row per algorithm, in the order insertion, merge, heap, quick
+///
+/// # Example
+/// ```
+/// use clrs::chapter_01::compare_sorts;
+/// let rows = compare_sorts(&[3, 1, 2]);
+/// assert_eq!(rows[0], ("insertion".to_string(), 3, 2));
+/// assert_eq!(rows.len(), 4);
+/// ```
+pub fn compare_sorts(input: &[i64]) -> Vec<(String, u64, u64)> {
+    let compare = |a: &i64, b: &i64| a.cmp(b);
+    let runs = [
+        (
+            "insertion",
+            insertion_sort_stats(&mut input.to_vec(), compare),
+        ),
+        ("merge", merge_sort_stats(&mut input.to_vec(), compare)),
+        ("heap", heapsort_stats(&mut input.to_vec(), compare)),
+        ("quick", quicksort_stats(&mut input.to_vec(), compare)),
+    ];
+    runs.into_iter()
+        .map(|(name, stats)| (name.to_string(), stats.comparisons, stats.swaps))
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -180,4 +221,20 @@ mod tests {
         // n² is always faster than n³, so should return full range
         assert!(result.is_some());
     }
+
+    #[test]
+    fn test_compare_sorts_reverse_sorted() {
+        let n: u64 = 256;
+        let input: Vec<i64> = (0..n as i64).rev().collect();
+        let rows = compare_sorts(&input);
+        let names: Vec<&str> = rows.iter().map(|(name, _, _)| name.as_str()).collect();
+        assert_eq!(names, vec!["insertion", "merge", "heap", "quick"]);
+
+        let quadratic = n * (n - 1) / 2;
+        assert_eq!(rows[0].1, quadratic);
+        assert_eq!(rows[0].2, quadratic);
+        for (name, comparisons, _) in &rows[1..] {
+            assert!(comparisons * 4 < quadratic, "{name}: {comparisons}");
+        }
+    }
 }
@@ -5,7 +5,7 @@
 //! - Memory efficiency
 //! - Code complexity/maintainability
 
-use std::time::Instant;
+use std::time::{Duration, Instant};
 
 /// Performance measurement result
 #[derive(Debug, Clone)]
@@ -114,6 +114,122 @@ pub fn is_more_efficient(comparison: &EfficiencyComparison, threshold: f64) -> b
         && comparison.operations_ratio.is_none_or(|r| r < threshold)
 }
 
+/// Growth rate suggested by a series of timings
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GrowthClass {
+    /// Θ(n)
+    Linear,
+    /// Θ(n lg n)
+    Linearithmic,
+    /// Θ(n²)
+    Quadratic,
+}
+
+impl GrowthClass {
+    /// Evaluates the model function at `n`, up to a constant factor
+    fn model(self, n: f64) -> f64 {
+        match self {
+            GrowthClass::Linear => n,
+            GrowthClass::Linearithmic => n * n.log2(),
+            GrowthClass::Quadratic => n * n,
+        }
+    }
+}
+
+/// Times an operation at each of several input sizes
+///
+/// Calls `run(n)` once for every `n` in `sizes` and records how long it took,
+/// producing the samples that [`fit_growth`] classifies.
+///
+/// # Arguments
+/// * `sizes` - The input sizes to measure, in any order
+/// * `run` - The operation to time, given the input size
+///
+/// # Returns
+/// One `(size, elapsed time)` pair per entry of `sizes`, in the same order
+///
+/// # Example
+/// ```
+/// use clrs::chapter_01::empirical_growth;
+/// let samples = empirical_growth(&[10, 100], |n| {
+///     let v: Vec<usize> = (0..n).collect();
+///     assert_eq!(v.len(), n);
+/// });
+/// assert_eq!(samples.len(), 2);
+/// assert_eq!(samples[1].0, 100);
+/// ```
+pub fn empirical_growth<F: FnMut(usize)>(sizes: &[usize], mut run: F) -> Vec<(usize, Duration)> {
+    sizes
+        .iter()
+        .map(|&n| {
+            let start = Instant::now();
+            run(n);
+            (n, start.elapsed())
+        })
+        .collect()
+}
+
+/// Guesses the growth rate of a series of timings by ratio analysis
+///
+/// Between each pair of consecutive sizes n₁ < n₂ the observed ratio
+/// t(n₂)/t(n₁) is compared with the ratio g(n₂)/g(n₁) each candidate model g
+/// predicts. The model with the smallest total squared error in the
+/// logarithms of these ratios wins, so constant factors cancel out and only
+/// the shape of the curve matters. Sizes of at least 2 keep lg n positive.
+///
+/// # Arguments
+/// * `samples` - `(size, elapsed time)` pairs, e.g. from [`empirical_growth`]
+///
+/// # Returns
+/// The best-fitting of linear, n lg n and quadratic growth
+///
+/// # Panics
+/// Panics if `samples` contains fewer than two distinct sizes
+///
+/// # Example
+/// ```
+/// use std::time::Duration;
+/// use clrs::chapter_01::{fit_growth, GrowthClass};
+/// let samples: Vec<_> = [100, 200, 400]
+///     .iter()
+///     .map(|&n| (n, Duration::from_micros(3 * n as u64)))
+///     .collect();
+/// assert_eq!(fit_growth(&samples), GrowthClass::Linear);
+/// ```
+pub fn fit_growth(samples: &[(usize, Duration)]) -> GrowthClass {
+    let mut sorted = samples.to_vec();
+    sorted.sort_by_key(|&(n, _)| n);
+    sorted.dedup_by_key(|&mut (n, _)| n);
+    assert!(
+        sorted.len() >= 2,
+        "fit_growth needs samples at two or more distinct sizes"
+    );
+
+    // Zero durations would make the logarithms infinite.
+    let time = |d: Duration| (d.as_nanos() as f64).max(1.0);
+    let error = |class: GrowthClass| -> f64 {
+        sorted
+            .windows(2)
+            .map(|w| {
+                let (n1, t1) = (w[0].0 as f64, time(w[0].1));
+                let (n2, t2) = (w[1].0 as f64, time(w[1].1));
+                let observed = (t2 / t1).ln();
+                let predicted = (class.model(n2) / class.model(n1)).ln();
+                (observed - predicted).powi(2)
+            })
+            .sum()
+    };
+
+    [
+        GrowthClass::Linear,
+        GrowthClass::Linearithmic,
+        GrowthClass::Quadratic,
+    ]
+    .into_iter()
+    .min_by(|&a, &b| error(a).total_cmp(&error(b)))
+    .expect("there are candidate classes")
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -149,4 +265,43 @@ mod tests {
         assert!(comparison.memory_ratio.unwrap() < 1.0); // A uses less memory
         assert!(is_more_efficient(&comparison, 1.0));
     }
+
+    #[test]
+    fn test_fit_growth_classifies_synthetic_timings() {
+        let sizes = [64, 128, 256, 512, 1024, 2048];
+        let timings = |f: fn(f64) -> f64| -> Vec<(usize, Duration)> {
+            sizes
+                .iter()
+                .map(|&n| (n, Duration::from_nanos(f(n as f64) as u64)))
+                .collect()
+        };
+
+        let quadratic = timings(|n| 5.0 * n * n + 40.0 * n);
+        assert_eq!(fit_growth(&quadratic), GrowthClass::Quadratic);
+        let linearithmic = timings(|n| 20.0 * n * n.log2());
+        assert_eq!(fit_growth(&linearithmic), GrowthClass::Linearithmic);
+        let linear = timings(|n| 300.0 * n + 1000.0);
+        assert_eq!(fit_growth(&linear), GrowthClass::Linear);
+
+        // Order and repeated sizes do not matter.
+        let mut shuffled = quadratic.clone();
+        shuffled.reverse();
+        shuffled.push(quadratic[0]);
+        assert_eq!(fit_growth(&shuffled), GrowthClass::Quadratic);
+    }
+
+    #[test]
+    fn test_empirical_growth_runs_each_size() {
+        let mut seen = Vec::new();
+        let samples = empirical_growth(&[3, 1, 2], |n| seen.push(n));
+        assert_eq!(seen, vec![3, 1, 2]);
+        let sizes: Vec<usize> = samples.iter().map(|&(n, _)| n).collect();
+        assert_eq!(sizes, vec![3, 1, 2]);
+    }
+
+    #[test]
+    #[should_panic(expected = "two or more distinct sizes")]
+    fn test_fit_growth_needs_two_sizes() {
+        fit_growth(&[(10, Duration::from_nanos(5)), (10, Duration::from_nanos(6))]);
+    }
 }
@@ -125,6 +125,70 @@ pub fn binary_search<T: Ord>(arr: &[T], v: &T) -> Option<usize> {
     iterative_binary_search(arr, v, 0, arr.len() - 1)
 }
 
+/// Returns the first index whose element is not less than `key`
+///
+/// Returns `arr.len()` if every element is less than `key`.
+///
+/// # Example
+/// ```
+/// use clrs::chapter_02::lower_bound;
+/// let arr = vec![1, 2, 2, 2, 3];
+/// assert_eq!(lower_bound(&arr, &2), 1);
+/// assert_eq!(lower_bound(&arr, &4), 5);
+/// ```
+///
+/// # Complexity
+/// - Time: O(log n)
+/// - Space: O(1)
+pub fn lower_bound<T: Ord>(arr: &[T], key: &T) -> usize {
+    let mut low = 0;
+    let mut high = arr.len();
+
+    while low < high {
+        let mid = low + (high - low) / 2;
+        if arr[mid] < *key {
+            low = mid + 1;
+        } else {
+            high = mid;
+        }
+    }
+
+    low
+}
+
+/// Returns the first index whose element is greater than `key`
+///
+/// Returns `arr.len()` if no element is greater than `key`. Together with
+/// [`lower_bound`], `upper_bound(arr, key) - lower_bound(arr, key)` is the
+/// number of occurrences of `key`.
+///
+/// # Example
+/// ```
+/// use clrs::chapter_02::{lower_bound, upper_bound};
+/// let arr = vec![1, 2, 2, 2, 3];
+/// assert_eq!(upper_bound(&arr, &2), 4);
+/// assert_eq!(upper_bound(&arr, &2) - lower_bound(&arr, &2), 3);
+/// ```
+///
+/// # Complexity
+/// - Time: O(log n)
+/// - Space: O(1)
+pub fn upper_bound<T: Ord>(arr: &[T], key: &T) -> usize {
+    let mut low = 0;
+    let mut high = arr.len();
+
+    while low < high {
+        let mid = low + (high - low) / 2;
+        if arr[mid] <= *key {
+            low = mid + 1;
+        } else {
+            high = mid;
+        }
+    }
+
+    low
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -166,4 +230,30 @@ mod tests {
         assert_eq!(binary_search(&arr, &7), Some(3));
         assert_eq!(binary_search(&arr, &10), None);
     }
+
+    #[test]
+    fn test_lower_upper_bound_duplicates() {
+        let arr = vec![1, 2, 2, 2, 3];
+        assert_eq!(lower_bound(&arr, &2), 1);
+        assert_eq!(upper_bound(&arr, &2), 4);
+        assert_eq!(upper_bound(&arr, &2) - lower_bound(&arr, &2), 3);
+
+        assert_eq!(lower_bound(&arr, &1), 0);
+        assert_eq!(upper_bound(&arr, &1), 1);
+        assert_eq!(lower_bound(&arr, &3), 4);
+        assert_eq!(upper_bound(&arr, &3), 5);
+    }
+
+    #[test]
+    fn test_lower_upper_bound_absent_keys() {
+        let arr = vec![1, 2, 2, 2, 3];
+        assert_eq!(lower_bound(&arr, &0), 0);
+        assert_eq!(upper_bound(&arr, &0), 0);
+        assert_eq!(lower_bound(&arr, &4), 5);
+        assert_eq!(upper_bound(&arr, &4), 5);
+
+        let empty: Vec<i32> = vec![];
+        assert_eq!(lower_bound(&empty, &1), 0);
+        assert_eq!(upper_bound(&empty, &1), 0);
+    }
 }
@@ -54,6 +54,46 @@ pub fn insertion_sort<T: Ord + Clone>(arr: &mut [T]) {
     }
 }
 
+/// Sorts the subarray `arr[lo..=hi]` using insertion sort
+///
+/// INSERTION-SORT restricted to a sub-range, for hybrid sorts that finish
+/// small partitions with insertion sort. Elements outside `lo..=hi` are not
+/// touched. Elements are moved by swapping, so `T` need not be `Clone`.
+///
+/// # Arguments
+/// * `arr` - The array containing the range (modified in-place)
+/// * `lo` - Start index of the range (0-based)
+/// * `hi` - End index of the range (0-based, inclusive)
+///
+/// # Panics
+/// Panics if `hi >= arr.len()` while `lo <= hi`.
+///
+/// # Example
+/// ```
+/// use clrs::chapter_02::insertion_sort_range;
+/// let mut arr = vec![9, 5, 2, 4, 0];
+/// insertion_sort_range(&mut arr, 1, 3);
+/// assert_eq!(arr, vec![9, 2, 4, 5, 0]);
+/// ```
+///
+/// # Complexity
+/// - Time: O(k²) where k = hi - lo + 1
+/// - Space: O(1)
+pub fn insertion_sort_range<T: Ord>(arr: &mut [T], lo: usize, hi: usize) {
+    if lo >= hi {
+        return;
+    }
+    assert!(hi < arr.len(), "range end {} out of bounds", hi);
+
+    for j in lo + 1..=hi {
+        let mut i = j;
+        while i > lo && arr[i - 1] > arr[i] {
+            arr.swap(i - 1, i);
+            i -= 1;
+        }
+    }
+}
+
 /// Sorts an array using insertion sort (nonincreasing order)
 ///
 /// This corresponds to Exercise 2.1-2, rewriting INSERTION-SORT
@@ -135,4 +175,19 @@ mod tests {
         insertion_sort_decreasing(&mut arr);
         assert_eq!(arr, vec![59, 58, 41, 41, 31, 26]);
     }
+
+    #[test]
+    fn test_insertion_sort_range() {
+        let mut arr = vec![9, 8, 7, 6, 5, 4, 3, 2, 1, 0];
+        insertion_sort_range(&mut arr, 2, 6);
+        assert_eq!(arr, vec![9, 8, 3, 4, 5, 6, 7, 2, 1, 0]);
+
+        // Degenerate ranges leave the array unchanged.
+        insertion_sort_range(&mut arr, 4, 4);
+        insertion_sort_range(&mut arr, 5, 3);
+        assert_eq!(arr, vec![9, 8, 3, 4, 5, 6, 7, 2, 1, 0]);
+
+        insertion_sort_range(&mut arr, 0, 9);
+        assert_eq!(arr, (0..10).collect::<Vec<_>>());
+    }
 }
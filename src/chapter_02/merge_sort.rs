@@ -126,9 +126,45 @@ pub fn merge_sort_full<T: Ord + Clone>(arr: &mut [T]) {
     merge_sort(arr, 0, arr.len() - 1);
 }
 
+/// Sorts an array using bottom-up (iterative) merge sort
+///
+/// Instead of recursing, this merges adjacent runs of width 1, 2, 4, ...
+/// until a single run covers the whole array. The last run of each pass may
+/// be shorter than `width` when the length is not a power of two. Because
+/// it reuses [`merge`], the sort is stable.
+///
+/// # Example
+/// ```
+/// use clrs::chapter_02::merge_sort_bottom_up;
+/// let mut arr = vec![3, 41, 52, 26, 38, 57, 9];
+/// merge_sort_bottom_up(&mut arr);
+/// assert_eq!(arr, vec![3, 9, 26, 38, 41, 52, 57]);
+/// ```
+///
+/// # Complexity
+/// - Time: O(n log n) for all cases
+/// - Space: O(n)
+pub fn merge_sort_bottom_up<T: Ord + Clone>(arr: &mut [T]) {
+    let n = arr.len();
+    let mut width = 1;
+
+    while width < n {
+        let mut p = 0;
+        // Merge A[p..p+width-1] with A[p+width..p+2*width-1], clamped to n
+        while p + width < n {
+            let q = p + width - 1;
+            let r = (p + 2 * width - 1).min(n - 1);
+            merge(arr, p, q, r);
+            p += 2 * width;
+        }
+        width *= 2;
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use rand::Rng;
 
     #[test]
     fn test_merge_sort_empty() {
@@ -171,4 +207,53 @@ mod tests {
         merge(&mut arr, 0, 2, 5);
         assert_eq!(arr, vec![1, 2, 3, 4, 5, 6]);
     }
+
+    #[test]
+    fn test_merge_sort_bottom_up_matches_recursive() {
+        let mut rng = rand::thread_rng();
+        for len in [0, 1, 2, 3, 7, 8, 15, 16, 17, 31, 64, 100, 128, 1000] {
+            let original: Vec<i32> = (0..len).map(|_| rng.gen_range(-50..50)).collect();
+
+            let mut expected = original.clone();
+            merge_sort_full(&mut expected);
+
+            let mut actual = original;
+            merge_sort_bottom_up(&mut actual);
+
+            assert_eq!(actual, expected, "length {}", len);
+        }
+    }
+
+    #[test]
+    fn test_merge_sort_bottom_up_is_stable() {
+        #[derive(Debug, Clone, PartialEq, Eq)]
+        struct Item {
+            key: u8,
+            tag: usize,
+        }
+
+        impl PartialOrd for Item {
+            fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+
+        impl Ord for Item {
+            fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+                self.key.cmp(&other.key)
+            }
+        }
+
+        let keys = [3, 1, 2, 3, 1, 2, 3, 1, 2, 0, 3];
+        let mut items: Vec<Item> = keys
+            .iter()
+            .enumerate()
+            .map(|(tag, &key)| Item { key, tag })
+            .collect();
+        merge_sort_bottom_up(&mut items);
+
+        for w in items.windows(2) {
+            assert!(w[0].key < w[1].key || (w[0].key == w[1].key && w[0].tag < w[1].tag));
+        }
+    }
 }
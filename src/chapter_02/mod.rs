@@ -8,9 +8,11 @@ pub mod insertion_sort;
 pub mod linear_search;
 pub mod merge_sort;
 pub mod selection_sort;
+pub mod sort_stats;
 
 pub use binary_search::*;
 pub use insertion_sort::*;
 pub use linear_search::*;
 pub use merge_sort::*;
 pub use selection_sort::*;
+pub use sort_stats::*;
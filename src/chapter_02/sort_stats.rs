@@ -0,0 +1,268 @@
+//! Instrumented Sorting (Sections 2.1-2.3)
+//!
+//! Variants of insertion sort, selection sort and merge sort that sort by a caller-supplied
+//! comparison closure and report how much work they did, so the elementary
+//! sorts can be compared side by side on the same input.
+
+use std::cmp::Ordering;
+
+/// Operation counts reported by an instrumented sort
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct SortStats {
+    /// Number of calls made to the comparison closure
+    pub comparisons: u64,
+    /// Number of element exchanges performed, or element moves for sorts
+    /// such as merge sort that copy rather than exchange
+    pub swaps: u64,
+    /// Whether the algorithm preserves the relative order of equal elements
+    pub stable: bool,
+}
+
+/// Sorts a slice with insertion sort using `compare`, counting operations
+///
+/// Each one-position move of the key is performed as a swap, so `swaps`
+/// equals the number of inversions in the input. Insertion sort is stable.
+///
+/// # Arguments
+/// * `arr` - The slice to sort in-place
+/// * `compare` - Comparison closure defining the order, as in `slice::sort_by`
+///
+/// # Example
+/// ```
+/// use clrs::chapter_02::insertion_sort_stats;
+/// let mut arr = vec![3, 1, 2];
+/// let stats = insertion_sort_stats(&mut arr, |a, b| a.cmp(b));
+/// assert_eq!(arr, vec![1, 2, 3]);
+/// assert_eq!(stats.swaps, 2);
+/// assert!(stats.stable);
+/// ```
+///
+/// # Complexity
+/// - Time: O(n²) worst case, O(n) best case (already sorted)
+/// - Space: O(1)
+pub fn insertion_sort_stats<T, F>(arr: &mut [T], mut compare: F) -> SortStats
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    let mut stats = SortStats {
+        stable: true,
+        ..SortStats::default()
+    };
+
+    for j in 1..arr.len() {
+        let mut i = j;
+        while i > 0 {
+            stats.comparisons += 1;
+            if compare(&arr[i - 1], &arr[i]) != Ordering::Greater {
+                break;
+            }
+            arr.swap(i - 1, i);
+            stats.swaps += 1;
+            i -= 1;
+        }
+    }
+
+    stats
+}
+
+/// Sorts a slice with selection sort using `compare`, counting operations
+///
+/// Always performs n(n-1)/2 comparisons. Only exchanges that move an element
+/// (`min_index != i`) are counted as swaps. The long-distance exchanges make
+/// selection sort unstable.
+///
+/// # Arguments
+/// * `arr` - The slice to sort in-place
+/// * `compare` - Comparison closure defining the order, as in `slice::sort_by`
+///
+/// # Example
+/// ```
+/// use clrs::chapter_02::selection_sort_stats;
+/// let mut arr = vec![3, 1, 2];
+/// let stats = selection_sort_stats(&mut arr, |a, b| a.cmp(b));
+/// assert_eq!(arr, vec![1, 2, 3]);
+/// assert_eq!(stats.comparisons, 3);
+/// assert!(!stats.stable);
+/// ```
+///
+/// # Complexity
+/// - Time: Θ(n²) for all cases
+/// - Space: O(1)
+pub fn selection_sort_stats<T, F>(arr: &mut [T], mut compare: F) -> SortStats
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    let mut stats = SortStats {
+        stable: false,
+        ..SortStats::default()
+    };
+    let n = arr.len();
+
+    for i in 0..n.saturating_sub(1) {
+        let mut min_index = i;
+        for j in (i + 1)..n {
+            stats.comparisons += 1;
+            if compare(&arr[j], &arr[min_index]) == Ordering::Less {
+                min_index = j;
+            }
+        }
+        if min_index != i {
+            arr.swap(i, min_index);
+            stats.swaps += 1;
+        }
+    }
+
+    stats
+}
+
+/// Sorts a slice with merge sort using `compare`, counting operations
+///
+/// Merge sort copies elements instead of exchanging them, so `swaps` counts
+/// the elements written back into `arr` by MERGE: n per level of recursion,
+/// or about n lg n in total. Ties are taken from the left half, which keeps
+/// merge sort stable.
+///
+/// # Arguments
+/// * `arr` - The slice to sort in-place
+/// * `compare` - Comparison closure defining the order, as in `slice::sort_by`
+///
+/// # Example
+/// ```
+/// use clrs::chapter_02::merge_sort_stats;
+/// let mut arr = vec![4, 3, 2, 1];
+/// let stats = merge_sort_stats(&mut arr, |a, b| a.cmp(b));
+/// assert_eq!(arr, vec![1, 2, 3, 4]);
+/// assert_eq!(stats.comparisons, 4);
+/// assert_eq!(stats.swaps, 8);
+/// assert!(stats.stable);
+/// ```
+///
+/// # Complexity
+/// - Time: Θ(n lg n) for all cases
+/// - Space: Θ(n)
+pub fn merge_sort_stats<T, F>(arr: &mut [T], mut compare: F) -> SortStats
+where
+    T: Clone,
+    F: FnMut(&T, &T) -> Ordering,
+{
+    let mut stats = SortStats {
+        stable: true,
+        ..SortStats::default()
+    };
+    merge_sort_counting(arr, &mut compare, &mut stats);
+    stats
+}
+
+fn merge_sort_counting<T, F>(arr: &mut [T], compare: &mut F, stats: &mut SortStats)
+where
+    T: Clone,
+    F: FnMut(&T, &T) -> Ordering,
+{
+    if arr.len() <= 1 {
+        return;
+    }
+    let mid = arr.len() / 2;
+    merge_sort_counting(&mut arr[..mid], compare, stats);
+    merge_sort_counting(&mut arr[mid..], compare, stats);
+
+    let left = arr[..mid].to_vec();
+    let right = arr[mid..].to_vec();
+    let (mut i, mut j) = (0, 0);
+    for slot in arr.iter_mut() {
+        let take_left = if i == left.len() {
+            false
+        } else if j == right.len() {
+            true
+        } else {
+            stats.comparisons += 1;
+            compare(&right[j], &left[i]) != Ordering::Less
+        };
+        if take_left {
+            *slot = left[i].clone();
+            i += 1;
+        } else {
+            *slot = right[j].clone();
+            j += 1;
+        }
+        stats.swaps += 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_insertion_sort_stats_formulas() {
+        let n: u64 = 10;
+
+        // Already sorted: one comparison per key, no swaps
+        let mut sorted: Vec<u64> = (0..n).collect();
+        let stats = insertion_sort_stats(&mut sorted, |a, b| a.cmp(b));
+        assert_eq!(stats.comparisons, n - 1);
+        assert_eq!(stats.swaps, 0);
+        assert!(stats.stable);
+
+        // Reverse sorted: every pair is an inversion
+        let mut reversed: Vec<u64> = (0..n).rev().collect();
+        let stats = insertion_sort_stats(&mut reversed, |a, b| a.cmp(b));
+        assert_eq!(reversed, (0..n).collect::<Vec<_>>());
+        assert_eq!(stats.comparisons, n * (n - 1) / 2);
+        assert_eq!(stats.swaps, n * (n - 1) / 2);
+    }
+
+    #[test]
+    fn test_selection_sort_stats_formulas() {
+        let n: u64 = 10;
+        for input in [(0..n).collect::<Vec<_>>(), (0..n).rev().collect::<Vec<_>>()] {
+            let mut arr = input;
+            let stats = selection_sort_stats(&mut arr, |a, b| a.cmp(b));
+            assert_eq!(arr, (0..n).collect::<Vec<_>>());
+            assert_eq!(stats.comparisons, n * (n - 1) / 2);
+            assert!(stats.swaps < n);
+            assert!(!stats.stable);
+        }
+    }
+
+    #[test]
+    fn test_stability_flags_match_behaviour() {
+        // Sort (key, tag) pairs by key only
+        let input = vec![(2, 'a'), (2, 'b'), (1, 'c')];
+
+        let mut by_insertion = input.clone();
+        let stats = insertion_sort_stats(&mut by_insertion, |a, b| a.0.cmp(&b.0));
+        assert!(stats.stable);
+        assert_eq!(by_insertion, vec![(1, 'c'), (2, 'a'), (2, 'b')]);
+
+        let mut by_selection = input;
+        let stats = selection_sort_stats(&mut by_selection, |a, b| a.0.cmp(&b.0));
+        assert!(!stats.stable);
+        // The first exchange carries (2, 'a') past (2, 'b')
+        assert_eq!(by_selection, vec![(1, 'c'), (2, 'b'), (2, 'a')]);
+    }
+
+    #[test]
+    fn test_merge_sort_stats() {
+        let n: u64 = 16;
+        for input in [(0..n).collect::<Vec<_>>(), (0..n).rev().collect::<Vec<_>>()] {
+            let mut arr = input;
+            let stats = merge_sort_stats(&mut arr, |a, b| a.cmp(b));
+            assert_eq!(arr, (0..n).collect::<Vec<_>>());
+            // Sorted halves exhaust one side after n/2 comparisons per merge
+            assert_eq!(stats.comparisons, n / 2 * 4);
+            assert_eq!(stats.swaps, n * 4);
+        }
+
+        let mut pairs = vec![(2, 'a'), (1, 'b'), (2, 'c'), (1, 'd')];
+        let stats = merge_sort_stats(&mut pairs, |a, b| a.0.cmp(&b.0));
+        assert!(stats.stable);
+        assert_eq!(pairs, vec![(1, 'b'), (1, 'd'), (2, 'a'), (2, 'c')]);
+    }
+
+    #[test]
+    fn test_stats_closure_order() {
+        let mut arr = vec![1, 5, 3, 4];
+        insertion_sort_stats(&mut arr, |a, b| b.cmp(a));
+        assert_eq!(arr, vec![5, 4, 3, 1]);
+    }
+}
@@ -136,7 +136,49 @@ fn add_matrices(
 /// # Complexity
 /// - Time: O(n^lg 7) ≈ O(n^2.81)
 /// - Space: O(n²)
+///
+/// In debug builds every subproblem of size at most
+/// `STRASSEN_CHECK_SIZE` is also multiplied with the standard algorithm and
+/// the two products are asserted equal, so a mistake in the S, P or C
+/// formulas panics on the smallest input that exposes it. The check is
+/// compiled out of release builds.
 pub fn strassen_matrix_multiply(a: &[Vec<i64>], b: &[Vec<i64>]) -> Vec<Vec<i64>> {
+    strassen_with(a, b, clrs_quadrants)
+}
+
+/// Largest subproblem size cross-checked against the standard product in
+/// debug builds.
+const STRASSEN_CHECK_SIZE: usize = 8;
+
+/// The four quadrants C11, C12, C21 and C22 of a product.
+type Quadrants = [Vec<Vec<i64>>; 4];
+
+/// Combines the products P1 through P7 into the quadrants of C, as in the
+/// last step of STRASSEN.
+fn clrs_quadrants(p: &[Vec<Vec<i64>>; 7]) -> Quadrants {
+    let [p1, p2, p3, p4, p5, p6, p7] = p;
+
+    // CLRS: C11 = P5 + P4 - P2 + P6
+    let c11 = add_matrices_full(&add_matrices_full(p5, p4), &subtract_matrices(p6, p2));
+
+    // CLRS: C12 = P1 + P2
+    let c12 = add_matrices_full(p1, p2);
+
+    // CLRS: C21 = P3 + P4
+    let c21 = add_matrices_full(p3, p4);
+
+    // CLRS: C22 = P5 + P1 - P3 - P7
+    let c22 = subtract_matrices(&subtract_matrices(&add_matrices_full(p5, p1), p3), p7);
+
+    [c11, c12, c21, c22]
+}
+
+/// Runs STRASSEN with `combine` computing C's quadrants from P1 through P7.
+fn strassen_with(
+    a: &[Vec<i64>],
+    b: &[Vec<i64>],
+    combine: fn(&[Vec<Vec<i64>>; 7]) -> Quadrants,
+) -> Vec<Vec<i64>> {
     let n = a.len();
 
     // Validate input
@@ -194,35 +236,32 @@ pub fn strassen_matrix_multiply(a: &[Vec<i64>], b: &[Vec<i64>]) -> Vec<Vec<i64>>
 
     // Compute the 7 products P1 through P7
     // CLRS: P1 = STRASSEN(A11, S1)
-    let p1 = strassen_matrix_multiply(&a11, &s1);
+    let p1 = strassen_with(&a11, &s1, combine);
     // CLRS: P2 = STRASSEN(S2, B22)
-    let p2 = strassen_matrix_multiply(&s2, &b22);
+    let p2 = strassen_with(&s2, &b22, combine);
     // CLRS: P3 = STRASSEN(S3, B11)
-    let p3 = strassen_matrix_multiply(&s3, &b11);
+    let p3 = strassen_with(&s3, &b11, combine);
     // CLRS: P4 = STRASSEN(A22, S4)
-    let p4 = strassen_matrix_multiply(&a22, &s4);
+    let p4 = strassen_with(&a22, &s4, combine);
     // CLRS: P5 = STRASSEN(S5, S6)
-    let p5 = strassen_matrix_multiply(&s5, &s6);
+    let p5 = strassen_with(&s5, &s6, combine);
     // CLRS: P6 = STRASSEN(S7, S8)
-    let p6 = strassen_matrix_multiply(&s7, &s8);
+    let p6 = strassen_with(&s7, &s8, combine);
     // CLRS: P7 = STRASSEN(S9, S10)
-    let p7 = strassen_matrix_multiply(&s9, &s10);
-
-    // Compute the four quadrants of C
-    // CLRS: C11 = P5 + P4 - P2 + P6
-    let c11 = add_matrices_full(&add_matrices_full(&p5, &p4), &subtract_matrices(&p6, &p2));
-
-    // CLRS: C12 = P1 + P2
-    let c12 = add_matrices_full(&p1, &p2);
-
-    // CLRS: C21 = P3 + P4
-    let c21 = add_matrices_full(&p3, &p4);
-
-    // CLRS: C22 = P5 + P1 - P3 - P7
-    let c22 = subtract_matrices(&subtract_matrices(&add_matrices_full(&p5, &p1), &p3), &p7);
-
-    // Combine the four quadrants
-    combine_matrices(&c11, &c12, &c21, &c22, n)
+    let p7 = strassen_with(&s9, &s10, combine);
+
+    // Compute the four quadrants of C and combine them
+    let [c11, c12, c21, c22] = combine(&[p1, p2, p3, p4, p5, p6, p7]);
+    let c = combine_matrices(&c11, &c12, &c21, &c22, n);
+
+    if cfg!(debug_assertions) && n <= STRASSEN_CHECK_SIZE {
+        assert_eq!(
+            c,
+            standard_matrix_multiply(a, b),
+            "Strassen product disagrees with the standard product for n = {n}"
+        );
+    }
+    c
 }
 
 /// Extracts a submatrix from a matrix
@@ -329,9 +368,79 @@ pub fn standard_matrix_multiply(a: &[Vec<i64>], b: &[Vec<i64>]) -> Vec<Vec<i64>>
     c
 }
 
+/// Tiled iterative matrix multiplication
+///
+/// Computes the same product as [`standard_matrix_multiply`], but splits the
+/// i, k and j ranges into `block_size`-wide strips and multiplies one
+/// `block_size × block_size` tile of A by one tile of B at a time. Within a
+/// tile the loops run in i-k-j order, so the innermost loop walks rows of B
+/// and C contiguously. A tile triple that fits in cache is reused
+/// `block_size` times before it is evicted, which the naive i-j-k order
+/// (striding down a column of B) does not achieve.
+///
+/// # Arguments
+/// * `a` - First matrix (n×m)
+/// * `b` - Second matrix (m×p)
+/// * `block_size` - Side length of the tiles
+///
+/// # Returns
+/// Resulting n×p matrix C = A × B
+///
+/// # Panics
+/// Panics if `block_size` is 0 or if the number of columns in A doesn't match
+/// the number of rows in B.
+///
+/// # Complexity
+/// - Time: O(nmp) = O(n³) for square matrices
+/// - Space: O(np)
+///
+/// # Example
+/// ```
+/// use clrs::chapter_04::{blocked_matrix_multiply, standard_matrix_multiply};
+/// let a = vec![vec![1, 3, 2], vec![7, 5, 0], vec![4, 1, 6]];
+/// let b = vec![vec![6, 8, 1], vec![4, 2, 0], vec![3, 9, 5]];
+/// assert_eq!(blocked_matrix_multiply(&a, &b, 2), standard_matrix_multiply(&a, &b));
+/// ```
+pub fn blocked_matrix_multiply(a: &[Vec<i64>], b: &[Vec<i64>], block_size: usize) -> Vec<Vec<i64>> {
+    assert!(block_size > 0, "block_size must be positive");
+    let n = a.len();
+    let m = a[0].len();
+    let p = b[0].len();
+
+    assert_eq!(
+        m,
+        b.len(),
+        "Number of columns in A must equal number of rows in B"
+    );
+
+    let mut c = vec![vec![0; p]; n];
+
+    for ii in (0..n).step_by(block_size) {
+        let i_end = (ii + block_size).min(n);
+        for kk in (0..m).step_by(block_size) {
+            let k_end = (kk + block_size).min(m);
+            for jj in (0..p).step_by(block_size) {
+                let j_end = (jj + block_size).min(p);
+                for i in ii..i_end {
+                    let c_row = &mut c[i][jj..j_end];
+                    for k in kk..k_end {
+                        let a_ik = a[i][k];
+                        for (c_ij, &b_kj) in c_row.iter_mut().zip(&b[k][jj..j_end]) {
+                            *c_ij += a_ik * b_kj;
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    c
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use rand::Rng;
 
     #[test]
     fn test_standard_matrix_multiply() {
@@ -418,4 +527,68 @@ mod tests {
         let c = strassen_matrix_multiply(&a, &b);
         assert_eq!(c, vec![vec![35]]);
     }
+
+    /// The C11 formula with the sign of P2 flipped, as if
+    /// `subtract_matrices(p6, p2)` had been typed `add_matrices_full(p6, p2)`.
+    #[cfg(debug_assertions)]
+    fn patched_quadrants(p: &[Vec<Vec<i64>>; 7]) -> Quadrants {
+        let [mut c11, c12, c21, c22] = clrs_quadrants(p);
+        c11 = add_matrices_full(&c11, &add_matrices_full(&p[1], &p[1]));
+        [c11, c12, c21, c22]
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "disagrees with the standard product for n = 2")]
+    fn test_strassen_debug_check_catches_bad_formula() {
+        let a = vec![vec![1, 3], vec![7, 5]];
+        let b = vec![vec![6, 8], vec![4, 2]];
+        let _ = strassen_with(&a, &b, patched_quadrants);
+    }
+
+    #[test]
+    fn test_strassen_matches_standard_on_random_16x16() {
+        let mut rng = rand::thread_rng();
+        let a: Vec<Vec<i64>> = (0..16)
+            .map(|_| (0..16).map(|_| rng.gen_range(-100..100)).collect())
+            .collect();
+        let b: Vec<Vec<i64>> = (0..16)
+            .map(|_| (0..16).map(|_| rng.gen_range(-100..100)).collect())
+            .collect();
+        // Sizes above the debug check are compared here instead.
+        assert_eq!(
+            strassen_matrix_multiply(&a, &b),
+            standard_matrix_multiply(&a, &b)
+        );
+    }
+
+    #[test]
+    fn test_blocked_matches_standard() {
+        let mut rng = rand::thread_rng();
+        let a: Vec<Vec<i64>> = (0..64)
+            .map(|_| (0..64).map(|_| rng.gen_range(-100..100)).collect())
+            .collect();
+        let b: Vec<Vec<i64>> = (0..64)
+            .map(|_| (0..64).map(|_| rng.gen_range(-100..100)).collect())
+            .collect();
+        let expected = standard_matrix_multiply(&a, &b);
+        // Block sizes that divide 64, that do not, and that exceed it.
+        for block_size in [1, 7, 16, 64, 100] {
+            assert_eq!(blocked_matrix_multiply(&a, &b, block_size), expected);
+        }
+
+        // Rectangular operands leave partial tiles along every dimension.
+        let a = vec![vec![1, 2, 3, 4, 5], vec![6, 7, 8, 9, 10]];
+        let b = vec![
+            vec![1, -1, 2],
+            vec![0, 3, 1],
+            vec![2, 2, 2],
+            vec![-4, 0, 1],
+            vec![1, 1, 1],
+        ];
+        assert_eq!(
+            blocked_matrix_multiply(&a, &b, 2),
+            standard_matrix_multiply(&a, &b)
+        );
+    }
 }
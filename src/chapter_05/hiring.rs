@@ -2,7 +2,8 @@
 //!
 //! The hiring problem models the process of interviewing and hiring candidates.
 //! We want to hire the best candidate, which requires interviewing all candidates
-//! and keeping track of the best seen so far.
+//! and keeping track of the best seen so far. The online variant of
+//! Section 5.4.4 instead hires exactly once, immediately after an interview.
 
 /// Result of the hiring process
 #[derive(Debug, Clone, PartialEq)]
@@ -95,9 +96,65 @@ pub fn expected_hires(n: usize) -> f64 {
     sum
 }
 
+/// Hires once under the online strategy of CLRS Section 5.4.4
+///
+/// This corresponds to ONLINE-MAXIMUM(k, n): the first `k` candidates are
+/// interviewed and rejected to establish a benchmark score, and the first
+/// later candidate who beats all of them is hired. CLRS hires the last
+/// candidate when nobody qualifies; here that outcome is reported as `None`.
+///
+/// # Arguments
+/// * `scores` - Candidate scores in interview order (higher is better)
+/// * `k` - Number of candidates to reject before hiring becomes possible
+///
+/// # Returns
+/// The index of the hired candidate, or `None` if no candidate after the
+/// first `k` beats them all
+///
+/// # Complexity
+/// - Time: O(n)
+/// - Space: O(1)
+///
+/// # Example
+/// ```
+/// use clrs::chapter_05::online_hiring;
+/// let scores = [3, 7, 2, 5, 9, 8];
+/// assert_eq!(online_hiring(&scores, 2), Some(4));
+/// assert_eq!(online_hiring(&scores, 5), None);
+/// ```
+pub fn online_hiring(scores: &[u64], k: usize) -> Option<usize> {
+    let k = k.min(scores.len());
+    let benchmark = scores[..k].iter().max();
+    scores[k..]
+        .iter()
+        .position(|score| benchmark.is_none_or(|best| score > best))
+        .map(|i| k + i)
+}
+
+/// Returns the sample size k = ⌊n/e⌋ for [`online_hiring`]
+///
+/// CLRS Section 5.4.4 shows that rejecting the first n/e candidates hires
+/// the best candidate with probability at least 1/e, which is asymptotically
+/// optimal.
+///
+/// # Arguments
+/// * `n` - Number of candidates
+///
+/// # Example
+/// ```
+/// use clrs::chapter_05::best_k_for;
+/// assert_eq!(best_k_for(100), 36);
+/// ```
+pub fn best_k_for(n: usize) -> usize {
+    (n as f64 / std::f64::consts::E).floor() as usize
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use rand::rngs::StdRng;
+    use rand::seq::SliceRandom;
+    use rand::SeedableRng;
 
     #[test]
     fn test_hire_assistant_increasing_order() {
@@ -156,4 +213,36 @@ mod tests {
         let e10 = expected_hires(10);
         assert!(e10 > 2.5 && e10 < 3.5);
     }
+
+    #[test]
+    fn test_online_hiring() {
+        assert_eq!(online_hiring(&[], 0), None);
+        assert_eq!(online_hiring(&[4, 1, 3], 0), Some(0));
+        assert_eq!(online_hiring(&[4, 1, 3], 1), None);
+        assert_eq!(online_hiring(&[1, 4, 3], 1), Some(1));
+        assert_eq!(online_hiring(&[1, 4, 3], 10), None);
+        assert_eq!(best_k_for(0), 0);
+        assert_eq!(best_k_for(10), 3);
+    }
+
+    #[test]
+    fn test_online_hiring_finds_best_about_one_time_in_e() {
+        let n = 50;
+        let k = best_k_for(n);
+        let trials = 20_000;
+        let mut rng = StdRng::seed_from_u64(5);
+        let mut scores: Vec<u64> = (1..=n as u64).collect();
+
+        let mut successes = 0;
+        for _ in 0..trials {
+            scores.shuffle(&mut rng);
+            if online_hiring(&scores, k).is_some_and(|i| scores[i] == n as u64) {
+                successes += 1;
+            }
+        }
+
+        let rate = successes as f64 / trials as f64;
+        let target = 1.0 / std::f64::consts::E;
+        assert!((rate - target).abs() < 0.03, "success rate {rate}");
+    }
 }
@@ -4,7 +4,8 @@
 //! including RANDOMIZE-IN-PLACE (Fisher-Yates shuffle), PERMUTE-BY-SORTING,
 //! and RANDOM-SAMPLE.
 
-use rand::Rng;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
 
 /// Randomizes an array in place using the Fisher-Yates shuffle
 ///
@@ -175,6 +176,143 @@ pub fn random_sample_alternative(m: usize, n: usize) -> Vec<usize> {
     arr
 }
 
+/// Selects k elements uniformly at random from a stream of unknown length
+///
+/// This is reservoir sampling (Algorithm R). The first k elements fill the
+/// reservoir; after that, the i-th element (1-based) replaces a uniformly
+/// chosen reservoir slot with probability k/i. By induction on i, once n
+/// elements have been seen each of them is in the reservoir with probability
+/// k/n, and every k-subset is equally likely.
+///
+/// # Arguments
+/// * `iter` - The stream to sample from, consumed once
+/// * `k` - Size of the sample
+/// * `seed` - Seed for the random number generator, so that a seed always
+///   selects the same sample from the same stream
+///
+/// # Returns
+/// The sampled elements, or the whole stream if it has fewer than k elements.
+/// The order of the sample is not random.
+///
+/// # Example
+/// ```
+/// use clrs::chapter_05::reservoir_sample;
+/// let sample = reservoir_sample(1..=100, 5, 42);
+/// assert_eq!(sample.len(), 5);
+/// assert_eq!(sample, reservoir_sample(1..=100, 5, 42));
+/// assert_eq!(reservoir_sample(1..=3, 5, 42), vec![1, 2, 3]);
+/// ```
+///
+/// # Complexity
+/// - Time: O(n) - one call to RANDOM per element after the first k
+/// - Space: O(k)
+pub fn reservoir_sample<T: Clone, I: Iterator<Item = T>>(iter: I, k: usize, seed: u64) -> Vec<T> {
+    let mut rng = StdRng::seed_from_u64(seed);
+    // `k` may far exceed the stream, so reserve only what it promises.
+    let mut reservoir = Vec::with_capacity(k.min(iter.size_hint().0));
+
+    for (i, item) in iter.enumerate() {
+        if i < k {
+            reservoir.push(item);
+        } else {
+            let j = rng.gen_range(0..=i);
+            if j < k {
+                reservoir[j] = item;
+            }
+        }
+    }
+
+    reservoir
+}
+
+/// Rearranges `arr` into the next permutation in lexicographic order
+///
+/// Finds the rightmost position `i` with `arr[i] < arr[i + 1]`, swaps
+/// `arr[i]` with the rightmost element greater than it, and reverses the
+/// suffix after `i`. Repeated calls starting from a sorted slice enumerate
+/// every distinct permutation exactly once.
+///
+/// # Arguments
+/// * `arr` - The slice to permute in place
+///
+/// # Returns
+/// `true` if a next permutation exists. Otherwise `arr` was the last
+/// (nonincreasing) permutation; it is wrapped around to the first
+/// (sorted) one and `false` is returned.
+///
+/// # Complexity
+/// - Time: O(n) worst case, O(1) amortized over a full enumeration
+/// - Space: O(1)
+///
+/// # Example
+/// ```
+/// use clrs::chapter_05::next_permutation;
+/// let mut arr = [1, 3, 2];
+/// assert!(next_permutation(&mut arr));
+/// assert_eq!(arr, [2, 1, 3]);
+/// let mut last = [3, 2, 1];
+/// assert!(!next_permutation(&mut last));
+/// assert_eq!(last, [1, 2, 3]);
+/// ```
+pub fn next_permutation<T: Ord>(arr: &mut [T]) -> bool {
+    step_permutation(arr, |a, b| a < b)
+}
+
+/// Rearranges `arr` into the previous permutation in lexicographic order
+///
+/// The mirror image of [`next_permutation`]: finds the rightmost descent,
+/// swaps it with the rightmost smaller element, and reverses the suffix.
+///
+/// # Arguments
+/// * `arr` - The slice to permute in place
+///
+/// # Returns
+/// `true` if a previous permutation exists. Otherwise `arr` was the first
+/// (sorted) permutation; it is wrapped around to the last (nonincreasing)
+/// one and `false` is returned.
+///
+/// # Complexity
+/// - Time: O(n) worst case, O(1) amortized over a full enumeration
+/// - Space: O(1)
+///
+/// # Example
+/// ```
+/// use clrs::chapter_05::prev_permutation;
+/// let mut arr = [2, 1, 3];
+/// assert!(prev_permutation(&mut arr));
+/// assert_eq!(arr, [1, 3, 2]);
+/// ```
+pub fn prev_permutation<T: Ord>(arr: &mut [T]) -> bool {
+    step_permutation(arr, |a, b| a > b)
+}
+
+/// Shared step of next/prev permutation, where `before(a, b)` says that `a`
+/// must move towards `b` for the permutation to advance.
+fn step_permutation<T, F>(arr: &mut [T], before: F) -> bool
+where
+    F: Fn(&T, &T) -> bool,
+{
+    let n = arr.len();
+    if n < 2 {
+        return false;
+    }
+
+    // Rightmost i with before(arr[i], arr[i + 1]); the suffix after i is
+    // ordered against `before`, so it is the last arrangement of those items.
+    let Some(i) = (0..n - 1).rev().find(|&i| before(&arr[i], &arr[i + 1])) else {
+        arr.reverse();
+        return false;
+    };
+
+    let j = (i + 1..n)
+        .rev()
+        .find(|&j| before(&arr[i], &arr[j]))
+        .expect("arr[i + 1] qualifies");
+    arr.swap(i, j);
+    arr[i + 1..].reverse();
+    true
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -280,4 +418,69 @@ mod tests {
             assert_eq!(set2.len(), 5);
         }
     }
+
+    #[test]
+    fn test_next_and_prev_permutation() {
+        let mut arr = [1, 2, 3, 4];
+        let mut seen = vec![arr];
+        while next_permutation(&mut arr) {
+            assert!(arr > *seen.last().unwrap());
+            seen.push(arr);
+        }
+        assert_eq!(seen.len(), 24);
+        assert_eq!(*seen.last().unwrap(), [4, 3, 2, 1]);
+        // Running off the end wraps around to the sorted permutation.
+        assert_eq!(arr, [1, 2, 3, 4]);
+
+        // Stepping backwards from the last permutation retraces the sequence.
+        let mut arr = [4, 3, 2, 1];
+        for expected in seen.iter().rev().skip(1) {
+            assert!(prev_permutation(&mut arr));
+            assert_eq!(arr, *expected);
+        }
+        assert!(!prev_permutation(&mut arr));
+        assert_eq!(arr, [4, 3, 2, 1]);
+
+        // Repeated elements yield each distinct arrangement once.
+        let mut arr = [1, 1, 2, 2];
+        let mut count = 1;
+        while next_permutation(&mut arr) {
+            count += 1;
+        }
+        assert_eq!(count, 6);
+
+        let mut empty: [i32; 0] = [];
+        assert!(!next_permutation(&mut empty));
+        let mut single = [7];
+        assert!(!prev_permutation(&mut single));
+    }
+
+    #[test]
+    fn test_reservoir_sample_is_uniform() {
+        let trials = 20_000;
+        let mut counts = [0usize; 10];
+        for seed in 0..trials {
+            let sample = reservoir_sample(0..10, 3, seed);
+            assert_eq!(sample.len(), 3);
+            assert_eq!(sample.iter().collect::<HashSet<_>>().len(), 3);
+            for x in sample {
+                counts[x] += 1;
+            }
+        }
+
+        // Each count is Binomial(20000, 0.3) with standard deviation about
+        // 65, so 0.3 ± 0.015 is more than four standard deviations wide.
+        for (x, &count) in counts.iter().enumerate() {
+            let p = count as f64 / trials as f64;
+            assert!((p - 0.3).abs() < 0.015, "element {x} sampled with p = {p}");
+        }
+
+        assert!(reservoir_sample(0..10, 0, 1).is_empty());
+        assert_eq!(reservoir_sample(std::iter::empty::<u8>(), 3, 1), vec![]);
+        assert_eq!(reservoir_sample(0..4, usize::MAX, 1), vec![0, 1, 2, 3]);
+        let unknown_length = (0..usize::MAX)
+            .filter(|x| x % 2 == 0)
+            .take_while(|&x| x < 4);
+        assert_eq!(reservoir_sample(unknown_length, usize::MAX, 1), vec![0, 2]);
+    }
 }
@@ -1,7 +1,29 @@
 //! Heap Operations (Sections 6.1-6.3)
 //!
 //! This module contains basic heap operations including parent/child indexing,
-//! MAX-HEAPIFY, BUILD-MAX-HEAP, and related functions.
+//! MAX-HEAPIFY, BUILD-MAX-HEAP, and related functions. Max-heaps and min-heaps
+//! share one implementation, [`heapify`] and [`build_heap`], parameterized by
+//! a [`HeapKind`], which also drives the general-purpose [`Heap`] type.
+
+/// Which order a heap maintains
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HeapKind {
+    /// Every parent is >= its children; the root is the maximum
+    Max,
+    /// Every parent is <= its children; the root is the minimum
+    Min,
+}
+
+impl HeapKind {
+    /// Returns true if `a` belongs strictly above `b` in a heap of this kind
+    #[inline]
+    pub fn prefers<T: Ord>(self, a: &T, b: &T) -> bool {
+        match self {
+            HeapKind::Max => a > b,
+            HeapKind::Min => a < b,
+        }
+    }
+}
 
 /// Returns the index of the parent of node i in a 1-based heap
 ///
@@ -91,24 +113,49 @@ pub fn right(i: usize) -> usize {
 /// // After heapify, the subtree rooted at index 1 should satisfy max-heap property
 /// ```
 pub fn max_heapify<T: Ord>(arr: &mut [T], heap_size: usize, i: usize) {
-    let mut largest = i;
+    heapify(arr, heap_size, i, HeapKind::Max);
+}
+
+/// Maintains the heap property of the given kind for a subtree rooted at index i
+///
+/// This is MAX-HEAPIFY from CLRS Section 6.2 with the comparison abstracted,
+/// so it also serves as MIN-HEAPIFY (Exercise 6.2-2).
+///
+/// # Arguments
+/// * `arr` - The array representing the heap
+/// * `heap_size` - The size of the heap (may be smaller than array length)
+/// * `i` - The index of the root of the subtree (0-based)
+/// * `kind` - Whether to maintain a max-heap or a min-heap
+///
+/// # Complexity
+/// - Time: O(lg n) where n is the heap size
+///
+/// # Example
+/// ```
+/// use clrs::chapter_06::{heapify, HeapKind};
+/// let mut arr = vec![1, 10, 3, 14, 7, 9, 16, 2, 8, 4];
+/// heapify(&mut arr, 10, 1, HeapKind::Min);
+/// assert_eq!(arr[1], 7);
+/// ```
+pub fn heapify<T: Ord>(arr: &mut [T], heap_size: usize, i: usize, kind: HeapKind) {
+    let mut top = i;
     let l = left(i);
     let r = right(i);
 
     // CLRS: if l <= A.heap-size and A[l] > A[i]
-    if l < heap_size && arr[l] > arr[largest] {
-        largest = l;
+    if l < heap_size && kind.prefers(&arr[l], &arr[top]) {
+        top = l;
     }
 
     // CLRS: if r <= A.heap-size and A[r] > A[largest]
-    if r < heap_size && arr[r] > arr[largest] {
-        largest = r;
+    if r < heap_size && kind.prefers(&arr[r], &arr[top]) {
+        top = r;
     }
 
     // CLRS: if largest != i
-    if largest != i {
-        arr.swap(i, largest);
-        max_heapify(arr, heap_size, largest);
+    if top != i {
+        arr.swap(i, top);
+        heapify(arr, heap_size, top, kind);
     }
 }
 
@@ -166,22 +213,7 @@ pub fn max_heapify_iterative<T: Ord>(arr: &mut [T], heap_size: usize, mut i: usi
 /// min_heapify(&mut arr, arr.len(), 1);
 /// ```
 pub fn min_heapify<T: Ord>(arr: &mut [T], heap_size: usize, i: usize) {
-    let mut smallest = i;
-    let l = left(i);
-    let r = right(i);
-
-    if l < heap_size && arr[l] < arr[smallest] {
-        smallest = l;
-    }
-
-    if r < heap_size && arr[r] < arr[smallest] {
-        smallest = r;
-    }
-
-    if smallest != i {
-        arr.swap(i, smallest);
-        min_heapify(arr, heap_size, smallest);
-    }
+    heapify(arr, heap_size, i, HeapKind::Min);
 }
 
 /// Builds a max-heap from an unordered array
@@ -204,20 +236,7 @@ pub fn min_heapify<T: Ord>(arr: &mut [T], heap_size: usize, i: usize) {
 /// // arr is now a max-heap
 /// ```
 pub fn build_max_heap<T: Ord>(arr: &mut [T]) {
-    let heap_size = arr.len();
-    // CLRS: for i = floor(A.length / 2) downto 1
-    // For 0-based: from (heap_size / 2 - 1) down to 0
-    // But we need to be careful: leaves are at indices >= heap_size / 2
-    // So we start from the last parent node
-    if heap_size <= 1 {
-        return;
-    }
-
-    // Start from the last parent node (index of last node's parent)
-    let start = (heap_size / 2) - 1;
-    for i in (0..=start).rev() {
-        max_heapify(arr, heap_size, i);
-    }
+    build_heap(arr, HeapKind::Max);
 }
 
 /// Builds a min-heap from an unordered array
@@ -235,14 +254,129 @@ pub fn build_max_heap<T: Ord>(arr: &mut [T]) {
 /// // arr is now a min-heap
 /// ```
 pub fn build_min_heap<T: Ord>(arr: &mut [T]) {
+    build_heap(arr, HeapKind::Min);
+}
+
+/// Builds a heap of the given kind from an unordered array
+///
+/// This is BUILD-MAX-HEAP from CLRS Section 6.3, calling [`heapify`] in a
+/// bottom-up manner with the comparison chosen by `kind`.
+///
+/// # Arguments
+/// * `arr` - The array to be converted into a heap (modified in-place)
+/// * `kind` - Whether to build a max-heap or a min-heap
+///
+/// # Complexity
+/// - Time: O(n) where n is the array length
+///
+/// # Example
+/// ```
+/// use clrs::chapter_06::{build_heap, HeapKind};
+/// let mut arr = vec![4, 1, 3, 2, 16, 9, 10, 14, 8, 7];
+/// build_heap(&mut arr, HeapKind::Max);
+/// assert_eq!(arr, vec![16, 14, 10, 8, 7, 9, 3, 2, 4, 1]);
+/// ```
+pub fn build_heap<T: Ord>(arr: &mut [T], kind: HeapKind) {
     let heap_size = arr.len();
+    // CLRS: for i = floor(A.length / 2) downto 1
+    // For 0-based: from (heap_size / 2 - 1) down to 0, the last parent node
     if heap_size <= 1 {
         return;
     }
 
     let start = (heap_size / 2) - 1;
     for i in (0..=start).rev() {
-        min_heapify(arr, heap_size, i);
+        heapify(arr, heap_size, i, kind);
+    }
+}
+
+/// A binary heap over either order, usable as a priority queue
+///
+/// # Example
+/// ```
+/// use clrs::chapter_06::{Heap, HeapKind};
+/// let mut heap = Heap::from_vec(vec![4, 1, 3, 2, 16], HeapKind::Min);
+/// heap.push(0);
+/// assert_eq!(heap.pop(), Some(0));
+/// assert_eq!(heap.pop(), Some(1));
+/// assert_eq!(heap.peek(), Some(&2));
+/// ```
+#[derive(Debug, Clone)]
+pub struct Heap<T: Ord> {
+    data: Vec<T>,
+    kind: HeapKind,
+}
+
+impl<T: Ord> Heap<T> {
+    /// Creates an empty heap of the given kind
+    pub fn new(kind: HeapKind) -> Self {
+        Heap {
+            data: Vec::new(),
+            kind,
+        }
+    }
+
+    /// Builds a heap of the given kind from `data` in O(n) time
+    pub fn from_vec(mut data: Vec<T>, kind: HeapKind) -> Self {
+        build_heap(&mut data, kind);
+        Heap { data, kind }
+    }
+
+    /// Returns the kind of heap
+    pub fn kind(&self) -> HeapKind {
+        self.kind
+    }
+
+    /// Returns the number of elements
+    pub fn len(&self) -> usize {
+        self.data.len()
+    }
+
+    /// Returns true if the heap is empty
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    /// Returns the root: the maximum of a max-heap or the minimum of a min-heap
+    pub fn peek(&self) -> Option<&T> {
+        self.data.first()
+    }
+
+    /// Inserts `item`, floating it up to its place
+    ///
+    /// This corresponds to MAX-HEAP-INSERT from CLRS Section 6.5.
+    ///
+    /// # Complexity
+    /// - Time: O(lg n)
+    pub fn push(&mut self, item: T) {
+        self.data.push(item);
+        let mut i = self.data.len() - 1;
+        while i > 0 && self.kind.prefers(&self.data[i], &self.data[parent(i)]) {
+            self.data.swap(i, parent(i));
+            i = parent(i);
+        }
+    }
+
+    /// Removes and returns the root
+    ///
+    /// This corresponds to HEAP-EXTRACT-MAX from CLRS Section 6.5.
+    ///
+    /// # Complexity
+    /// - Time: O(lg n)
+    pub fn pop(&mut self) -> Option<T> {
+        if self.data.is_empty() {
+            return None;
+        }
+        let last = self.data.len() - 1;
+        self.data.swap(0, last);
+        let root = self.data.pop();
+        heapify(&mut self.data, last, 0, self.kind);
+        root
+    }
+
+    /// Consumes the heap and returns its array in heap order
+    pub fn into_vec(self) -> Vec<T> {
+        self.data
     }
 }
 
@@ -353,4 +487,67 @@ mod tests {
         build_max_heap(&mut arr);
         assert_eq!(arr, vec![42]);
     }
+
+    fn assert_heap_property(arr: &[i32], kind: HeapKind) {
+        for i in 1..arr.len() {
+            assert!(
+                !kind.prefers(&arr[i], &arr[parent(i)]),
+                "{:?}-heap property violated at index {}",
+                kind,
+                i
+            );
+        }
+    }
+
+    #[test]
+    fn test_build_heap_both_kinds() {
+        // CLRS Figure 6.3 input
+        let input = vec![4, 1, 3, 2, 16, 9, 10, 14, 8, 7];
+
+        let mut max = input.clone();
+        build_heap(&mut max, HeapKind::Max);
+        assert_eq!(max, vec![16, 14, 10, 8, 7, 9, 3, 2, 4, 1]);
+        let mut expected = input.clone();
+        build_max_heap(&mut expected);
+        assert_eq!(max, expected);
+
+        let mut min = input.clone();
+        build_heap(&mut min, HeapKind::Min);
+        assert_heap_property(&min, HeapKind::Min);
+        let mut expected = input.clone();
+        build_min_heap(&mut expected);
+        assert_eq!(min, expected);
+
+        // CLRS Exercise 6.3-1
+        let mut arr = vec![5, 3, 17, 10, 84, 19, 6, 22, 9];
+        build_heap(&mut arr, HeapKind::Max);
+        assert_eq!(arr, vec![84, 22, 19, 10, 3, 17, 6, 5, 9]);
+    }
+
+    #[test]
+    fn test_heap_type_orders() {
+        let input = vec![4, 1, 3, 2, 16, 9, 10, 14, 8, 7];
+        for kind in [HeapKind::Max, HeapKind::Min] {
+            let mut heap = Heap::from_vec(input.clone(), kind);
+            heap.push(5);
+            heap.push(12);
+            assert_eq!(heap.len(), 12);
+            assert_heap_property(&heap.clone().into_vec(), kind);
+
+            let mut drained = Vec::new();
+            while let Some(x) = heap.pop() {
+                assert_heap_property(&heap.clone().into_vec(), kind);
+                drained.push(x);
+            }
+
+            let mut expected = input.clone();
+            expected.extend([5, 12]);
+            expected.sort();
+            if kind == HeapKind::Max {
+                expected.reverse();
+            }
+            assert_eq!(drained, expected);
+            assert!(heap.is_empty());
+        }
+    }
 }
@@ -3,7 +3,10 @@
 //! Heapsort uses a heap to sort an array. It first builds a max-heap,
 //! then repeatedly extracts the maximum element.
 
+use std::cmp::Ordering;
+
 use super::heap::{build_max_heap, max_heapify};
+use crate::chapter_02::SortStats;
 
 /// Sorts an array using heapsort
 ///
@@ -47,6 +50,83 @@ pub fn heapsort<T: Ord>(arr: &mut [T]) {
     }
 }
 
+/// Sorts a slice with heapsort using `compare`, counting operations
+///
+/// Runs the same BUILD-MAX-HEAP and extraction loop as [`heapsort`], with
+/// MAX-HEAPIFY spending two comparisons per level it sifts down (fewer at
+/// the bottom of the heap). Every exchange, including moving the maximum to
+/// the end, counts as a swap. The long-distance exchanges make heapsort
+/// unstable.
+///
+/// # Arguments
+/// * `arr` - The slice to sort in-place
+/// * `compare` - Comparison closure defining the order, as in `slice::sort_by`
+///
+/// # Example
+/// ```
+/// use clrs::chapter_06::heapsort_stats;
+/// let mut arr = vec![3, 1, 2];
+/// let stats = heapsort_stats(&mut arr, |a, b| a.cmp(b));
+/// assert_eq!(arr, vec![1, 2, 3]);
+/// assert!(!stats.stable);
+/// ```
+///
+/// # Complexity
+/// - Time: O(n lg n) for all cases
+/// - Space: O(1)
+pub fn heapsort_stats<T, F>(arr: &mut [T], mut compare: F) -> SortStats
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    let mut stats = SortStats::default();
+    let n = arr.len();
+
+    for i in (0..n / 2).rev() {
+        sift_down_counting(arr, n, i, &mut compare, &mut stats);
+    }
+    for heap_size in (1..n).rev() {
+        arr.swap(0, heap_size);
+        stats.swaps += 1;
+        sift_down_counting(arr, heap_size, 0, &mut compare, &mut stats);
+    }
+
+    stats
+}
+
+/// Iterative MAX-HEAPIFY under `compare` that tallies its work in `stats`
+fn sift_down_counting<T, F>(
+    arr: &mut [T],
+    heap_size: usize,
+    mut i: usize,
+    compare: &mut F,
+    stats: &mut SortStats,
+) where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    loop {
+        let (l, r) = (2 * i + 1, 2 * i + 2);
+        let mut largest = i;
+        if l < heap_size {
+            stats.comparisons += 1;
+            if compare(&arr[l], &arr[largest]) == Ordering::Greater {
+                largest = l;
+            }
+        }
+        if r < heap_size {
+            stats.comparisons += 1;
+            if compare(&arr[r], &arr[largest]) == Ordering::Greater {
+                largest = r;
+            }
+        }
+        if largest == i {
+            return;
+        }
+        arr.swap(i, largest);
+        stats.swaps += 1;
+        i = largest;
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -100,4 +180,22 @@ mod tests {
         heapsort(&mut arr);
         assert_eq!(arr, vec![1, 1, 2, 3, 4, 5, 5, 6, 9]);
     }
+
+    #[test]
+    fn test_heapsort_stats() {
+        let n: u64 = 100;
+        let mut arr: Vec<u64> = (0..n).rev().collect();
+        let stats = heapsort_stats(&mut arr, |a, b| a.cmp(b));
+        assert_eq!(arr, (0..n).collect::<Vec<_>>());
+        // At most two comparisons and one swap per level for each of the
+        // n / 2 builds and n - 1 extractions
+        let lg_n = 7;
+        assert!(stats.comparisons <= 2 * lg_n * (n / 2 + n - 1), "{stats:?}");
+        assert!(stats.swaps >= n - 1);
+        assert!(!stats.stable);
+
+        let mut arr = vec![1, 5, 3, 4];
+        heapsort_stats(&mut arr, |a, b| b.cmp(a));
+        assert_eq!(arr, vec![5, 4, 3, 1]);
+    }
 }
@@ -2,9 +2,12 @@
 //!
 //! This module implements a max-priority queue using a max-heap.
 //! Priority queues support operations like extracting the maximum,
-//! increasing a key, and inserting elements.
+//! increasing a key, and inserting elements. [`IndexedMinHeap`] provides
+//! the min-priority counterpart for elements named by small integer ids, as
+//! needed by Dijkstra's and Prim's algorithms, and [`MinPriorityQueue`]
+//! wraps it with handles it hands out itself.
 
-use super::heap::{max_heapify, parent};
+use super::heap::{left, max_heapify, parent, right};
 
 /// Returns the maximum element of the heap
 ///
@@ -238,6 +241,294 @@ pub fn heap_delete<T: Ord + Clone>(arr: &mut [T], heap_size: &mut usize, i: usiz
     *heap_size -= 1;
 }
 
+/// Identifies an element inserted into a [`MinPriorityQueue`]
+///
+/// Handles stay valid while the element is in the queue, even as it moves
+/// within the heap, and are never reused by the same queue.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct QueueHandle(usize);
+
+/// A min-heap of keys attached to caller-chosen integer ids
+///
+/// This is the min-priority queue of CLRS Section 6.5 in the form used by
+/// Dijkstra's and Prim's algorithms: the elements are vertices `0..n`, and
+/// `position[id]` records where each id sits in the heap so that
+/// DECREASE-KEY can find it in O(1). Ids need not be dense or inserted in
+/// order; the position table grows to fit the largest id pushed.
+///
+/// # Example
+/// ```
+/// use clrs::chapter_06::IndexedMinHeap;
+/// let mut heap = IndexedMinHeap::new();
+/// heap.push(3, 10);
+/// heap.push(7, 20);
+/// heap.decrease_key(7, 5);
+/// assert_eq!(heap.pop_min(), Some((7, 5)));
+/// assert!(heap.contains(3));
+/// assert_eq!(heap.pop_min(), Some((3, 10)));
+/// ```
+#[derive(Debug, Clone)]
+pub struct IndexedMinHeap<K: Ord> {
+    /// Heap of (key, id) pairs ordered by key
+    heap: Vec<(K, usize)>,
+    /// `position[id]` is the heap index of `id`, or None if it is not queued
+    position: Vec<Option<usize>>,
+}
+
+impl<K: Ord> IndexedMinHeap<K> {
+    /// Creates an empty heap
+    pub fn new() -> Self {
+        IndexedMinHeap {
+            heap: Vec::new(),
+            position: Vec::new(),
+        }
+    }
+
+    /// Creates an empty heap with room for ids `0..n` without reallocating
+    pub fn with_capacity(n: usize) -> Self {
+        IndexedMinHeap {
+            heap: Vec::with_capacity(n),
+            position: vec![None; n],
+        }
+    }
+
+    /// Returns the number of queued ids
+    pub fn len(&self) -> usize {
+        self.heap.len()
+    }
+
+    /// Returns true if no ids are queued
+    pub fn is_empty(&self) -> bool {
+        self.heap.is_empty()
+    }
+
+    /// Returns true if `id` is currently queued
+    pub fn contains(&self, id: usize) -> bool {
+        self.position.get(id).is_some_and(Option::is_some)
+    }
+
+    /// Returns the current key of `id`
+    pub fn key(&self, id: usize) -> Option<&K> {
+        let index = (*self.position.get(id)?)?;
+        Some(&self.heap[index].0)
+    }
+
+    /// Returns the id with the smallest key
+    ///
+    /// This corresponds to HEAP-MINIMUM, the min-heap version of HEAP-MAXIMUM
+    /// from CLRS Section 6.5.
+    pub fn peek_min(&self) -> Option<(usize, &K)> {
+        self.heap.first().map(|(key, id)| (*id, key))
+    }
+
+    /// Queues `id` with priority `key`
+    ///
+    /// This corresponds to MIN-HEAP-INSERT (CLRS Exercise 6.5-3).
+    ///
+    /// # Panics
+    /// Panics if `id` is already queued.
+    ///
+    /// # Complexity
+    /// - Time: O(lg n)
+    pub fn push(&mut self, id: usize, key: K) {
+        assert!(!self.contains(id), "id is already in the heap");
+        if id >= self.position.len() {
+            self.position.resize(id + 1, None);
+        }
+        let index = self.heap.len();
+        self.position[id] = Some(index);
+        self.heap.push((key, id));
+        self.sift_up(index);
+    }
+
+    /// Removes and returns the id with the smallest key
+    ///
+    /// This corresponds to HEAP-EXTRACT-MIN (CLRS Exercise 6.5-3).
+    ///
+    /// # Complexity
+    /// - Time: O(lg n)
+    pub fn pop_min(&mut self) -> Option<(usize, K)> {
+        if self.heap.is_empty() {
+            return None;
+        }
+        let last = self.heap.len() - 1;
+        self.swap(0, last);
+        let (key, id) = self.heap.pop().expect("heap is nonempty");
+        self.position[id] = None;
+        self.sift_down(0);
+        Some((id, key))
+    }
+
+    /// Lowers the key of `id` to `key`
+    ///
+    /// This corresponds to HEAP-DECREASE-KEY (CLRS Exercise 6.5-3).
+    ///
+    /// # Panics
+    /// Panics if `id` is not queued or if the new key is larger than the
+    /// current key.
+    ///
+    /// # Complexity
+    /// - Time: O(lg n)
+    pub fn decrease_key(&mut self, id: usize, key: K) {
+        let index = self
+            .position
+            .get(id)
+            .copied()
+            .flatten()
+            .expect("id is not in the heap");
+        // CLRS: if key > A[i]
+        if key > self.heap[index].0 {
+            panic!("new key is larger than current key");
+        }
+        self.heap[index].0 = key;
+        self.sift_up(index);
+    }
+
+    /// Moves the element at `i` up while it is smaller than its parent
+    fn sift_up(&mut self, mut i: usize) {
+        while i > 0 && self.heap[i].0 < self.heap[parent(i)].0 {
+            self.swap(i, parent(i));
+            i = parent(i);
+        }
+    }
+
+    /// MIN-HEAPIFY on the element at `i`, keeping positions up to date
+    fn sift_down(&mut self, mut i: usize) {
+        let n = self.heap.len();
+        loop {
+            let mut smallest = i;
+            if left(i) < n && self.heap[left(i)].0 < self.heap[smallest].0 {
+                smallest = left(i);
+            }
+            if right(i) < n && self.heap[right(i)].0 < self.heap[smallest].0 {
+                smallest = right(i);
+            }
+            if smallest == i {
+                return;
+            }
+            self.swap(i, smallest);
+            i = smallest;
+        }
+    }
+
+    fn swap(&mut self, i: usize, j: usize) {
+        self.heap.swap(i, j);
+        self.position[self.heap[i].1] = Some(i);
+        self.position[self.heap[j].1] = Some(j);
+    }
+}
+
+impl<K: Ord> Default for IndexedMinHeap<K> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A min-priority queue backed by a min-heap
+///
+/// This is the min-heap version of the priority queue from CLRS Section 6.5.
+/// It is an [`IndexedMinHeap`] whose ids are allocated by the queue and
+/// returned as handles, for callers that have no natural numbering of their
+/// own.
+///
+/// # Example
+/// ```
+/// use clrs::chapter_06::MinPriorityQueue;
+/// let mut queue = MinPriorityQueue::new();
+/// let a = queue.insert(10);
+/// let b = queue.insert(20);
+/// queue.decrease_key(b, 5);
+/// assert_eq!(queue.extract_min(), Some((b, 5)));
+/// assert_eq!(queue.extract_min(), Some((a, 10)));
+/// ```
+#[derive(Debug, Clone)]
+pub struct MinPriorityQueue<K: Ord> {
+    heap: IndexedMinHeap<K>,
+    next_handle: usize,
+}
+
+impl<K: Ord> MinPriorityQueue<K> {
+    /// Creates an empty min-priority queue
+    pub fn new() -> Self {
+        MinPriorityQueue {
+            heap: IndexedMinHeap::new(),
+            next_handle: 0,
+        }
+    }
+
+    /// Returns the number of elements in the queue
+    pub fn len(&self) -> usize {
+        self.heap.len()
+    }
+
+    /// Returns true if the queue is empty
+    pub fn is_empty(&self) -> bool {
+        self.heap.is_empty()
+    }
+
+    /// Returns true if the element with this handle is still in the queue
+    pub fn contains(&self, handle: QueueHandle) -> bool {
+        self.heap.contains(handle.0)
+    }
+
+    /// Returns the current key of the element with this handle
+    pub fn key(&self, handle: QueueHandle) -> Option<&K> {
+        self.heap.key(handle.0)
+    }
+
+    /// Returns the element with the smallest key
+    ///
+    /// This corresponds to HEAP-MINIMUM, the min-heap version of HEAP-MAXIMUM
+    /// from CLRS Section 6.5.
+    pub fn minimum(&self) -> Option<(QueueHandle, &K)> {
+        self.heap.peek_min().map(|(id, key)| (QueueHandle(id), key))
+    }
+
+    /// Inserts `key` and returns a handle to it
+    ///
+    /// This corresponds to MIN-HEAP-INSERT (CLRS Exercise 6.5-3).
+    ///
+    /// # Complexity
+    /// - Time: O(lg n)
+    pub fn insert(&mut self, key: K) -> QueueHandle {
+        let handle = QueueHandle(self.next_handle);
+        self.next_handle += 1;
+        self.heap.push(handle.0, key);
+        handle
+    }
+
+    /// Removes and returns the element with the smallest key
+    ///
+    /// This corresponds to HEAP-EXTRACT-MIN (CLRS Exercise 6.5-3).
+    ///
+    /// # Complexity
+    /// - Time: O(lg n)
+    pub fn extract_min(&mut self) -> Option<(QueueHandle, K)> {
+        self.heap.pop_min().map(|(id, key)| (QueueHandle(id), key))
+    }
+
+    /// Lowers the key of the element with this handle to `key`
+    ///
+    /// This corresponds to HEAP-DECREASE-KEY (CLRS Exercise 6.5-3).
+    ///
+    /// # Panics
+    /// Panics if the element is no longer in the queue or if the new key is
+    /// larger than the current key.
+    ///
+    /// # Complexity
+    /// - Time: O(lg n)
+    pub fn decrease_key(&mut self, handle: QueueHandle, key: K) {
+        assert!(self.contains(handle), "handle is not in the queue");
+        self.heap.decrease_key(handle.0, key);
+    }
+}
+
+impl<K: Ord> Default for MinPriorityQueue<K> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -300,4 +591,75 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn test_min_priority_queue_decrease_key() {
+        let mut queue = MinPriorityQueue::new();
+        let handles: Vec<_> = [4, 1, 3, 2, 16, 9, 10, 14, 8, 7]
+            .iter()
+            .map(|&key| queue.insert(key))
+            .collect();
+        assert_eq!(queue.len(), 10);
+        assert_eq!(queue.minimum(), Some((handles[1], &1)));
+
+        // 16 becomes the new minimum; 14 moves ahead of 3.
+        queue.decrease_key(handles[4], 0);
+        queue.decrease_key(handles[7], 2);
+        assert_eq!(queue.key(handles[7]), Some(&2));
+
+        let mut order = Vec::new();
+        while let Some((handle, key)) = queue.extract_min() {
+            assert!(!queue.contains(handle));
+            order.push(key);
+        }
+        assert_eq!(order, vec![0, 1, 2, 2, 3, 4, 7, 8, 9, 10]);
+        assert_eq!(queue.key(handles[0]), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "new key is larger than current key")]
+    fn test_min_priority_queue_rejects_larger_key() {
+        let mut queue = MinPriorityQueue::new();
+        let handle = queue.insert(5);
+        queue.decrease_key(handle, 6);
+    }
+
+    #[test]
+    fn test_indexed_min_heap() {
+        let mut heap = IndexedMinHeap::with_capacity(4);
+        assert!(heap.is_empty());
+        assert_eq!(heap.pop_min(), None);
+
+        // Ids beyond the initial capacity and out of order are fine.
+        for (id, key) in [(2, 30), (0, 50), (9, 10), (5, 40), (1, 20)] {
+            heap.push(id, key);
+        }
+        assert_eq!(heap.len(), 5);
+        assert_eq!(heap.peek_min(), Some((9, &10)));
+        assert!(heap.contains(5) && !heap.contains(3) && !heap.contains(100));
+
+        heap.decrease_key(0, 15);
+        heap.decrease_key(5, 5);
+        heap.decrease_key(2, 30);
+        assert_eq!(heap.key(0), Some(&15));
+
+        let mut order = Vec::new();
+        while let Some((id, key)) = heap.pop_min() {
+            assert!(!heap.contains(id));
+            order.push((id, key));
+        }
+        assert_eq!(order, vec![(5, 5), (9, 10), (0, 15), (1, 20), (2, 30)]);
+
+        // A popped id can be queued again.
+        heap.push(9, 1);
+        assert_eq!(heap.pop_min(), Some((9, 1)));
+    }
+
+    #[test]
+    #[should_panic(expected = "id is already in the heap")]
+    fn test_indexed_min_heap_rejects_duplicate_id() {
+        let mut heap = IndexedMinHeap::new();
+        heap.push(1, 5);
+        heap.push(1, 3);
+    }
 }
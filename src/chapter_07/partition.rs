@@ -1,6 +1,9 @@
 //! Partition Operations (Section 7.1)
 //!
-//! This module contains the PARTITION procedure that is the core of quicksort.
+//! This module contains the PARTITION procedure that is the core of quicksort,
+//! together with the alternative schemes from the chapter problems.
+
+use std::cmp::Ordering;
 
 /// Partitions the subarray A[p..r] around a pivot
 ///
@@ -139,6 +142,168 @@ pub fn partition_nonincreasing<T: Ord>(arr: &mut [T], p: usize, r: usize) -> usi
     (i + 1) as usize
 }
 
+/// Partitions the subarray A[p..r] using Hoare's original scheme
+///
+/// This corresponds to HOARE-PARTITION from CLRS Problem 7-1. The pivot is
+/// A[p]; two indices move toward each other and exchange out-of-place pairs.
+/// Unlike PARTITION, the pivot does not necessarily end in its final
+/// position, so quicksort recurses on A[p..j] and A[j+1..r].
+///
+/// # Arguments
+/// * `arr` - The array to partition
+/// * `p` - Start index (0-based)
+/// * `r` - End index (0-based, inclusive)
+///
+/// # Returns
+/// An index j with p <= j < r (when p < r) such that every element of
+/// A[p..j] is <= every element of A[j+1..r]
+///
+/// # Complexity
+/// - Time: Θ(n) where n = r - p + 1
+/// - Space: O(1)
+///
+/// # Example
+/// ```
+/// use clrs::chapter_07::hoare_partition;
+/// let mut arr = vec![13, 19, 9, 5, 12, 8, 7, 4, 11, 2, 6, 21];
+/// let j = hoare_partition(&mut arr, 0, 11);
+/// assert_eq!(j, 8);
+/// assert!(arr[..=j].iter().all(|&x| x <= 13));
+/// assert!(arr[j + 1..].iter().all(|&x| x >= 13));
+/// ```
+pub fn hoare_partition<T: Ord>(arr: &mut [T], p: usize, r: usize) -> usize {
+    // CLRS: x = A[p]; the pivot is tracked by index since exchanges move it
+    let mut pivot = p;
+
+    // CLRS: i = p - 1, j = r + 1
+    let mut i = p as isize - 1;
+    let mut j = r as isize + 1;
+
+    loop {
+        // CLRS: repeat j = j - 1 until A[j] <= x
+        loop {
+            j -= 1;
+            if arr[j as usize] <= arr[pivot] {
+                break;
+            }
+        }
+        // CLRS: repeat i = i + 1 until A[i] >= x
+        loop {
+            i += 1;
+            if arr[i as usize] >= arr[pivot] {
+                break;
+            }
+        }
+        // CLRS: if i < j exchange A[i] with A[j] else return j
+        if i < j {
+            let (i, j) = (i as usize, j as usize);
+            arr.swap(i, j);
+            if pivot == i {
+                pivot = j;
+            } else if pivot == j {
+                pivot = i;
+            }
+        } else {
+            return j as usize;
+        }
+    }
+}
+
+/// Partitions the subarray A[p..r] into elements less than, equal to, and
+/// greater than the pivot
+///
+/// This corresponds to PARTITION' from CLRS Problem 7-2. The pivot is A[r],
+/// as in PARTITION. Quicksort then only recurses on the strictly smaller and
+/// strictly larger bands, so runs of equal keys cost linear time.
+///
+/// # Arguments
+/// * `arr` - The array to partition
+/// * `p` - Start index (0-based)
+/// * `r` - End index (0-based, inclusive)
+///
+/// # Returns
+/// Indices (q, t) with p <= q <= t <= r such that A[q..t] all equal the
+/// pivot, A[p..q-1] are smaller and A[t+1..r] are larger
+///
+/// # Complexity
+/// - Time: Θ(n) where n = r - p + 1
+/// - Space: O(1)
+///
+/// # Example
+/// ```
+/// use clrs::chapter_07::partition_three_way;
+/// let mut arr = vec![4, 9, 4, 1, 4, 7, 4];
+/// let (q, t) = partition_three_way(&mut arr, 0, 6);
+/// assert_eq!((q, t), (1, 4));
+/// assert_eq!(arr[q..=t], [4, 4, 4, 4]);
+/// ```
+pub fn partition_three_way<T: Ord>(arr: &mut [T], p: usize, r: usize) -> (usize, usize) {
+    // Move the pivot A[r] to the front; A[lt..i] then always equals it
+    arr.swap(p, r);
+    let mut lt = p;
+    let mut i = p + 1;
+    let mut gt = r;
+    while i <= gt {
+        match arr[i].cmp(&arr[lt]) {
+            Ordering::Less => {
+                arr.swap(lt, i);
+                lt += 1;
+                i += 1;
+            }
+            Ordering::Greater => {
+                arr.swap(i, gt);
+                gt -= 1;
+            }
+            Ordering::Equal => i += 1,
+        }
+    }
+    (lt, gt)
+}
+
+/// Partitions the subarray A[p..r] around the median of three elements
+///
+/// This is the median-of-3 method from CLRS Problem 7-5: the median of
+/// A[p], A[(p + r) / 2] and A[r] is moved to A[r] and PARTITION runs as
+/// usual. Sorted and reverse-sorted inputs, the worst cases of plain
+/// PARTITION, then split evenly.
+///
+/// # Arguments
+/// * `arr` - The array to partition
+/// * `p` - Start index (0-based)
+/// * `r` - End index (0-based, inclusive)
+///
+/// # Returns
+/// The index of the pivot element after partitioning
+///
+/// # Complexity
+/// - Time: Θ(n) where n = r - p + 1
+/// - Space: O(1)
+///
+/// # Example
+/// ```
+/// use clrs::chapter_07::median_of_three_partition;
+/// let mut arr = vec![1, 2, 3, 4, 5, 6, 7];
+/// let q = median_of_three_partition(&mut arr, 0, 6);
+/// assert_eq!((q, arr[q]), (3, 4));
+/// ```
+pub fn median_of_three_partition<T: Ord>(arr: &mut [T], p: usize, r: usize) -> usize {
+    if r - p >= 2 {
+        let mid = p + (r - p) / 2;
+        // Order the three samples so that A[p] <= A[mid] <= A[r]
+        if arr[mid] < arr[p] {
+            arr.swap(mid, p);
+        }
+        if arr[r] < arr[p] {
+            arr.swap(r, p);
+        }
+        if arr[r] < arr[mid] {
+            arr.swap(r, mid);
+        }
+        arr.swap(mid, r);
+    }
+    partition(arr, p, r)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -216,4 +381,37 @@ mod tests {
         let q = partition(&mut arr, 0, 4);
         assert_eq!(q, 0); // Pivot 1 should be at the beginning
     }
+
+    #[test]
+    fn test_hoare_partition_splits() {
+        let mut arr = vec![13, 19, 9, 5, 12, 8, 7, 4, 11, 2, 6, 21];
+        let j = hoare_partition(&mut arr, 0, 11);
+        assert!(j < 11);
+        let left_max = arr[..=j].iter().max().unwrap();
+        let right_min = arr[j + 1..].iter().min().unwrap();
+        assert!(left_max <= right_min);
+
+        let mut arr = vec![5, 5, 5, 5];
+        let j = hoare_partition(&mut arr, 0, 3);
+        assert!(j < 3);
+    }
+
+    #[test]
+    fn test_partition_three_way_bands() {
+        let mut arr = vec![3, 8, 3, 1, 9, 3, 2, 3];
+        let (q, t) = partition_three_way(&mut arr, 0, 7);
+        assert_eq!((q, t), (2, 5));
+        assert!(arr[..q].iter().all(|&x| x < 3));
+        assert!(arr[q..=t].iter().all(|&x| x == 3));
+        assert!(arr[t + 1..].iter().all(|&x| x > 3));
+    }
+
+    #[test]
+    fn test_median_of_three_partition_sorted_input() {
+        let mut arr: Vec<i32> = (0..9).rev().collect();
+        let q = median_of_three_partition(&mut arr, 0, 8);
+        assert_eq!((q, arr[q]), (4, 4));
+        assert!(arr[..q].iter().all(|&x| x <= 4));
+        assert!(arr[q + 1..].iter().all(|&x| x > 4));
+    }
 }
@@ -3,7 +3,12 @@
 //! This module contains the quicksort algorithm that uses PARTITION
 //! to sort arrays in place.
 
-use super::partition::partition;
+use std::cmp::Ordering;
+
+use super::partition::{
+    hoare_partition, median_of_three_partition, partition, partition_three_way,
+};
+use crate::chapter_02::{insertion_sort_range, SortStats};
 
 /// Sorts an array using quicksort
 ///
@@ -45,6 +50,113 @@ pub fn quicksort<T: Ord>(arr: &mut [T], p: usize, r: usize) {
     }
 }
 
+/// Sorts an array using quicksort, finishing small subarrays with insertion sort
+///
+/// Like QUICKSORT, but any subarray with at most `cutoff` elements is sorted
+/// by insertion sort instead of being partitioned further. Insertion sort is
+/// faster on short inputs, so a small cutoff (around 10 to 20) trims the
+/// constant factor (compare CLRS Exercise 7.4-5). A cutoff of 0 or 1 gives
+/// plain QUICKSORT.
+///
+/// # Arguments
+/// * `arr` - The array to be sorted (modified in-place)
+/// * `p` - Start index (0-based)
+/// * `r` - End index (0-based, inclusive)
+/// * `cutoff` - Largest subarray length handed to insertion sort
+///
+/// # Example
+/// ```
+/// use clrs::chapter_07::quicksort_with_cutoff;
+/// let mut arr = vec![3, 1, 4, 1, 5, 9, 2, 6];
+/// quicksort_with_cutoff(&mut arr, 0, 7, 4);
+/// assert_eq!(arr, vec![1, 1, 2, 3, 4, 5, 6, 9]);
+/// ```
+pub fn quicksort_with_cutoff<T: Ord>(arr: &mut [T], p: usize, r: usize, cutoff: usize) {
+    if p >= r {
+        return;
+    }
+    if r - p < cutoff {
+        insertion_sort_range(arr, p, r);
+        return;
+    }
+
+    let q = partition(arr, p, r);
+    if q > p {
+        quicksort_with_cutoff(arr, p, q - 1, cutoff);
+    }
+    quicksort_with_cutoff(arr, q + 1, r, cutoff);
+}
+
+/// Partitioning procedure used by [`quicksort_with`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PartitionScheme {
+    /// PARTITION from Section 7.1, with the last element as pivot
+    Lomuto,
+    /// HOARE-PARTITION from Problem 7-1, with the first element as pivot
+    Hoare,
+    /// PARTITION' from Problem 7-2, which groups keys equal to the pivot
+    ThreeWay,
+    /// PARTITION with the median-of-3 pivot of Problem 7-5
+    MedianOfThree,
+}
+
+/// Sorts an entire array using quicksort with the given partition scheme
+///
+/// Every scheme yields a full sort, so the schemes can be compared on the
+/// same inputs. The recursion follows each scheme's contract: Lomuto and
+/// median-of-3 exclude the placed pivot, Hoare recurses on both halves of
+/// its split, and three-way skips the whole band of keys equal to the pivot.
+///
+/// # Arguments
+/// * `arr` - The array to be sorted (modified in-place)
+/// * `scheme` - The partitioning procedure to use
+///
+/// # Complexity
+/// - Average case: O(n lg n) for every scheme
+/// - Worst case: O(n²); sorted inputs trigger it for Lomuto and Hoare, many
+///   equal keys for every scheme but three-way
+///
+/// # Example
+/// ```
+/// use clrs::chapter_07::{quicksort_with, PartitionScheme};
+/// let mut arr = vec![3, 1, 4, 1, 5, 9, 2, 6];
+/// quicksort_with(&mut arr, PartitionScheme::Hoare);
+/// assert_eq!(arr, vec![1, 1, 2, 3, 4, 5, 6, 9]);
+/// ```
+pub fn quicksort_with<T: Ord>(arr: &mut [T], scheme: PartitionScheme) {
+    if arr.len() > 1 {
+        quicksort_with_scheme(arr, 0, arr.len() - 1, scheme);
+    }
+}
+
+fn quicksort_with_scheme<T: Ord>(arr: &mut [T], p: usize, r: usize, scheme: PartitionScheme) {
+    if p >= r {
+        return;
+    }
+    let (left_end, right_start) = match scheme {
+        PartitionScheme::Lomuto => {
+            let q = partition(arr, p, r);
+            (q.checked_sub(1), q + 1)
+        }
+        PartitionScheme::MedianOfThree => {
+            let q = median_of_three_partition(arr, p, r);
+            (q.checked_sub(1), q + 1)
+        }
+        PartitionScheme::Hoare => {
+            let j = hoare_partition(arr, p, r);
+            (Some(j), j + 1)
+        }
+        PartitionScheme::ThreeWay => {
+            let (q, t) = partition_three_way(arr, p, r);
+            (q.checked_sub(1), t + 1)
+        }
+    };
+    if let Some(left_end) = left_end {
+        quicksort_with_scheme(arr, p, left_end, scheme);
+    }
+    quicksort_with_scheme(arr, right_start, r, scheme);
+}
+
 /// Sorts an array using quicksort (nonincreasing order)
 ///
 /// Modified version of QUICKSORT to sort in nonincreasing order (Exercise 7.1-4).
@@ -88,6 +200,124 @@ pub fn quicksort_full<T: Ord>(arr: &mut [T]) {
     quicksort(arr, 0, arr.len() - 1);
 }
 
+/// Convenience function for nonincreasing quicksort on entire array
+///
+/// Partitions with the comparison reversed, as [`quicksort_nonincreasing`]
+/// does, instead of sorting into increasing order and reversing. Equal
+/// elements may end up in any order.
+///
+/// # Example
+/// ```
+/// use clrs::chapter_07::quicksort_desc;
+/// let mut arr = vec![3, 1, 4, 1, 5];
+/// quicksort_desc(&mut arr);
+/// assert_eq!(arr, vec![5, 4, 3, 1, 1]);
+/// ```
+pub fn quicksort_desc<T: Ord>(arr: &mut [T]) {
+    if arr.is_empty() {
+        return;
+    }
+    quicksort_nonincreasing(arr, 0, arr.len() - 1);
+}
+
+/// Sorts a slice with quicksort using `compare`, counting operations
+///
+/// Each call picks the median of the first, middle and last elements as the
+/// pivot, as [`median_of_three_partition`] does, so sorted and reverse-sorted
+/// inputs take O(n lg n) comparisons rather than the Θ(n²) of plain
+/// QUICKSORT. The partition is three-way, as in [`partition_three_way`], so
+/// every key equal to the pivot is settled at once and inputs with many equal
+/// keys stay fast too. Only the smaller side is sorted recursively; the
+/// larger one is handled by iteration, as in Problem 7-4, which keeps the
+/// stack shallow whatever the pivots. The comparisons spent choosing the
+/// median are counted along with those made by partitioning. Only exchanges
+/// of two distinct positions count as swaps. Quicksort is unstable.
+///
+/// # Arguments
+/// * `arr` - The slice to sort in-place
+/// * `compare` - Comparison closure defining the order, as in `slice::sort_by`
+///
+/// # Example
+/// ```
+/// use clrs::chapter_07::quicksort_stats;
+/// let mut arr = vec![3, 1, 4, 1, 5, 9, 2, 6];
+/// let stats = quicksort_stats(&mut arr, |a, b| a.cmp(b));
+/// assert_eq!(arr, vec![1, 1, 2, 3, 4, 5, 6, 9]);
+/// assert!(!stats.stable);
+/// ```
+///
+/// # Complexity
+/// - Time: O(n lg n) on sorted, reverse-sorted and all-equal inputs, O(n²)
+///   worst case for inputs built to defeat the median of three
+/// - Space: O(lg n) for the recursion stack
+pub fn quicksort_stats<T, F>(arr: &mut [T], mut compare: F) -> SortStats
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    let mut stats = SortStats::default();
+    quicksort_counting(arr, &mut compare, &mut stats);
+    stats
+}
+
+fn quicksort_counting<T, F>(mut arr: &mut [T], compare: &mut F, stats: &mut SortStats)
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    let exchange = |arr: &mut [T], i: usize, j: usize, stats: &mut SortStats| {
+        if i != j {
+            arr.swap(i, j);
+            stats.swaps += 1;
+        }
+    };
+
+    while arr.len() > 1 {
+        let r = arr.len() - 1;
+        if r >= 2 {
+            let mid = r / 2;
+            // Order the three samples so that A[0] <= A[mid] <= A[r]
+            for (a, b) in [(0, mid), (0, r), (mid, r)] {
+                stats.comparisons += 1;
+                if compare(&arr[b], &arr[a]) == Ordering::Less {
+                    exchange(arr, a, b, stats);
+                }
+            }
+            exchange(arr, mid, r, stats);
+        }
+
+        // Three-way partition around the pivot A[r], moved to the front;
+        // A[lt..i] then always equals it
+        exchange(arr, 0, r, stats);
+        let mut lt = 0;
+        let mut i = 1;
+        let mut gt = r;
+        while i <= gt {
+            stats.comparisons += 1;
+            match compare(&arr[i], &arr[lt]) {
+                Ordering::Less => {
+                    exchange(arr, lt, i, stats);
+                    lt += 1;
+                    i += 1;
+                }
+                Ordering::Greater => {
+                    exchange(arr, i, gt, stats);
+                    gt -= 1;
+                }
+                Ordering::Equal => i += 1,
+            }
+        }
+
+        let (left, rest) = std::mem::take(&mut arr).split_at_mut(lt);
+        let right = &mut rest[gt - lt + 1..];
+        if left.len() < right.len() {
+            quicksort_counting(left, compare, stats);
+            arr = right;
+        } else {
+            quicksort_counting(right, compare, stats);
+            arr = left;
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -141,6 +371,29 @@ mod tests {
         assert_eq!(arr, vec![9, 6, 5, 4, 3, 2, 1, 1]);
     }
 
+    #[test]
+    fn test_quicksort_desc() {
+        use rand::rngs::StdRng;
+        use rand::seq::SliceRandom;
+        use rand::SeedableRng;
+
+        let mut arr = vec![3, 1, 4, 1, 5];
+        quicksort_desc(&mut arr);
+        assert_eq!(arr, vec![5, 4, 3, 1, 1]);
+
+        let mut distinct: Vec<i32> = (0..300).collect();
+        distinct.shuffle(&mut StdRng::seed_from_u64(448));
+        let mut expected = distinct.clone();
+        quicksort_full(&mut expected);
+        expected.reverse();
+        quicksort_desc(&mut distinct);
+        assert_eq!(distinct, expected);
+
+        let mut empty: Vec<i32> = vec![];
+        quicksort_desc(&mut empty);
+        assert!(empty.is_empty());
+    }
+
     #[test]
     fn test_quicksort_subarray() {
         let mut arr = vec![9, 3, 1, 4, 1, 5, 2, 6, 8];
@@ -150,4 +403,96 @@ mod tests {
         assert_eq!(arr[0], 9);
         assert_eq!(arr[8], 8);
     }
+
+    #[test]
+    fn test_quicksort_with_cutoff() {
+        use rand::Rng;
+
+        let mut rng = rand::thread_rng();
+        for cutoff in [0, 1, 5, 16, 1000] {
+            let mut arr: Vec<i32> = (0..500).map(|_| rng.gen_range(-50..50)).collect();
+            let mut expected = arr.clone();
+            expected.sort();
+            quicksort_with_cutoff(&mut arr, 0, 499, cutoff);
+            assert_eq!(arr, expected);
+        }
+
+        let mut arr = vec![9, 3, 1, 4, 1, 5, 2, 6, 8];
+        quicksort_with_cutoff(&mut arr, 1, 7, 3);
+        assert_eq!(arr, vec![9, 1, 1, 2, 3, 4, 5, 6, 8]);
+    }
+
+    #[test]
+    fn test_quicksort_with_every_scheme() {
+        use rand::rngs::StdRng;
+        use rand::{Rng, SeedableRng};
+
+        let schemes = [
+            PartitionScheme::Lomuto,
+            PartitionScheme::Hoare,
+            PartitionScheme::ThreeWay,
+            PartitionScheme::MedianOfThree,
+        ];
+        let mut rng = StdRng::seed_from_u64(415);
+        let random: Vec<i32> = (0..2000).map(|_| rng.gen_range(-100..100)).collect();
+        let mut sorted = random.clone();
+        sorted.sort();
+        let descending: Vec<i32> = (0..300).rev().collect();
+
+        for scheme in schemes {
+            let mut arr = random.clone();
+            quicksort_with(&mut arr, scheme);
+            assert_eq!(arr, sorted, "{scheme:?}");
+
+            let mut arr = descending.clone();
+            quicksort_with(&mut arr, scheme);
+            assert_eq!(arr, (0..300).collect::<Vec<_>>(), "{scheme:?}");
+
+            let mut arr = vec![7; 50];
+            quicksort_with(&mut arr, scheme);
+            assert_eq!(arr, vec![7; 50]);
+
+            for mut arr in [vec![], vec![1], vec![2, 1]] {
+                quicksort_with(&mut arr, scheme);
+                assert!(arr.windows(2).all(|w| w[0] <= w[1]));
+            }
+        }
+    }
+
+    #[test]
+    fn test_quicksort_stats() {
+        use rand::rngs::StdRng;
+        use rand::{Rng, SeedableRng};
+
+        let n: u64 = 200;
+        for input in [(0..n).collect::<Vec<_>>(), (0..n).rev().collect::<Vec<_>>()] {
+            let mut arr = input;
+            let stats = quicksort_stats(&mut arr, |a, b| a.cmp(b));
+            assert_eq!(arr, (0..n).collect::<Vec<_>>());
+            assert!(stats.comparisons < n * (n - 1) / 8, "{stats:?}");
+            assert!(!stats.stable);
+        }
+
+        let mut rng = StdRng::seed_from_u64(440);
+        let mut arr: Vec<i32> = (0..500).map(|_| rng.gen_range(-20..20)).collect();
+        let mut expected = arr.clone();
+        expected.sort();
+        quicksort_stats(&mut arr, |a, b| a.cmp(b));
+        assert_eq!(arr, expected);
+
+        let mut arr = vec![1, 5, 3, 4];
+        quicksort_stats(&mut arr, |a, b| b.cmp(a));
+        assert_eq!(arr, vec![5, 4, 3, 1]);
+    }
+
+    #[test]
+    fn test_quicksort_stats_all_equal() {
+        // The first partition puts every key in the equal band, so the sort
+        // ends after n + 2 comparisons instead of recursing n levels deep.
+        let n: u64 = 100_000;
+        let mut arr = vec![7i64; n as usize];
+        let stats = quicksort_stats(&mut arr, |a, b| a.cmp(b));
+        assert!(arr.iter().all(|&x| x == 7));
+        assert_eq!(stats.comparisons, n + 2);
+    }
 }
@@ -27,12 +27,29 @@
 /// assert_eq!(sorted, vec![0.13, 0.16, 0.20, 0.39, 0.42, 0.53, 0.64, 0.71, 0.79, 0.89]);
 /// ```
 pub fn bucket_sort(arr: &[f64]) -> Vec<f64> {
-    let n = arr.len();
-
-    if n == 0 {
+    if arr.is_empty() {
         return vec![];
     }
 
+    let mut b = scatter(arr);
+
+    // CLRS: for i = 0 to n - 1
+    // CLRS: sort list B[i] with insertion sort
+    for bucket in &mut b {
+        insertion_sort_bucket(bucket);
+    }
+
+    // CLRS: concatenate the lists B[0], B[1], ..., B[n-1] together in order
+    b.into_iter().flatten().collect()
+}
+
+/// Distributes the values of `arr` into `n = arr.len()` buckets
+///
+/// # Panics
+/// Panics if an element is not in [0.0, 1.0)
+fn scatter(arr: &[f64]) -> Vec<Vec<f64>> {
+    let n = arr.len();
+
     // CLRS: let B[0..n-1] be a new array
     let mut b: Vec<Vec<f64>> = vec![Vec::new(); n];
 
@@ -48,14 +65,7 @@ pub fn bucket_sort(arr: &[f64]) -> Vec<f64> {
         b[index].push(value);
     }
 
-    // CLRS: for i = 0 to n - 1
-    // CLRS: sort list B[i] with insertion sort
-    for bucket in &mut b {
-        insertion_sort_bucket(bucket);
-    }
-
-    // CLRS: concatenate the lists B[0], B[1], ..., B[n-1] together in order
-    b.into_iter().flatten().collect()
+    b
 }
 
 /// Insertion sort for a bucket (used as subroutine in bucket sort)
@@ -92,6 +102,46 @@ pub fn bucket_sort_inplace(arr: &mut [f64]) {
     arr.copy_from_slice(&sorted);
 }
 
+/// Sorts in place with bucket sort and reports how full each bucket was
+///
+/// Runs BUCKET-SORT exactly as [`bucket_sort`] does, but also returns the
+/// size n_i of every bucket B[i]. The analysis in CLRS Section 8.4 rests on
+/// these sizes: for uniform input each n_i is binomial with E[n_i] = 1 and
+/// E[n_i²] = 2 - 1/n, so the insertion sorts take Θ(n) expected time overall.
+///
+/// # Arguments
+/// * `arr` - The array to be sorted (must contain floats in [0.0, 1.0))
+///
+/// # Returns
+/// The occupancy count of each of the `arr.len()` buckets
+///
+/// # Complexity
+/// - Average case: Θ(n)
+/// - Worst case: Θ(n²) if all elements fall in the same bucket
+/// - Space: Θ(n)
+///
+/// # Example
+/// ```
+/// use clrs::chapter_08::bucket_sort_with_stats;
+/// let mut arr = vec![0.78, 0.17, 0.39, 0.26, 0.72];
+/// let counts = bucket_sort_with_stats(&mut arr);
+/// assert_eq!(arr, vec![0.17, 0.26, 0.39, 0.72, 0.78]);
+/// assert_eq!(counts, vec![1, 2, 0, 2, 0]);
+/// ```
+pub fn bucket_sort_with_stats(arr: &mut [f64]) -> Vec<usize> {
+    let mut b = scatter(arr);
+    let counts = b.iter().map(Vec::len).collect();
+
+    for bucket in &mut b {
+        insertion_sort_bucket(bucket);
+    }
+    for (slot, value) in arr.iter_mut().zip(b.into_iter().flatten()) {
+        *slot = value;
+    }
+
+    counts
+}
+
 /// Bucket sort with merge sort for worst-case O(n lg n) (Exercise 8.4-2)
 ///
 /// This version uses merge sort instead of insertion sort to improve
@@ -108,22 +158,11 @@ pub fn bucket_sort_inplace(arr: &mut [f64]) {
 /// - Average case: Θ(n)
 /// - Space: Θ(n)
 pub fn bucket_sort_merge_sort(arr: &[f64]) -> Vec<f64> {
-    let n = arr.len();
-
-    if n == 0 {
+    if arr.is_empty() {
         return vec![];
     }
 
-    let mut b: Vec<Vec<f64>> = vec![Vec::new(); n];
-
-    for &value in arr {
-        if !(0.0..1.0).contains(&value) {
-            panic!("Element {} is not in range [0.0, 1.0)", value);
-        }
-        let index = (n as f64 * value) as usize;
-        let index = index.min(n - 1);
-        b[index].push(value);
-    }
+    let mut b = scatter(arr);
 
     // Sort each bucket using merge sort
     for bucket in &mut b {
@@ -184,6 +223,8 @@ fn merge_buckets(arr: &mut [f64], mid: usize) {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
 
     #[test]
     fn test_bucket_sort_basic() {
@@ -256,4 +297,29 @@ mod tests {
         let sorted = bucket_sort(&arr);
         assert!(sorted.is_empty());
     }
+
+    #[test]
+    fn test_bucket_sort_with_stats() {
+        assert!(bucket_sort_with_stats(&mut []).is_empty());
+
+        let mut rng = StdRng::seed_from_u64(8);
+        let mut arr: Vec<f64> = (0..1000).map(|_| rng.gen()).collect();
+        let expected = bucket_sort(&arr);
+
+        let counts = bucket_sort_with_stats(&mut arr);
+        assert_eq!(arr, expected);
+        assert!(arr.windows(2).all(|w| w[0] <= w[1]));
+        assert_eq!(counts.len(), 1000);
+        assert_eq!(counts.iter().sum::<usize>(), 1000);
+
+        // Each n_i is Binomial(1000, 1/1000), so P(n_i > 8) is below 1e-5
+        // and the sum of squares should be near n (2 - 1/n).
+        assert!(
+            counts.iter().all(|&c| c <= 8),
+            "max {:?}",
+            counts.iter().max()
+        );
+        let sum_squares: usize = counts.iter().map(|&c| c * c).sum();
+        assert!((1800..2200).contains(&sum_squares), "{sum_squares}");
+    }
 }
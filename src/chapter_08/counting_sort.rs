@@ -64,6 +64,162 @@ pub fn counting_sort(arr: &[usize], k: usize) -> Vec<usize> {
     b
 }
 
+/// Sorts an array using counting sort and also returns the count array `C`
+///
+/// The returned `C` is the cumulative count array CLRS Section 8.2 builds
+/// before the placement loop (Figure 8.2(b)), where `C[i]` is the number of
+/// elements `<= i`. The placement loop runs on a working copy, so the
+/// returned array is not decremented.
+///
+/// # Arguments
+/// * `arr` - The array to be sorted (must contain integers in range [0, k])
+/// * `k` - The maximum value in the array (all elements must be <= k)
+///
+/// # Returns
+/// A tuple of the sorted output `B` and the cumulative count array `C`
+///
+/// # Complexity
+/// - Time: Θ(n + k)
+/// - Space: Θ(n + k)
+///
+/// # Example
+/// ```
+/// use clrs::chapter_08::counting_sort_with_counts;
+/// let (sorted, c) = counting_sort_with_counts(&[2, 5, 3, 0, 2, 3, 0, 3], 5);
+/// assert_eq!(sorted, vec![0, 0, 2, 2, 3, 3, 3, 5]);
+/// assert_eq!(c, vec![2, 2, 4, 7, 7, 8]);
+/// ```
+pub fn counting_sort_with_counts(arr: &[usize], k: usize) -> (Vec<usize>, Vec<usize>) {
+    let c = counting_sort_preprocess(arr, k);
+    let mut positions = c.clone();
+    let mut b = vec![0; arr.len()];
+
+    for &value in arr.iter().rev() {
+        b[positions[value] - 1] = value;
+        positions[value] -= 1;
+    }
+
+    (b, c)
+}
+
+/// Sorts an array of unsigned integers using counting sort, finding `k` itself
+///
+/// One pass converts the elements to `usize` and finds their maximum, which
+/// becomes the `k` passed to [`counting_sort`], so the count array has exactly
+/// `max + 1` entries. Any type that converts losslessly into `usize`, such as
+/// `u8`, `u16` or `usize`, can be sorted this way.
+///
+/// # Arguments
+/// * `arr` - The array to be sorted
+///
+/// # Returns
+/// A new sorted vector of the elements as `usize`; empty for an empty input
+///
+/// # Complexity
+/// - Time: Θ(n + k) where k is the largest element
+/// - Space: Θ(n + k)
+///
+/// # Example
+/// ```
+/// use clrs::chapter_08::counting_sort_auto;
+/// let arr: Vec<u8> = vec![5, 0, 5, 2];
+/// assert_eq!(counting_sort_auto(&arr), vec![0, 2, 5, 5]);
+/// ```
+pub fn counting_sort_auto<T: Into<usize> + Copy>(arr: &[T]) -> Vec<usize> {
+    let values: Vec<usize> = arr.iter().map(|&x| x.into()).collect();
+    match values.iter().max() {
+        Some(&k) => counting_sort(&values, k),
+        None => Vec::new(),
+    }
+}
+
+/// Sorts an array of integers into descending order using counting sort
+///
+/// This is COUNTING-SORT from CLRS Section 8.2 with the placement loop
+/// turned around; see [`counting_sort_desc_by_key`] for how stability is
+/// kept.
+///
+/// # Arguments
+/// * `arr` - The array to be sorted (must contain integers in range [0, k])
+/// * `k` - The maximum value in the array (all elements must be <= k)
+///
+/// # Returns
+/// A new vector sorted in descending order
+///
+/// # Complexity
+/// - Time: Θ(n + k)
+/// - Space: Θ(n + k)
+///
+/// # Example
+/// ```
+/// use clrs::chapter_08::counting_sort_desc;
+/// let arr = vec![2, 5, 3, 0, 2, 3];
+/// assert_eq!(counting_sort_desc(&arr, 5), vec![5, 3, 3, 2, 2, 0]);
+/// ```
+pub fn counting_sort_desc(arr: &[usize], k: usize) -> Vec<usize> {
+    counting_sort_desc_by_key(arr, k, |&value| value)
+}
+
+/// Stably sorts records into descending order of an integer key
+///
+/// With the cumulative counts `C[i]` (the number of keys <= i), the records
+/// with key `v` belong in the output positions `n - C[v]` up to
+/// `n - C[v - 1] - 1`. Walking the input forward and placing each record at
+/// `n - C[v]` before decrementing `C[v]` fills that block from its low end in
+/// input order, so equal keys keep their relative order.
+///
+/// # Arguments
+/// * `arr` - The records to be sorted
+/// * `k` - The maximum key (all keys must be <= k)
+/// * `key` - Extracts the integer key of a record
+///
+/// # Returns
+/// A new vector of the records sorted by descending key
+///
+/// # Panics
+/// Panics if some key exceeds `k`
+///
+/// # Complexity
+/// - Time: Θ(n + k)
+/// - Space: Θ(n + k)
+///
+/// # Example
+/// ```
+/// use clrs::chapter_08::counting_sort_desc_by_key;
+/// let scores = vec![("ann", 3), ("bob", 7), ("cy", 3)];
+/// let ranked = counting_sort_desc_by_key(&scores, 10, |&(_, score)| score);
+/// assert_eq!(ranked, vec![("bob", 7), ("ann", 3), ("cy", 3)]);
+/// ```
+pub fn counting_sort_desc_by_key<T, F>(arr: &[T], k: usize, key: F) -> Vec<T>
+where
+    T: Clone,
+    F: Fn(&T) -> usize,
+{
+    let n = arr.len();
+    let mut c = vec![0; k + 1];
+    for item in arr {
+        let value = key(item);
+        if value > k {
+            panic!("Element {} exceeds maximum value k = {}", value, k);
+        }
+        c[value] += 1;
+    }
+    for i in 1..=k {
+        c[i] += c[i - 1];
+    }
+
+    let mut b: Vec<Option<T>> = vec![None; n];
+    for item in arr {
+        let value = key(item);
+        b[n - c[value]] = Some(item.clone());
+        c[value] -= 1;
+    }
+
+    b.into_iter()
+        .map(|item| item.expect("every position is filled"))
+        .collect()
+}
+
 /// Sorts an array in-place using counting sort
 ///
 /// This version modifies the input array directly.
@@ -213,4 +369,44 @@ mod tests {
         // Query range [3..3]: elements with value 3
         assert_eq!(counting_sort_query(&c, 3, 3), 3);
     }
+
+    #[test]
+    fn test_counting_sort_with_counts_clrs_example() {
+        // Example from CLRS Figure 8.2
+        let arr = vec![2, 5, 3, 0, 2, 3, 0, 3];
+        let (sorted, c) = counting_sort_with_counts(&arr, 5);
+        assert_eq!(sorted, vec![0, 0, 2, 2, 3, 3, 3, 5]);
+        assert_eq!(c, vec![2, 2, 4, 7, 7, 8]);
+        assert_eq!(sorted, counting_sort(&arr, 5));
+    }
+
+    #[test]
+    fn test_counting_sort_auto() {
+        assert!(counting_sort_auto::<usize>(&[]).is_empty());
+        assert_eq!(counting_sort_auto(&[7u16]), vec![7]);
+        assert_eq!(counting_sort_auto(&[5usize, 0, 5, 2]), vec![0, 2, 5, 5]);
+        assert_eq!(counting_sort_auto(&[3u8, 255, 0, 3]), vec![0, 3, 3, 255]);
+    }
+
+    #[test]
+    fn test_counting_sort_desc() {
+        let arr = vec![2, 5, 3, 0, 2, 3];
+        assert_eq!(counting_sort_desc(&arr, 5), vec![5, 3, 3, 2, 2, 0]);
+        assert!(counting_sort_desc(&[], 5).is_empty());
+
+        // Tag each key with its input position to check stability
+        let tagged: Vec<(usize, char)> = arr.iter().copied().zip('a'..).collect();
+        let sorted = counting_sort_desc_by_key(&tagged, 5, |&(key, _)| key);
+        assert_eq!(
+            sorted,
+            vec![(5, 'b'), (3, 'c'), (3, 'f'), (2, 'a'), (2, 'e'), (0, 'd')]
+        );
+    }
+
+    #[test]
+    fn test_counting_sort_with_counts_empty() {
+        let (sorted, c) = counting_sort_with_counts(&[], 3);
+        assert!(sorted.is_empty());
+        assert_eq!(c, vec![0, 0, 0, 0]);
+    }
 }
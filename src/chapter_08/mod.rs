@@ -2,11 +2,14 @@
 //!
 //! This chapter covers non-comparison-based sorting algorithms that can
 //! achieve linear time complexity: counting sort, radix sort, and bucket sort.
+//! It also provides a run-time choice among all of the Part II sorts.
 
 pub mod bucket_sort;
 pub mod counting_sort;
 pub mod radix_sort;
+pub mod sort_algorithm;
 
 pub use bucket_sort::*;
 pub use counting_sort::*;
 pub use radix_sort::*;
+pub use sort_algorithm::*;
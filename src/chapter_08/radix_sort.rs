@@ -2,13 +2,20 @@
 //!
 //! Radix sort sorts on the least significant digit first, then the next,
 //! and so on. It uses a stable sort (like counting sort) as a subroutine.
+//! Variable-length strings are sorted most significant character first
+//! instead, by [`msd_radix_sort`].
 
-// Radix sort uses counting sort as a subroutine, but implements it inline
+use crate::chapter_02::insertion_sort_range;
+
+/// Number of distinct digit values in each radix sort pass (one byte)
+pub const RADIX_BASE: usize = 256;
 
 /// Sorts an array of integers using radix sort
 ///
 /// This corresponds to RADIX-SORT from CLRS Section 8.3.
 /// The algorithm sorts by processing digits from least significant to most.
+/// Digits are bytes (base 256), and every pass reuses the same scratch
+/// buffer and count array, so only one O(n) allocation is made in total.
 ///
 /// # Arguments
 /// * `arr` - The array to be sorted (must contain non-negative integers)
@@ -17,7 +24,7 @@
 /// A new sorted vector
 ///
 /// # Complexity
-/// - Time: Θ(d(n + k)) where d is the number of digits and k is the radix (10 for decimal)
+/// - Time: Θ(d(n + k)) where d is the number of bytes in the maximum and k = 256
 /// - Space: Θ(n + k)
 ///
 /// # Example
@@ -28,61 +35,73 @@
 /// assert_eq!(sorted, vec![329, 355, 436, 457, 657, 720, 839]);
 /// ```
 pub fn radix_sort(arr: &[usize]) -> Vec<usize> {
-    if arr.is_empty() {
-        return vec![];
-    }
-
-    // Find the maximum number to know number of digits
-    let max = *arr.iter().max().unwrap();
     let mut result = arr.to_vec();
-
-    // Do counting sort for every digit
-    // Instead of passing digit number, pass exp (10^i where i is current digit number)
-    let mut exp = 1;
-    while max / exp > 0 {
-        // Sort by current digit using counting sort
-        result = radix_sort_counting_sort_by_digit(&result, exp);
-        exp *= 10;
-    }
-
+    radix_sort_inplace(&mut result);
     result
 }
 
-/// Helper function: counting sort by a specific digit
+/// Stable counting sort of `src` into `dst` on the byte at bit offset `shift`
 ///
-/// Sorts the array based on the digit at position `exp` (1, 10, 100, ...)
-fn radix_sort_counting_sort_by_digit(arr: &[usize], exp: usize) -> Vec<usize> {
-    let n = arr.len();
-    let k = 9; // For decimal digits, range is 0-9
-
-    // Count occurrences of each digit
-    let mut c = vec![0; k + 1];
-
-    for &value in arr {
-        let digit = (value / exp) % 10;
-        c[digit] += 1;
+/// This is the COUNTING-SORT subroutine of RADIX-SORT with k = 255 and the
+/// sort key `(x >> shift) & 0xff`. The caller supplies `dst` and `counts`, so
+/// a sequence of passes can share them instead of allocating per pass;
+/// `counts` is cleared on entry.
+///
+/// # Arguments
+/// * `src` - The values to sort
+/// * `dst` - Receives `src` stably sorted by the selected byte; must have the
+///   same length as `src`
+/// * `counts` - Scratch space for the digit counts
+/// * `shift` - Bit offset of the digit, a multiple of 8
+///
+/// # Complexity
+/// - Time: Θ(n + k)
+///
+/// # Example
+/// ```
+/// use clrs::chapter_08::{counting_sort_pass, RADIX_BASE};
+/// let src = vec![0x0102, 0x0201, 0x0101];
+/// let mut dst = vec![0; 3];
+/// let mut counts = [0; RADIX_BASE];
+/// counting_sort_pass(&src, &mut dst, &mut counts, 0);
+/// assert_eq!(dst, vec![0x0201, 0x0101, 0x0102]);
+/// ```
+pub fn counting_sort_pass(
+    src: &[usize],
+    dst: &mut [usize],
+    counts: &mut [usize; RADIX_BASE],
+    shift: u32,
+) {
+    assert_eq!(
+        src.len(),
+        dst.len(),
+        "source and destination lengths differ"
+    );
+    let digit = |value: usize| (value >> shift) & (RADIX_BASE - 1);
+
+    counts.fill(0);
+    for &value in src {
+        counts[digit(value)] += 1;
     }
 
-    // Make cumulative
-    for i in 1..=k {
-        c[i] += c[i - 1];
+    // Make cumulative: counts[d] is one past the last slot for digit d
+    for d in 1..RADIX_BASE {
+        counts[d] += counts[d - 1];
     }
 
-    // Build output array
-    let mut b = vec![0; n];
-
     // Process in reverse to maintain stability
-    for &value in arr.iter().rev() {
-        let digit = (value / exp) % 10;
-        b[c[digit] - 1] = value;
-        c[digit] -= 1;
+    for &value in src.iter().rev() {
+        let d = digit(value);
+        counts[d] -= 1;
+        dst[counts[d]] = value;
     }
-
-    b
 }
 
 /// Sorts an array in-place using radix sort
 ///
+/// Passes alternate between `arr` and a single scratch buffer; the result is
+/// copied back only if it ends in the scratch buffer.
+///
 /// # Arguments
 /// * `arr` - The array to be sorted (modified in-place)
 ///
@@ -94,8 +113,28 @@ fn radix_sort_counting_sort_by_digit(arr: &[usize], exp: usize) -> Vec<usize> {
 /// assert_eq!(arr, vec![329, 355, 436, 457, 657, 720, 839]);
 /// ```
 pub fn radix_sort_inplace(arr: &mut [usize]) {
-    let sorted = radix_sort(arr);
-    arr.copy_from_slice(&sorted);
+    let Some(&max) = arr.iter().max() else {
+        return;
+    };
+
+    let mut scratch = vec![0; arr.len()];
+    let mut counts = [0; RADIX_BASE];
+    let mut sorted_in_arr = true;
+
+    let mut shift = 0;
+    while shift < usize::BITS && max >> shift > 0 {
+        if sorted_in_arr {
+            counting_sort_pass(arr, &mut scratch, &mut counts, shift);
+        } else {
+            counting_sort_pass(&scratch, arr, &mut counts, shift);
+        }
+        sorted_in_arr = !sorted_in_arr;
+        shift += 8;
+    }
+
+    if !sorted_in_arr {
+        arr.copy_from_slice(&scratch);
+    }
 }
 
 /// Sorts integers in range [0, n³ - 1] in O(n) time (Exercise 8.3-4)
@@ -210,6 +249,80 @@ fn radix_sort_base_n_by_digit(
     b
 }
 
+/// Buckets at or below this size are finished with insertion sort
+const MSD_INSERTION_CUTOFF: usize = 16;
+
+/// Sorts strings lexicographically using most-significant-digit radix sort
+///
+/// The strings are distributed into buckets by their first byte, each bucket
+/// is sorted recursively on the next byte, and so on. A string that has
+/// ended falls into a bucket of its own that precedes every byte value, so a
+/// proper prefix sorts before its extensions. Comparing UTF-8 bytes gives the
+/// same order as comparing `String`s. Buckets of at most 16 strings are
+/// finished with insertion sort, which beats another 256-way distribution on
+/// so few elements.
+///
+/// # Arguments
+/// * `arr` - The strings to sort (modified in-place)
+///
+/// # Complexity
+/// - Time: O(D + n k) where D is the total length of the distinguishing
+///   prefixes and k = 256 per distribution pass
+/// - Space: O(n + k) besides the recursion stack of depth at most the
+///   longest distinguishing prefix
+///
+/// # Example
+/// ```
+/// use clrs::chapter_08::msd_radix_sort;
+/// let mut arr = vec!["ab".to_string(), "abc".to_string(), "a".to_string()];
+/// msd_radix_sort(&mut arr);
+/// assert_eq!(arr, vec!["a", "ab", "abc"]);
+/// ```
+pub fn msd_radix_sort(arr: &mut [String]) {
+    let mut scratch = Vec::with_capacity(arr.len());
+    msd_radix_sort_by_byte(arr, 0, &mut scratch);
+}
+
+/// Sorts strings that agree on their first `depth` bytes
+fn msd_radix_sort_by_byte(arr: &mut [String], depth: usize, scratch: &mut Vec<String>) {
+    let n = arr.len();
+    if n <= MSD_INSERTION_CUTOFF {
+        if n > 1 {
+            insertion_sort_range(arr, 0, n - 1);
+        }
+        return;
+    }
+
+    // Symbol 0 marks the end of the string; byte b is symbol b + 1
+    let symbol = |s: &String| s.as_bytes().get(depth).map_or(0, |&b| b as usize + 1);
+
+    // counts[c + 1] counts symbol c, so the prefix sums start each bucket
+    let mut counts = [0; RADIX_BASE + 2];
+    for s in arr.iter() {
+        counts[symbol(s) + 1] += 1;
+    }
+    for c in 1..counts.len() {
+        counts[c] += counts[c - 1];
+    }
+
+    scratch.clear();
+    scratch.resize_with(n, String::new);
+    for s in arr.iter_mut() {
+        let c = symbol(s);
+        scratch[counts[c]] = std::mem::take(s);
+        counts[c] += 1;
+    }
+    arr.swap_with_slice(scratch);
+
+    // counts[c] now ends bucket c; the ended strings in bucket 0 are equal
+    for c in 1..=RADIX_BASE {
+        let bucket = counts[c - 1]..counts[c];
+        if bucket.len() > 1 {
+            msd_radix_sort_by_byte(&mut arr[bucket], depth + 1, scratch);
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -263,4 +376,64 @@ mod tests {
         let sorted = radix_sort(&arr);
         assert!(sorted.is_empty());
     }
+
+    #[test]
+    fn test_radix_sort_matches_std_sort() {
+        use rand::Rng;
+
+        let mut rng = rand::thread_rng();
+        for len in [1, 2, 10, 1000] {
+            let arr: Vec<usize> = (0..len).map(|_| rng.gen()).collect();
+            let mut expected = arr.clone();
+            expected.sort();
+            assert_eq!(radix_sort(&arr), expected);
+        }
+        let arr = vec![usize::MAX, 0, 1 << 40, 255, 256];
+        let mut expected = arr.clone();
+        expected.sort();
+        assert_eq!(radix_sort(&arr), expected);
+    }
+
+    #[test]
+    fn test_radix_sort_inplace_random_u32() {
+        use rand::rngs::StdRng;
+        use rand::{Rng, SeedableRng};
+
+        let mut rng = StdRng::seed_from_u64(390);
+        let mut arr: Vec<usize> = (0..5000).map(|_| rng.gen::<u32>() as usize).collect();
+        let mut expected = arr.clone();
+        expected.sort_unstable();
+        radix_sort_inplace(&mut arr);
+        assert_eq!(arr, expected);
+    }
+
+    #[test]
+    fn test_msd_radix_sort_matches_std_sort() {
+        use rand::rngs::StdRng;
+        use rand::seq::SliceRandom;
+        use rand::{Rng, SeedableRng};
+
+        let mut arr = vec!["ab".to_string(), "abc".to_string(), "a".to_string()];
+        msd_radix_sort(&mut arr);
+        assert_eq!(arr, vec!["a", "ab", "abc"]);
+
+        // A small alphabet forces long shared prefixes and many duplicates;
+        // the multi-byte characters check that byte order is string order.
+        let alphabet = ['a', 'b', 'c', 'z', 'é', 'ß', '中'];
+        let mut rng = StdRng::seed_from_u64(414);
+        for len in [0, 1, 2, 17, 100, 5000] {
+            let mut arr: Vec<String> = (0..len)
+                .map(|_| {
+                    let length = rng.gen_range(0..12);
+                    (0..length)
+                        .map(|_| *alphabet.choose(&mut rng).unwrap())
+                        .collect()
+                })
+                .collect();
+            let mut expected = arr.clone();
+            expected.sort();
+            msd_radix_sort(&mut arr);
+            assert_eq!(arr, expected);
+        }
+    }
 }
@@ -0,0 +1,181 @@
+//! Choosing a Sorting Algorithm at Run Time
+//!
+//! Part II of CLRS presents several sorting algorithms with different
+//! trade-offs. This module gathers them behind one entry point so that they
+//! can be selected, and compared, on the same inputs.
+
+use crate::chapter_02::{insertion_sort, merge_sort_full, selection_sort};
+use crate::chapter_06::heapsort;
+use crate::chapter_07::{quicksort_full, randomized_quicksort_full};
+
+use super::{counting_sort_inplace, radix_sort_inplace};
+
+/// A sorting algorithm from Chapters 2 and 6 through 8
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SortAlgorithm {
+    /// INSERTION-SORT (Section 2.1)
+    Insertion,
+    /// Selection sort (Exercise 2.2-2)
+    Selection,
+    /// MERGE-SORT (Section 2.3)
+    Merge,
+    /// HEAPSORT (Section 6.4)
+    Heap,
+    /// QUICKSORT (Section 7.1)
+    Quick,
+    /// RANDOMIZED-QUICKSORT (Section 7.3)
+    RandomizedQuick,
+    /// COUNTING-SORT (Section 8.2); integer keys only
+    Counting,
+    /// RADIX-SORT (Section 8.3); integer keys only
+    Radix,
+}
+
+impl SortAlgorithm {
+    /// Every algorithm, comparison sorts first
+    pub const ALL: [SortAlgorithm; 8] = [
+        SortAlgorithm::Insertion,
+        SortAlgorithm::Selection,
+        SortAlgorithm::Merge,
+        SortAlgorithm::Heap,
+        SortAlgorithm::Quick,
+        SortAlgorithm::RandomizedQuick,
+        SortAlgorithm::Counting,
+        SortAlgorithm::Radix,
+    ];
+
+    /// Returns true if the algorithm only compares elements, so that it can
+    /// sort any `Ord` type through [`sort`]
+    pub fn is_comparison_sort(self) -> bool {
+        !matches!(self, SortAlgorithm::Counting | SortAlgorithm::Radix)
+    }
+}
+
+/// Sorts an array with the chosen comparison sort
+///
+/// # Arguments
+/// * `arr` - The array to be sorted (modified in-place)
+/// * `algo` - The algorithm to run
+///
+/// # Panics
+/// Panics if `algo` is `Counting` or `Radix`, which need integer keys; use
+/// [`sort_usize`] for those.
+///
+/// # Example
+/// ```
+/// use clrs::chapter_08::{sort, SortAlgorithm};
+/// let mut arr = vec!["pear", "fig", "apple"];
+/// sort(&mut arr, SortAlgorithm::Heap);
+/// assert_eq!(arr, vec!["apple", "fig", "pear"]);
+/// ```
+pub fn sort<T: Ord + Clone>(arr: &mut [T], algo: SortAlgorithm) {
+    match algo {
+        SortAlgorithm::Insertion => insertion_sort(arr),
+        SortAlgorithm::Selection => selection_sort(arr),
+        SortAlgorithm::Merge => merge_sort_full(arr),
+        SortAlgorithm::Heap => heapsort(arr),
+        SortAlgorithm::Quick => quicksort_full(arr),
+        SortAlgorithm::RandomizedQuick => randomized_quicksort_full(arr),
+        SortAlgorithm::Counting | SortAlgorithm::Radix => {
+            panic!("{algo:?} sort needs integer keys; use sort_usize")
+        }
+    }
+}
+
+/// Largest key [`sort_usize`] always hands to counting sort, however short
+/// the array
+pub const COUNTING_SORT_MIN_RANGE: usize = 1 << 16;
+
+/// Sorts an array of non-negative integers with any of the algorithms
+///
+/// Counting sort uses the array maximum as its key bound k, so its running
+/// time and its count array are Θ(n + k). When k exceeds both 4n and
+/// [`COUNTING_SORT_MIN_RANGE`], a count array that large would dwarf the
+/// input, or not fit in memory at all for keys near `usize::MAX`, so radix
+/// sort runs instead.
+///
+/// # Arguments
+/// * `arr` - The array to be sorted (modified in-place)
+/// * `algo` - The algorithm to run
+///
+/// # Example
+/// ```
+/// use clrs::chapter_08::{sort_usize, SortAlgorithm};
+/// let mut arr = vec![329, 457, 657, 839, 436, 720, 355];
+/// sort_usize(&mut arr, SortAlgorithm::Radix);
+/// assert_eq!(arr, vec![329, 355, 436, 457, 657, 720, 839]);
+/// ```
+pub fn sort_usize(arr: &mut [usize], algo: SortAlgorithm) {
+    match algo {
+        SortAlgorithm::Counting => {
+            let k = arr.iter().copied().max().unwrap_or(0);
+            if k <= COUNTING_SORT_MIN_RANGE.max(arr.len().saturating_mul(4)) {
+                counting_sort_inplace(arr, k);
+            } else {
+                radix_sort_inplace(arr);
+            }
+        }
+        SortAlgorithm::Radix => radix_sort_inplace(arr),
+        _ => sort(arr, algo),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+
+    #[test]
+    fn test_comparison_sorts_agree() {
+        let mut rng = StdRng::seed_from_u64(419);
+        let words: Vec<String> = (0..300)
+            .map(|_| {
+                let length = rng.gen_range(0..6);
+                (0..length).map(|_| rng.gen_range('a'..='e')).collect()
+            })
+            .collect();
+        let mut expected = words.clone();
+        expected.sort();
+
+        for algo in SortAlgorithm::ALL {
+            if !algo.is_comparison_sort() {
+                continue;
+            }
+            let mut arr = words.clone();
+            sort(&mut arr, algo);
+            assert_eq!(arr, expected, "{algo:?}");
+
+            let mut empty: Vec<String> = Vec::new();
+            sort(&mut empty, algo);
+            assert!(empty.is_empty());
+        }
+    }
+
+    #[test]
+    fn test_sort_usize_covers_every_algorithm() {
+        let mut rng = StdRng::seed_from_u64(8);
+        let values: Vec<usize> = (0..500).map(|_| rng.gen_range(0..1000)).collect();
+        let mut expected = values.clone();
+        expected.sort();
+
+        for algo in SortAlgorithm::ALL {
+            let mut arr = values.clone();
+            sort_usize(&mut arr, algo);
+            assert_eq!(arr, expected, "{algo:?}");
+        }
+    }
+
+    #[test]
+    fn test_sort_usize_counting_handles_huge_keys() {
+        let mut arr = vec![usize::MAX, 3, usize::MAX - 1, 0, 1 << 40, 3];
+        sort_usize(&mut arr, SortAlgorithm::Counting);
+        assert_eq!(arr, vec![0, 3, 3, 1 << 40, usize::MAX - 1, usize::MAX]);
+    }
+
+    #[test]
+    #[should_panic(expected = "needs integer keys")]
+    fn test_sort_rejects_counting_for_generic_keys() {
+        sort(&mut ['b', 'a'], SortAlgorithm::Counting);
+    }
+}
@@ -3,13 +3,17 @@
 //! This module contains RANDOMIZED-SELECT, which finds the ith smallest
 //! element in expected linear time.
 
-use crate::chapter_07::randomized_quicksort::randomized_partition;
+use rand::Rng;
+
+use crate::chapter_07::partition_three_way;
 
 /// Finds the ith smallest element using randomized select
 ///
 /// This corresponds to RANDOMIZED-SELECT from CLRS Section 9.2.
 /// The algorithm uses randomized partition to find the ith order statistic
-/// in expected O(n) time.
+/// in expected O(n) time. The partition is three-way, so every element equal
+/// to the pivot is excluded from further recursion and duplicate-heavy
+/// inputs stay linear.
 ///
 /// # Arguments
 /// * `arr` - The array to search (modified in-place)
@@ -37,28 +41,39 @@ pub fn randomized_select<T: Ord + Clone>(arr: &mut [T], p: usize, r: usize, i: u
         return arr[p].clone();
     }
 
-    // CLRS: q = RANDOMIZED-PARTITION(A, p, r)
-    let q = randomized_partition(arr, p, r);
+    // CLRS: q = RANDOMIZED-PARTITION(A, p, r), widened to the band A[lt..=gt]
+    // of elements equal to the pivot
+    let (lt, gt) = randomized_partition_three_way(arr, p, r);
 
-    // CLRS: k = q - p + 1
-    let k = q - p + 1;
+    // Ranks (within A[p..r]) of the first and last pivot-equal elements
+    let first = lt - p + 1;
+    let last = gt - p + 1;
 
-    // CLRS: if i == k
-    if i == k {
-        // CLRS: return A[q]
-        return arr[q].clone();
-    }
-
-    // CLRS: else if i < k
-    if i < k {
+    if i < first {
         // CLRS: return RANDOMIZED-SELECT(A, p, q - 1, i)
-        randomized_select(arr, p, q - 1, i)
-    } else {
+        randomized_select(arr, p, lt - 1, i)
+    } else if i > last {
         // CLRS: else return RANDOMIZED-SELECT(A, q + 1, r, i - k)
-        randomized_select(arr, q + 1, r, i - k)
+        randomized_select(arr, gt + 1, r, i - last)
+    } else {
+        // CLRS: return A[q]
+        arr[lt].clone()
     }
 }
 
+/// Partitions A[p..r] around a random pivot into three bands: elements less
+/// than, equal to and greater than the pivot.
+///
+/// Returns `(lt, gt)` such that A[p..lt] < pivot, A[lt..=gt] == pivot and
+/// A[gt+1..=r] > pivot. Excluding the whole equal band from recursion keeps
+/// RANDOMIZED-SELECT linear on inputs with many duplicates, where Lomuto's
+/// PARTITION degrades to Θ(n²) time and Θ(n) recursion depth.
+fn randomized_partition_three_way<T: Ord>(arr: &mut [T], p: usize, r: usize) -> (usize, usize) {
+    let pivot_index = rand::thread_rng().gen_range(p..=r);
+    arr.swap(r, pivot_index);
+    partition_three_way(arr, p, r)
+}
+
 /// Iterative version of RANDOMIZED-SELECT (Exercise 9.2-3)
 ///
 /// This avoids recursion overhead and uses constant space.
@@ -89,16 +104,17 @@ pub fn randomized_select_iterative<T: Ord + Clone>(
             return arr[p].clone();
         }
 
-        let q = randomized_partition(arr, p, r);
-        let k = q - p + 1;
+        let (lt, gt) = randomized_partition_three_way(arr, p, r);
+        let first = lt - p + 1;
+        let last = gt - p + 1;
 
-        if i == k {
-            return arr[q].clone();
-        } else if i < k {
-            r = q - 1;
+        if i < first {
+            r = lt - 1;
+        } else if i > last {
+            p = gt + 1;
+            i -= last;
         } else {
-            p = q + 1;
-            i -= k;
+            return arr[lt].clone();
         }
     }
 }
@@ -152,6 +168,44 @@ pub fn randomized_median<T: Ord + Clone>(arr: &mut [T]) -> T {
     randomized_select_full(arr, i)
 }
 
+/// Returns the k smallest elements in sorted order
+///
+/// Uses RANDOMIZED-SELECT to partition a copy of the array around its kth
+/// smallest element, which leaves the k smallest elements in the first k
+/// positions, and then sorts only those.
+///
+/// # Arguments
+/// * `arr` - The input array (not modified)
+/// * `k` - How many elements to return; values above `arr.len()` return all
+///
+/// # Returns
+/// The k smallest elements in nondecreasing order
+///
+/// # Complexity
+/// - Expected time: O(n + k lg k)
+/// - Space: O(n) for the copy
+///
+/// # Example
+/// ```
+/// use clrs::chapter_09::k_smallest;
+/// let arr = vec![3, 1, 4, 1, 5, 9, 2, 6];
+/// assert_eq!(k_smallest(&arr, 3), vec![1, 1, 2]);
+/// ```
+pub fn k_smallest<T: Ord + Clone>(arr: &[T], k: usize) -> Vec<T> {
+    let mut copy = arr.to_vec();
+    let k = k.min(copy.len());
+    if k == 0 {
+        return Vec::new();
+    }
+    if k < copy.len() {
+        let r = copy.len() - 1;
+        randomized_select(&mut copy, 0, r, k);
+        copy.truncate(k);
+    }
+    copy.sort();
+    copy
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -198,4 +252,46 @@ mod tests {
         let third = randomized_select_full(&mut arr, 3);
         assert_eq!(third, 3);
     }
+
+    #[test]
+    fn test_randomized_select_all_equal() {
+        let mut arr = vec![7u8; 100_000];
+        assert_eq!(randomized_median(&mut arr), 7);
+        assert_eq!(randomized_select_iterative(&mut arr, 0, 99_999, 1), 7);
+    }
+
+    #[test]
+    fn test_randomized_select_many_duplicates() {
+        let original: Vec<u32> = (0..10_000).map(|x| x % 3).collect();
+        let mut sorted = original.clone();
+        sorted.sort();
+        for &i in &[1, 3_333, 3_334, 5_000, 6_667, 6_668, 10_000] {
+            let mut arr = original.clone();
+            assert_eq!(randomized_select_full(&mut arr, i), sorted[i - 1]);
+            let mut arr = original.clone();
+            assert_eq!(
+                randomized_select_iterative(&mut arr, 0, 9_999, i),
+                sorted[i - 1]
+            );
+        }
+    }
+
+    #[test]
+    fn test_k_smallest_matches_sort_truncate() {
+        use rand::Rng;
+
+        let mut rng = rand::thread_rng();
+        for _ in 0..50 {
+            let n = rng.gen_range(0..200);
+            let arr: Vec<i32> = (0..n).map(|_| rng.gen_range(-20..20)).collect();
+            let k = rng.gen_range(0..=n + 5);
+
+            let mut expected = arr.clone();
+            expected.sort();
+            expected.truncate(k);
+            assert_eq!(k_smallest(&arr, k), expected);
+        }
+        assert!(k_smallest(&[3, 1, 2], 0).is_empty());
+        assert_eq!(k_smallest(&[3, 1, 2], 10), vec![1, 2, 3]);
+    }
 }
@@ -3,7 +3,10 @@
 //! This module contains SELECT, which finds the ith smallest element
 //! in worst-case O(n) time using the median-of-medians algorithm.
 
-use crate::chapter_07::partition::partition;
+use rand::Rng;
+
+use crate::chapter_02::insertion_sort_range;
+use crate::chapter_07::partition::{partition, partition_three_way};
 
 /// Finds the median of a small array using insertion sort
 ///
@@ -181,6 +184,170 @@ pub fn median<T: Ord + Clone>(arr: &mut [T]) -> T {
     select_full(arr, i)
 }
 
+/// Finds the ith smallest element in place and returns its index
+///
+/// A variant of SELECT that needs neither `Clone` nor O(n) scratch space.
+/// Each group of 5 is sorted in place and its median swapped to the front of
+/// the subarray, so the median-of-medians recursion runs on that prefix.
+/// Partitioning is three-way, so runs of equal keys cannot unbalance it.
+///
+/// On return `arr` is partitioned around the returned index `k`: every
+/// element before it is `<= arr[k]`, every element after it is `>= arr[k]`,
+/// and `arr[k]` is the ith smallest element.
+///
+/// # Arguments
+/// * `arr` - The array to search (reordered in-place)
+/// * `i` - The order statistic to find (1-based: 1 = minimum, n = maximum)
+///
+/// # Returns
+/// The index holding the ith smallest element, which is always `i - 1`
+///
+/// # Complexity
+/// - Time: O(n) worst-case
+/// - Space: O(lg n) for the median-of-medians recursion, no heap allocation
+///
+/// # Panics
+/// Panics if `i` is not in `1..=arr.len()`.
+///
+/// # Example
+/// ```
+/// use clrs::chapter_09::select_in_place;
+/// let mut arr = vec![3, 1, 4, 1, 5, 9, 2, 6];
+/// let k = select_in_place(&mut arr, 4);
+/// assert_eq!(arr[k], 3);
+/// ```
+pub fn select_in_place<T: Ord>(arr: &mut [T], i: usize) -> usize {
+    assert!(
+        i >= 1 && i <= arr.len(),
+        "Order statistic i must be between 1 and {}",
+        arr.len()
+    );
+    select_rank(arr, i - 1)
+}
+
+/// Moves the element of 0-based `rank` into `arr[rank]` and returns `rank`.
+fn select_rank<T: Ord>(arr: &mut [T], rank: usize) -> usize {
+    let mut window = arr;
+    let mut offset = 0;
+    let mut rank = rank;
+
+    loop {
+        let n = window.len();
+        if n <= 5 {
+            insertion_sort_range(window, 0, n - 1);
+            return offset + rank;
+        }
+
+        // Sort each group of 5 and gather the group medians at the front.
+        let num_groups = n.div_ceil(5);
+        for g in 0..num_groups {
+            let start = g * 5;
+            let end = (start + 5).min(n);
+            insertion_sort_range(window, start, end - 1);
+            window.swap(g, start + (end - start - 1) / 2);
+        }
+
+        // Median of the medians becomes the pivot.
+        let pivot = select_rank(&mut window[..num_groups], (num_groups - 1) / 2);
+        window.swap(pivot, n - 1);
+        let (lt, gt) = partition_three_way(window, 0, n - 1);
+
+        if rank < lt {
+            window = &mut window[..lt];
+        } else if rank > gt {
+            window = &mut window[gt + 1..];
+            offset += gt + 1;
+            rank -= gt + 1;
+        } else {
+            return offset + rank;
+        }
+    }
+}
+
+/// Finds the ith smallest element with randomized pivots and a linear-time
+/// fallback
+///
+/// An introselect: each round partitions the remaining window around a
+/// random pivot, as in RANDOMIZED-SELECT, so the expected running time is
+/// that of randomized selection. Unlucky pivots are caught by charging every
+/// round the length of the window it partitions against a budget of `3n`
+/// element visits. Once a round would exceed the budget, the remaining window
+/// is handed to the median-of-medians SELECT. A plain bound on the number of
+/// rounds is not enough: 2 lg n rounds that each peel off one element still
+/// cost Θ(n lg n), whereas the budget caps the randomized phase at O(n).
+///
+/// Partitioning is three-way, so runs of equal keys end a round early.
+///
+/// # Arguments
+/// * `arr` - The array to search (reordered in-place)
+/// * `i` - The order statistic to find (1-based: 1 = minimum, n = maximum)
+///
+/// # Returns
+/// A reference to the ith smallest element, which ends up at `arr[i - 1]`
+///
+/// # Complexity
+/// - Time: O(n) expected with a small constant, O(n) worst-case
+/// - Space: O(lg n) for the fallback's recursion, no heap allocation
+///
+/// # Panics
+/// Panics if `i` is not in `1..=arr.len()`.
+///
+/// # Example
+/// ```
+/// use clrs::chapter_09::select_introselect;
+/// let mut arr = vec![3, 1, 4, 1, 5, 9, 2, 6];
+/// assert_eq!(*select_introselect(&mut arr, 5), 4);
+/// ```
+pub fn select_introselect<T: Ord>(arr: &mut [T], i: usize) -> &T {
+    assert!(
+        i >= 1 && i <= arr.len(),
+        "Order statistic i must be between 1 and {}",
+        arr.len()
+    );
+    let mut rng = rand::thread_rng();
+    let k = introselect_rank(arr, i - 1, |window| rng.gen_range(0..window.len()));
+    &arr[k]
+}
+
+/// Moves the element of 0-based `rank` into `arr[rank]` and returns `rank`,
+/// asking `pick_pivot` for a pivot index within each window until the work
+/// budget runs out.
+fn introselect_rank<T: Ord>(
+    arr: &mut [T],
+    rank: usize,
+    mut pick_pivot: impl FnMut(&[T]) -> usize,
+) -> usize {
+    let mut budget = 3 * arr.len();
+    let mut window = arr;
+    let mut offset = 0;
+    let mut rank = rank;
+
+    loop {
+        let n = window.len();
+        if n <= 5 {
+            insertion_sort_range(window, 0, n - 1);
+            return offset + rank;
+        }
+        if n > budget {
+            return offset + select_rank(window, rank);
+        }
+        budget -= n;
+
+        let pivot = pick_pivot(window);
+        window.swap(pivot, n - 1);
+        let (lt, gt) = partition_three_way(window, 0, n - 1);
+        if rank < lt {
+            window = &mut window[..lt];
+        } else if rank > gt {
+            window = &mut window[gt + 1..];
+            offset += gt + 1;
+            rank -= gt + 1;
+        } else {
+            return offset + rank;
+        }
+    }
+}
+
 /// Makes quicksort run in O(n lg n) worst-case (Exercise 9.3-3)
 ///
 /// Uses SELECT to find the median as pivot, guaranteeing balanced partitions.
@@ -259,4 +426,63 @@ mod tests {
         quicksort_with_median_pivot(&mut arr, 0, 7);
         assert_eq!(arr, vec![1, 1, 2, 3, 4, 5, 6, 9]);
     }
+
+    #[test]
+    fn test_select_in_place_every_rank() {
+        use rand::seq::SliceRandom;
+
+        let mut rng = rand::thread_rng();
+        for i in 1..=50 {
+            let mut arr: Vec<u32> = (0..50).collect();
+            arr.shuffle(&mut rng);
+            let k = select_in_place(&mut arr, i);
+            assert_eq!(arr[k], (i - 1) as u32);
+            assert!(arr[..k].iter().all(|x| *x < arr[k]));
+            assert!(arr[k + 1..].iter().all(|x| *x > arr[k]));
+        }
+    }
+
+    #[test]
+    fn test_select_in_place_without_clone() {
+        #[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
+        struct Key(u32);
+
+        let mut arr: Vec<Key> = (0..1000).map(|x| Key(x % 7)).collect();
+        let k = select_in_place(&mut arr, 500);
+        assert_eq!(arr[k], Key(3));
+
+        let mut equal: Vec<Key> = (0..1000).map(|_| Key(1)).collect();
+        assert_eq!(select_in_place(&mut equal, 1000), 999);
+    }
+
+    #[test]
+    fn test_select_introselect() {
+        let mut arr = vec![3, 1, 4, 1, 5, 9, 2, 6];
+        assert_eq!(*select_introselect(&mut arr, 1), 1);
+        assert_eq!(*select_introselect(&mut arr, 8), 9);
+
+        let mut equal = vec![7; 1000];
+        assert_eq!(*select_introselect(&mut equal, 500), 7);
+    }
+
+    #[test]
+    fn test_introselect_falls_back_on_adversarial_pivots() {
+        // Always pivoting on the maximum peels off a single element per
+        // round, the Θ(n²) worst case of RANDOMIZED-SELECT.
+        let n = 200_000;
+        let mut arr: Vec<u32> = (0..n as u32).collect();
+        let mut rounds = 0;
+        let k = introselect_rank(&mut arr, 0, |window| {
+            rounds += 1;
+            (0..window.len()).max_by_key(|&j| window[j]).unwrap()
+        });
+        assert_eq!(k, 0);
+        assert_eq!(arr[0], 0);
+        // The 3n budget admits only three such rounds before SELECT takes over
+        assert_eq!(rounds, 3);
+
+        let mut arr: Vec<u32> = (0..n as u32).rev().collect();
+        let median = *select_introselect(&mut arr, n / 2);
+        assert_eq!(median, (n / 2 - 1) as u32);
+    }
 }
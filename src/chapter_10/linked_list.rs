@@ -2,6 +2,30 @@
 //!
 //! This module contains implementations of singly and doubly linked lists.
 
+use std::fmt;
+
+/// Error returned when a position lies past the end of a list
+///
+/// `len` is the length of the list, so valid insertion positions are
+/// `0..=len`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OutOfBounds {
+    pub index: usize,
+    pub len: usize,
+}
+
+impl fmt::Display for OutOfBounds {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "index {} out of bounds for a list of length {}",
+            self.index, self.len
+        )
+    }
+}
+
+impl std::error::Error for OutOfBounds {}
+
 /// Node in a singly linked list
 #[derive(Debug, Clone)]
 pub struct ListNode<T> {
@@ -85,6 +109,72 @@ impl<T: PartialEq> SinglyLinkedList<T> {
         self.head = Some(new_node);
     }
 
+    /// Returns the key at position `index`, counting from the head at 0
+    ///
+    /// # Complexity
+    /// - Time: O(index)
+    ///
+    /// # Example
+    /// ```
+    /// use clrs::chapter_10::SinglyLinkedList;
+    /// let mut list = SinglyLinkedList::new();
+    /// list.insert(2);
+    /// list.insert(1);
+    /// assert_eq!(list.get(1), Some(&2));
+    /// assert_eq!(list.get(2), None);
+    /// ```
+    pub fn get(&self, index: usize) -> Option<&T> {
+        let mut current = self.head.as_ref();
+        for _ in 0..index {
+            current = current?.next.as_ref();
+        }
+        current.map(|node| &node.key)
+    }
+
+    /// Inserts a key so that it ends up at position `index`
+    ///
+    /// Index 0 inserts at the head like [`insert`](Self::insert), and an
+    /// index equal to the length of the list appends at the tail.
+    ///
+    /// # Arguments
+    /// * `index` - The position the key will occupy
+    /// * `x` - The key to insert
+    ///
+    /// # Returns
+    /// `Err(OutOfBounds)` if `index` is greater than the length of the list,
+    /// in which case the list is unchanged
+    ///
+    /// # Complexity
+    /// - Time: O(index)
+    ///
+    /// # Example
+    /// ```
+    /// use clrs::chapter_10::SinglyLinkedList;
+    /// let mut list = SinglyLinkedList::new();
+    /// list.insert(3);
+    /// list.insert(1);
+    /// list.insert_at(1, 2).unwrap();
+    /// assert_eq!(list.get(1), Some(&2));
+    /// assert!(list.insert_at(5, 4).is_err());
+    /// ```
+    pub fn insert_at(&mut self, index: usize, x: T) -> Result<(), OutOfBounds> {
+        let mut link = &mut self.head;
+        for position in 0..index {
+            match link {
+                Some(node) => link = &mut node.next,
+                None => {
+                    return Err(OutOfBounds {
+                        index,
+                        len: position,
+                    })
+                }
+            }
+        }
+        let next = link.take();
+        *link = Some(Box::new(ListNode { key: x, next }));
+        Ok(())
+    }
+
     /// Deletes a node with the given key
     ///
     /// This corresponds to LIST-DELETE from CLRS Section 10.2.
@@ -122,6 +212,54 @@ impl<T: PartialEq> SinglyLinkedList<T> {
 
         false
     }
+
+    /// Merges two sorted lists into one sorted list
+    ///
+    /// The nodes of both lists are relinked in place; no key is moved or
+    /// copied. When keys compare equal, those from `self` come first, so the
+    /// merge is stable. Either list may be empty.
+    ///
+    /// # Arguments
+    /// * `other` - A second list sorted in nondecreasing order
+    ///
+    /// # Returns
+    /// A list holding every node of both lists in nondecreasing order
+    ///
+    /// # Complexity
+    /// - Time: O(n + m)
+    /// - Space: O(1)
+    ///
+    /// # Example
+    /// ```
+    /// use clrs::chapter_10::SinglyLinkedList;
+    /// let mut odds = SinglyLinkedList::new();
+    /// odds.insert(3);
+    /// odds.insert(1);
+    /// let mut evens = SinglyLinkedList::new();
+    /// evens.insert(2);
+    /// let merged = odds.merge_sorted(evens);
+    /// let head = merged.head.as_ref().unwrap();
+    /// assert_eq!((head.key, head.next.as_ref().unwrap().key), (1, 2));
+    /// ```
+    pub fn merge_sorted(self, other: Self) -> Self
+    where
+        T: Ord,
+    {
+        let mut a = self.head;
+        let mut b = other.head;
+        let mut head = None;
+        let mut tail = &mut head;
+
+        while let (Some(x), Some(y)) = (&a, &b) {
+            let source = if x.key <= y.key { &mut a } else { &mut b };
+            let mut node = source.take().expect("both lists are nonempty");
+            *source = node.next.take();
+            tail = &mut tail.insert(node).next;
+        }
+        *tail = a.or(b);
+
+        SinglyLinkedList { head }
+    }
 }
 
 impl<T: PartialEq> Default for SinglyLinkedList<T> {
@@ -363,4 +501,65 @@ mod tests {
         assert!(list.search(2).is_some());
         assert!(list.search(3).is_some());
     }
+
+    fn list_from(keys: &[i32]) -> SinglyLinkedList<i32> {
+        let mut list = SinglyLinkedList::new();
+        for &key in keys.iter().rev() {
+            list.insert(key);
+        }
+        list
+    }
+
+    fn keys_of(list: &SinglyLinkedList<i32>) -> Vec<i32> {
+        let mut keys = Vec::new();
+        let mut current = list.head.as_ref();
+        while let Some(node) = current {
+            keys.push(node.key);
+            current = node.next.as_ref();
+        }
+        keys
+    }
+
+    #[test]
+    fn test_merge_sorted() {
+        let merged = list_from(&[1, 3, 5]).merge_sorted(list_from(&[2, 4, 6]));
+        assert_eq!(keys_of(&merged), vec![1, 2, 3, 4, 5, 6]);
+
+        let merged = list_from(&[1, 2, 2, 9]).merge_sorted(list_from(&[0, 2, 10, 11]));
+        assert_eq!(keys_of(&merged), vec![0, 1, 2, 2, 2, 9, 10, 11]);
+    }
+
+    #[test]
+    fn test_merge_sorted_with_empty_operand() {
+        let merged = list_from(&[]).merge_sorted(list_from(&[4, 7]));
+        assert_eq!(keys_of(&merged), vec![4, 7]);
+
+        let merged = list_from(&[4, 7]).merge_sorted(list_from(&[]));
+        assert_eq!(keys_of(&merged), vec![4, 7]);
+
+        let merged = list_from(&[]).merge_sorted(list_from(&[]));
+        assert!(merged.head.is_none());
+    }
+
+    #[test]
+    fn test_insert_at_and_get() {
+        let mut list = list_from(&[2, 4]);
+        list.insert_at(0, 1).unwrap();
+        list.insert_at(2, 3).unwrap();
+        list.insert_at(4, 5).unwrap();
+        assert_eq!(keys_of(&list), vec![1, 2, 3, 4, 5]);
+        for (index, key) in (1..=5).enumerate() {
+            assert_eq!(list.get(index), Some(&key));
+        }
+        assert_eq!(list.get(5), None);
+
+        assert_eq!(list.insert_at(6, 7), Err(OutOfBounds { index: 6, len: 5 }));
+        assert_eq!(keys_of(&list), vec![1, 2, 3, 4, 5]);
+
+        let mut empty = SinglyLinkedList::new();
+        assert_eq!(empty.get(0), None);
+        assert_eq!(empty.insert_at(1, 0), Err(OutOfBounds { index: 1, len: 0 }));
+        empty.insert_at(0, 9).unwrap();
+        assert_eq!(keys_of(&empty), vec![9]);
+    }
 }
@@ -1,14 +1,16 @@
 //! Chapter 10: Elementary Data Structures
 //!
 //! This chapter covers fundamental data structures including stacks, queues,
-//! linked lists, and tree representations.
+//! linked lists, and tree representations, along with a randomized skip list.
 
 pub mod linked_list;
 pub mod queue;
+pub mod skip_list;
 pub mod stack;
 pub mod tree;
 
 pub use linked_list::*;
 pub use queue::*;
+pub use skip_list::*;
 pub use stack::*;
 pub use tree::*;
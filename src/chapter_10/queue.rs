@@ -16,12 +16,16 @@
 /// queue.enqueue(1).unwrap();
 /// assert_eq!(queue.dequeue(), Some(4));
 /// ```
+///
+/// A queue made with [`Queue::new_growable`] never overflows: a full buffer
+/// is reallocated at twice the size instead.
 #[derive(Debug, Clone)]
 pub struct Queue<T> {
     arr: Vec<Option<T>>,
     head: usize,
     tail: usize,
     capacity: usize,
+    growable: bool,
 }
 
 impl<T> Queue<T> {
@@ -30,12 +34,17 @@ impl<T> Queue<T> {
     /// # Arguments
     /// * `capacity` - Maximum number of elements the queue can hold
     ///
+    /// # Panics
+    /// Panics if `capacity` is zero, since the head and tail indices wrap
+    /// modulo the array size.
+    ///
     /// # Example
     /// ```
     /// use clrs::chapter_10::Queue;
     /// let queue: Queue<i32> = Queue::new(10);
     /// ```
     pub fn new(capacity: usize) -> Self {
+        assert!(capacity > 0, "queue capacity must be positive");
         let mut arr = Vec::with_capacity(capacity);
         arr.resize_with(capacity, || None);
         Queue {
@@ -43,9 +52,46 @@ impl<T> Queue<T> {
             head: 0,
             tail: 0,
             capacity,
+            growable: false,
+        }
+    }
+
+    /// Creates a new empty queue that doubles its array whenever it fills up
+    ///
+    /// # Arguments
+    /// * `capacity` - Initial array size; raised to 1 if zero
+    ///
+    /// # Example
+    /// ```
+    /// use clrs::chapter_10::Queue;
+    /// let mut queue = Queue::new_growable(2);
+    /// for x in 0..5 {
+    ///     queue.enqueue(x).unwrap();
+    /// }
+    /// assert_eq!(queue.len(), 5);
+    /// assert_eq!(queue.dequeue(), Some(0));
+    /// ```
+    pub fn new_growable(capacity: usize) -> Self {
+        Queue {
+            growable: true,
+            ..Self::new(capacity.max(1))
         }
     }
 
+    /// Returns the number of elements in the queue
+    pub fn len(&self) -> usize {
+        (self.tail + self.capacity - self.head) % self.capacity
+    }
+
+    /// Iterates over the elements from head to tail, i.e. in dequeue order
+    pub fn iter(&self) -> impl Iterator<Item = &T> + '_ {
+        (0..self.len()).map(move |i| {
+            self.arr[(self.head + i) % self.capacity]
+                .as_ref()
+                .expect("slots between head and tail are occupied")
+        })
+    }
+
     /// Checks if the queue is empty
     ///
     /// This corresponds to QUEUE-EMPTY from CLRS Exercise 10.1-4.
@@ -74,10 +120,11 @@ impl<T> Queue<T> {
     /// * `x` - The element to enqueue
     ///
     /// # Returns
-    /// `Ok(())` on success, `Err` if queue overflow
+    /// `Ok(())` on success, `Err` if queue overflow; a growable queue never
+    /// overflows
     ///
     /// # Complexity
-    /// - Time: O(1)
+    /// - Time: O(1), amortized O(1) for a growable queue
     ///
     /// # Example
     /// ```
@@ -87,7 +134,10 @@ impl<T> Queue<T> {
     /// ```
     pub fn enqueue(&mut self, x: T) -> Result<(), &'static str> {
         if self.is_full() {
-            return Err("queue overflow");
+            if !self.growable {
+                return Err("queue overflow");
+            }
+            self.grow();
         }
         self.arr[self.tail] = Some(x);
         self.tail = (self.tail + 1) % self.capacity;
@@ -119,6 +169,23 @@ impl<T> Queue<T> {
         self.head = (self.head + 1) % self.capacity;
         result
     }
+
+    /// Doubles the array, moving the elements to its front in queue order
+    /// so that the wrapped-around part is no longer split.
+    fn grow(&mut self) {
+        let len = self.len();
+        let new_capacity = self.capacity * 2;
+        let mut arr = Vec::with_capacity(new_capacity);
+        for i in 0..len {
+            arr.push(self.arr[(self.head + i) % self.capacity].take());
+        }
+        arr.resize_with(new_capacity, || None);
+
+        self.arr = arr;
+        self.head = 0;
+        self.tail = len;
+        self.capacity = new_capacity;
+    }
 }
 
 /// Deque (double-ended queue) (Exercise 10.1-5)
@@ -324,6 +391,48 @@ mod tests {
         assert_eq!(queue.dequeue(), None);
     }
 
+    #[test]
+    #[should_panic(expected = "capacity must be positive")]
+    fn test_queue_rejects_zero_capacity() {
+        let _: Queue<i32> = Queue::new(0);
+    }
+
+    #[test]
+    fn test_growable_queue_preserves_fifo_order() {
+        let mut queue = Queue::new_growable(4);
+        // Wrap the buffer around before the first grow.
+        queue.enqueue(-2).unwrap();
+        queue.enqueue(-1).unwrap();
+        assert_eq!(queue.dequeue(), Some(-2));
+        assert_eq!(queue.dequeue(), Some(-1));
+
+        for x in 0..10_000 {
+            queue.enqueue(x).unwrap();
+            if x % 1000 == 999 {
+                assert!(queue.iter().copied().eq(0..=x));
+            }
+        }
+        assert_eq!(queue.len(), 10_000);
+
+        for x in 0..5_000 {
+            assert_eq!(queue.dequeue(), Some(x));
+        }
+        // Refill so the tail wraps past the head's old position.
+        for x in 10_000..15_000 {
+            queue.enqueue(x).unwrap();
+        }
+        assert!(queue.iter().copied().eq(5_000..15_000));
+        for x in 5_000..15_000 {
+            assert_eq!(queue.dequeue(), Some(x));
+        }
+        assert!(queue.is_empty());
+        assert_eq!(queue.dequeue(), None);
+
+        let empty: Queue<i32> = Queue::new_growable(0);
+        assert_eq!(empty.len(), 0);
+        assert_eq!(empty.iter().count(), 0);
+    }
+
     #[test]
     fn test_deque() {
         let mut deque = Deque::new(10);
@@ -0,0 +1,354 @@
+//! Skip Lists
+//!
+//! A skip list is a sorted linked list augmented with randomly chosen
+//! "express lanes": each node is promoted to the next level with
+//! probability 1/2, giving expected O(lg n) search, insertion and deletion.
+//! It is a randomized alternative to the balanced search trees of later
+//! chapters and a natural application of the probabilistic analysis of
+//! Chapter 5.
+
+use std::ops::{Bound, RangeBounds};
+
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+/// Maximum number of levels; enough for 2^32 keys with p = 1/2.
+const MAX_LEVEL: usize = 32;
+
+#[derive(Debug, Clone)]
+struct SkipNode<K, V> {
+    key: K,
+    value: V,
+    /// `forward[l]` is the next node on level `l`.
+    forward: Vec<Option<usize>>,
+}
+
+/// Probabilistic ordered map backed by a skip list
+///
+/// Nodes live in an arena and are linked by index; `None` as a predecessor
+/// stands for the header. The level of each new node is drawn from a
+/// geometric distribution using an internal RNG, which can be seeded with
+/// [`SkipList::with_seed`] for reproducible structures.
+///
+/// # Example
+/// ```
+/// use clrs::chapter_10::SkipList;
+/// let mut list = SkipList::with_seed(7);
+/// list.insert(3, "c");
+/// list.insert(1, "a");
+/// list.insert(2, "b");
+/// assert_eq!(list.search(&2), Some(&"b"));
+/// let keys: Vec<_> = list.range(2..).map(|(k, _)| *k).collect();
+/// assert_eq!(keys, vec![2, 3]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct SkipList<K: Ord, V> {
+    head: Vec<Option<usize>>,
+    level: usize,
+    nodes: Vec<Option<SkipNode<K, V>>>,
+    free: Vec<usize>,
+    len: usize,
+    rng: StdRng,
+}
+
+impl<K: Ord, V> SkipList<K, V> {
+    /// Creates an empty skip list seeded from system entropy
+    pub fn new() -> Self {
+        Self::with_rng(StdRng::from_entropy())
+    }
+
+    /// Creates an empty skip list whose level choices are determined by `seed`
+    ///
+    /// Two lists built with the same seed and the same sequence of operations
+    /// have identical structure.
+    pub fn with_seed(seed: u64) -> Self {
+        Self::with_rng(StdRng::seed_from_u64(seed))
+    }
+
+    fn with_rng(rng: StdRng) -> Self {
+        SkipList {
+            head: vec![None; MAX_LEVEL],
+            level: 1,
+            nodes: Vec::new(),
+            free: Vec::new(),
+            len: 0,
+            rng,
+        }
+    }
+
+    /// Returns the number of key-value pairs
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns true if the list holds no keys
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the number of levels currently in use (at least 1)
+    pub fn height(&self) -> usize {
+        self.level
+    }
+
+    /// Searches for `key`
+    ///
+    /// # Returns
+    /// A reference to the associated value, or None if the key is absent
+    ///
+    /// # Complexity
+    /// - Expected time: O(lg n)
+    pub fn search(&self, key: &K) -> Option<&V> {
+        let update = self.predecessors(key);
+        self.next(update[0], 0)
+            .map(|idx| self.node(idx))
+            .filter(|node| node.key == *key)
+            .map(|node| &node.value)
+    }
+
+    /// Inserts a key-value pair
+    ///
+    /// # Returns
+    /// The previous value if the key was already present
+    ///
+    /// # Complexity
+    /// - Expected time: O(lg n)
+    pub fn insert(&mut self, key: K, value: V) -> Option<V> {
+        let mut update = self.predecessors(&key);
+
+        if let Some(idx) = self.next(update[0], 0) {
+            let node = self.node_mut(idx);
+            if node.key == key {
+                return Some(std::mem::replace(&mut node.value, value));
+            }
+        }
+
+        let node_level = self.random_level();
+        if node_level > self.level {
+            for slot in update.iter_mut().take(node_level).skip(self.level) {
+                *slot = None;
+            }
+            self.level = node_level;
+        }
+
+        let forward = (0..node_level).map(|l| self.next(update[l], l)).collect();
+        let node = SkipNode {
+            key,
+            value,
+            forward,
+        };
+        let idx = match self.free.pop() {
+            Some(idx) => {
+                self.nodes[idx] = Some(node);
+                idx
+            }
+            None => {
+                self.nodes.push(Some(node));
+                self.nodes.len() - 1
+            }
+        };
+
+        for (l, &pred) in update.iter().enumerate().take(node_level) {
+            self.set_next(pred, l, Some(idx));
+        }
+        self.len += 1;
+        None
+    }
+
+    /// Removes `key` from the list
+    ///
+    /// # Returns
+    /// The removed value, or None if the key was absent
+    ///
+    /// # Complexity
+    /// - Expected time: O(lg n)
+    pub fn delete(&mut self, key: &K) -> Option<V> {
+        let update = self.predecessors(key);
+        let idx = self.next(update[0], 0)?;
+        if self.node(idx).key != *key {
+            return None;
+        }
+
+        let node = self.nodes[idx].take().expect("linked node must be live");
+        for (l, &next) in node.forward.iter().enumerate() {
+            self.set_next(update[l], l, next);
+        }
+        self.free.push(idx);
+        self.len -= 1;
+
+        while self.level > 1 && self.head[self.level - 1].is_none() {
+            self.level -= 1;
+        }
+        Some(node.value)
+    }
+
+    /// Iterates in key order over the pairs whose keys fall in `range`
+    ///
+    /// # Complexity
+    /// - Time: O(lg n + m) expected, where m is the number of pairs yielded
+    pub fn range<'a, R>(&'a self, range: R) -> impl Iterator<Item = (&'a K, &'a V)> + 'a
+    where
+        R: RangeBounds<K> + 'a,
+    {
+        let first = match range.start_bound() {
+            Bound::Included(start) => self.next(self.predecessors(start)[0], 0),
+            Bound::Excluded(start) => {
+                let mut current = self.next(self.predecessors(start)[0], 0);
+                while let Some(idx) = current.filter(|&idx| self.node(idx).key == *start) {
+                    current = self.node(idx).forward[0];
+                }
+                current
+            }
+            Bound::Unbounded => self.head[0],
+        };
+
+        std::iter::successors(first, move |&idx| self.node(idx).forward[0])
+            .map(move |idx| self.node(idx))
+            .take_while(move |node| match range.end_bound() {
+                Bound::Included(end) => node.key <= *end,
+                Bound::Excluded(end) => node.key < *end,
+                Bound::Unbounded => true,
+            })
+            .map(|node| (&node.key, &node.value))
+    }
+
+    /// Iterates over all pairs in key order
+    pub fn iter(&self) -> impl Iterator<Item = (&K, &V)> + '_ {
+        self.range(..)
+    }
+
+    /// Returns, for each level, the last node whose key is less than `key`
+    /// (None meaning the header).
+    fn predecessors(&self, key: &K) -> Vec<Option<usize>> {
+        let mut update = vec![None; MAX_LEVEL];
+        let mut current = None;
+        for l in (0..self.level).rev() {
+            while let Some(idx) = self.next(current, l) {
+                if self.node(idx).key < *key {
+                    current = Some(idx);
+                } else {
+                    break;
+                }
+            }
+            update[l] = current;
+        }
+        update
+    }
+
+    fn next(&self, from: Option<usize>, level: usize) -> Option<usize> {
+        match from {
+            None => self.head[level],
+            Some(idx) => self.node(idx).forward[level],
+        }
+    }
+
+    fn set_next(&mut self, from: Option<usize>, level: usize, to: Option<usize>) {
+        match from {
+            None => self.head[level] = to,
+            Some(idx) => self.node_mut(idx).forward[level] = to,
+        }
+    }
+
+    fn node(&self, idx: usize) -> &SkipNode<K, V> {
+        self.nodes[idx].as_ref().expect("linked node must be live")
+    }
+
+    fn node_mut(&mut self, idx: usize) -> &mut SkipNode<K, V> {
+        self.nodes[idx].as_mut().expect("linked node must be live")
+    }
+
+    /// Draws a level from the geometric distribution with p = 1/2.
+    fn random_level(&mut self) -> usize {
+        let mut level = 1;
+        while level < MAX_LEVEL && self.rng.gen_bool(0.5) {
+            level += 1;
+        }
+        level
+    }
+}
+
+impl<K: Ord, V> Default for SkipList<K, V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::seq::SliceRandom;
+
+    /// Level of each node, listed in key order.
+    fn level_profile<K: Ord, V>(list: &SkipList<K, V>) -> Vec<usize> {
+        std::iter::successors(list.head[0], |&idx| list.node(idx).forward[0])
+            .map(|idx| list.node(idx).forward.len())
+            .collect()
+    }
+
+    #[test]
+    fn test_insert_search_range() {
+        let mut keys: Vec<u32> = (0..10_000).collect();
+        keys.shuffle(&mut StdRng::seed_from_u64(1));
+
+        let mut list = SkipList::with_seed(42);
+        for &k in &keys {
+            assert_eq!(list.insert(k, k * 2), None);
+        }
+        assert_eq!(list.len(), 10_000);
+
+        for k in 0..10_000 {
+            assert_eq!(list.search(&k), Some(&(k * 2)));
+        }
+        assert_eq!(list.search(&10_000), None);
+
+        let collected: Vec<u32> = list.range(100..200).map(|(k, _)| *k).collect();
+        assert_eq!(collected, (100..200).collect::<Vec<_>>());
+        let collected: Vec<u32> = list.range(9_990..).map(|(k, _)| *k).collect();
+        assert_eq!(collected, (9_990..10_000).collect::<Vec<_>>());
+        let collected: Vec<u32> = list
+            .range((Bound::Excluded(5), Bound::Included(8)))
+            .map(|(k, _)| *k)
+            .collect();
+        assert_eq!(collected, vec![6, 7, 8]);
+        assert_eq!(list.range(20_000..).count(), 0);
+        assert_eq!(list.iter().count(), 10_000);
+
+        // Same seed and insertion order give the same structure.
+        let mut twin = SkipList::with_seed(42);
+        for &k in &keys {
+            twin.insert(k, k * 2);
+        }
+        assert_eq!(level_profile(&list), level_profile(&twin));
+        assert_eq!(list.height(), twin.height());
+    }
+
+    #[test]
+    fn test_insert_replaces_and_delete() {
+        let mut list = SkipList::with_seed(3);
+        for k in 0..100 {
+            list.insert(k, k);
+        }
+        assert_eq!(list.insert(50, 500), Some(50));
+        assert_eq!(list.search(&50), Some(&500));
+        assert_eq!(list.len(), 100);
+
+        for k in (0..100).step_by(2) {
+            assert!(list.delete(&k).is_some());
+        }
+        assert_eq!(list.delete(&0), None);
+        assert_eq!(list.len(), 50);
+        let remaining: Vec<i32> = list.iter().map(|(k, _)| *k).collect();
+        assert_eq!(remaining, (1..100).step_by(2).collect::<Vec<_>>());
+
+        // Freed slots are reused.
+        list.insert(200, 200);
+        assert_eq!(list.nodes.len(), 100);
+
+        for k in (1..100).step_by(2) {
+            list.delete(&k);
+        }
+        list.delete(&200);
+        assert!(list.is_empty());
+        assert_eq!(list.height(), 1);
+    }
+}
@@ -3,6 +3,22 @@
 //! A stack is a dynamic set in which the element deleted from the set
 //! is the one most recently inserted (LIFO - Last In, First Out).
 
+use std::fmt;
+
+/// Error returned when pushing onto a full array-backed stack
+///
+/// This is the STACK-FULL condition from CLRS Exercise 10.1-2.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StackOverflow;
+
+impl fmt::Display for StackOverflow {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "stack overflow")
+    }
+}
+
+impl std::error::Error for StackOverflow {}
+
 /// Stack implemented using an array
 ///
 /// This corresponds to the stack implementation from CLRS Section 10.1.
@@ -45,6 +61,35 @@ impl<T> Stack<T> {
         }
     }
 
+    /// Creates a new empty stack that holds at most `n` elements
+    ///
+    /// Once `n` elements are stored, [`push`](Self::push) fails with
+    /// [`StackOverflow`] until an element is popped. This is the same bounded
+    /// stack that [`Stack::new`] creates.
+    ///
+    /// # Example
+    /// ```
+    /// use clrs::chapter_10::{Stack, StackOverflow};
+    /// let mut stack = Stack::with_capacity(1);
+    /// assert_eq!(stack.push(1), Ok(()));
+    /// assert_eq!(stack.push(2), Err(StackOverflow));
+    /// ```
+    pub fn with_capacity(n: usize) -> Self {
+        Self::new(n)
+    }
+
+    /// Returns the maximum number of elements the stack can hold
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Checks if the stack is full
+    ///
+    /// This corresponds to STACK-FULL from CLRS Exercise 10.1-2.
+    pub fn is_full(&self) -> bool {
+        self.top >= self.capacity
+    }
+
     /// Checks if the stack is empty
     ///
     /// This corresponds to STACK-EMPTY from CLRS.
@@ -70,7 +115,7 @@ impl<T> Stack<T> {
     /// * `x` - The element to push
     ///
     /// # Returns
-    /// `Ok(())` on success, `Err` if stack overflow
+    /// `Ok(())` on success, `Err(StackOverflow)` if the stack is full
     ///
     /// # Complexity
     /// - Time: O(1)
@@ -81,9 +126,9 @@ impl<T> Stack<T> {
     /// let mut stack = Stack::new(5);
     /// stack.push(42).unwrap();
     /// ```
-    pub fn push(&mut self, x: T) -> Result<(), &'static str> {
-        if self.top >= self.capacity {
-            return Err("stack overflow");
+    pub fn push(&mut self, x: T) -> Result<(), StackOverflow> {
+        if self.is_full() {
+            return Err(StackOverflow);
         }
         self.arr[self.top] = Some(x);
         self.top += 1;
@@ -114,20 +159,59 @@ impl<T> Stack<T> {
         self.top -= 1;
         self.arr[self.top].take()
     }
+
+    /// Returns a reference to the top element without removing it
+    ///
+    /// # Returns
+    /// `Some(&element)` if the stack is not empty, `None` otherwise
+    ///
+    /// # Complexity
+    /// - Time: O(1)
+    ///
+    /// # Example
+    /// ```
+    /// use clrs::chapter_10::Stack;
+    /// let mut stack = Stack::new(5);
+    /// stack.push(42).unwrap();
+    /// assert_eq!(stack.peek(), Some(&42));
+    /// ```
+    pub fn peek(&self) -> Option<&T> {
+        if self.is_empty() {
+            return None;
+        }
+        self.arr[self.top - 1].as_ref()
+    }
+
+    /// Returns a mutable reference to the top element without removing it
+    ///
+    /// # Returns
+    /// `Some(&mut element)` if the stack is not empty, `None` otherwise
+    ///
+    /// # Complexity
+    /// - Time: O(1)
+    pub fn peek_mut(&mut self) -> Option<&mut T> {
+        if self.is_empty() {
+            return None;
+        }
+        self.arr[self.top - 1].as_mut()
+    }
 }
 
 /// Two stacks in one array (Exercise 10.1-2)
 ///
 /// Implements two stacks in a single array where neither overflows
-/// unless the total number of elements exceeds the array size.
+/// unless the total number of elements exceeds the array size. The first
+/// stack grows upward from index 0 and the second grows downward from the
+/// end of the array, so an overflow occurs only when the two tops meet.
 ///
 /// # Example
 /// ```
 /// use clrs::chapter_10::TwoStacks;
 /// let mut stacks = TwoStacks::new(10);
-/// stacks.push1(1).unwrap();
-/// stacks.push2(2).unwrap();
-/// assert_eq!(stacks.pop1(), Some(1));
+/// stacks.push_first(1).unwrap();
+/// stacks.push_second(2).unwrap();
+/// assert_eq!(stacks.pop_first(), Some(1));
+/// assert_eq!(stacks.pop_second(), Some(2));
 /// ```
 #[derive(Debug, Clone)]
 pub struct TwoStacks<T> {
@@ -153,48 +237,81 @@ impl<T> TwoStacks<T> {
         }
     }
 
-    /// Checks if stack 1 is empty
-    pub fn is_empty1(&self) -> bool {
+    /// Returns the total number of elements held by both stacks
+    pub fn len(&self) -> usize {
+        self.top1 + (self.capacity - self.top2)
+    }
+
+    /// Checks if both stacks are empty
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Checks if the shared array is full, i.e. the two tops have met
+    pub fn is_full(&self) -> bool {
+        self.top1 >= self.top2
+    }
+
+    /// Checks if the first stack is empty
+    pub fn is_empty_first(&self) -> bool {
         self.top1 == 0
     }
 
-    /// Checks if stack 2 is empty
-    pub fn is_empty2(&self) -> bool {
+    /// Checks if the second stack is empty
+    pub fn is_empty_second(&self) -> bool {
         self.top2 == self.capacity
     }
 
-    /// Pushes element onto stack 1
-    pub fn push1(&mut self, x: T) -> Result<(), &'static str> {
-        if self.top1 >= self.top2 {
-            return Err("stack overflow");
+    /// Pushes an element onto the first stack
+    ///
+    /// # Returns
+    /// `Ok(())` on success, `Err(StackOverflow)` if the array is full
+    ///
+    /// # Complexity
+    /// - Time: O(1)
+    pub fn push_first(&mut self, x: T) -> Result<(), StackOverflow> {
+        if self.is_full() {
+            return Err(StackOverflow);
         }
         self.arr[self.top1] = Some(x);
         self.top1 += 1;
         Ok(())
     }
 
-    /// Pushes element onto stack 2
-    pub fn push2(&mut self, x: T) -> Result<(), &'static str> {
-        if self.top1 >= self.top2 {
-            return Err("stack overflow");
+    /// Pushes an element onto the second stack
+    ///
+    /// # Returns
+    /// `Ok(())` on success, `Err(StackOverflow)` if the array is full
+    ///
+    /// # Complexity
+    /// - Time: O(1)
+    pub fn push_second(&mut self, x: T) -> Result<(), StackOverflow> {
+        if self.is_full() {
+            return Err(StackOverflow);
         }
         self.top2 -= 1;
         self.arr[self.top2] = Some(x);
         Ok(())
     }
 
-    /// Pops element from stack 1
-    pub fn pop1(&mut self) -> Option<T> {
-        if self.is_empty1() {
+    /// Pops an element from the first stack
+    ///
+    /// # Complexity
+    /// - Time: O(1)
+    pub fn pop_first(&mut self) -> Option<T> {
+        if self.is_empty_first() {
             return None;
         }
         self.top1 -= 1;
         self.arr[self.top1].take()
     }
 
-    /// Pops element from stack 2
-    pub fn pop2(&mut self) -> Option<T> {
-        if self.is_empty2() {
+    /// Pops an element from the second stack
+    ///
+    /// # Complexity
+    /// - Time: O(1)
+    pub fn pop_second(&mut self) -> Option<T> {
+        if self.is_empty_second() {
             return None;
         }
         let result = self.arr[self.top2].take();
@@ -248,27 +365,98 @@ mod tests {
         assert_eq!(stack.pop(), None);
     }
 
+    #[test]
+    fn test_stack_peek_empty() {
+        let mut stack: Stack<i32> = Stack::new(3);
+        assert_eq!(stack.peek(), None);
+        assert_eq!(stack.peek_mut(), None);
+    }
+
+    #[test]
+    fn test_stack_peek_non_empty() {
+        let mut stack = Stack::new(3);
+        stack.push(1).unwrap();
+        stack.push(2).unwrap();
+        assert_eq!(stack.peek(), Some(&2));
+
+        if let Some(top) = stack.peek_mut() {
+            *top = 20;
+        }
+        assert_eq!(stack.pop(), Some(20));
+        assert_eq!(stack.peek(), Some(&1));
+    }
+
+    #[test]
+    fn test_stack_with_capacity_overflow() {
+        let mut stack = Stack::with_capacity(2);
+        assert_eq!(stack.capacity(), 2);
+        assert_eq!(stack.push('a'), Ok(()));
+        assert_eq!(stack.push('b'), Ok(()));
+        assert!(stack.is_full());
+        assert_eq!(stack.push('c'), Err(StackOverflow));
+        assert_eq!(stack.peek(), Some(&'b'));
+
+        stack.pop();
+        assert_eq!(stack.push('c'), Ok(()));
+        assert_eq!(StackOverflow.to_string(), "stack overflow");
+    }
+
     #[test]
     fn test_two_stacks() {
         let mut stacks = TwoStacks::new(10);
-        stacks.push1(1).unwrap();
-        stacks.push1(2).unwrap();
-        stacks.push2(10).unwrap();
-        stacks.push2(9).unwrap();
-
-        assert_eq!(stacks.pop1(), Some(2));
-        assert_eq!(stacks.pop2(), Some(9));
-        assert_eq!(stacks.pop1(), Some(1));
-        assert_eq!(stacks.pop2(), Some(10));
+        stacks.push_first(1).unwrap();
+        stacks.push_first(2).unwrap();
+        stacks.push_second(10).unwrap();
+        stacks.push_second(9).unwrap();
+        assert_eq!(stacks.len(), 4);
+
+        assert_eq!(stacks.pop_first(), Some(2));
+        assert_eq!(stacks.pop_second(), Some(9));
+        assert_eq!(stacks.pop_first(), Some(1));
+        assert_eq!(stacks.pop_second(), Some(10));
+        assert!(stacks.is_empty());
+        assert_eq!(stacks.pop_first(), None);
+        assert_eq!(stacks.pop_second(), None);
     }
 
     #[test]
     fn test_two_stacks_overflow() {
         let mut stacks = TwoStacks::new(3);
-        stacks.push1(1).unwrap();
-        stacks.push1(2).unwrap();
-        stacks.push2(3).unwrap();
+        stacks.push_first(1).unwrap();
+        stacks.push_first(2).unwrap();
+        stacks.push_second(3).unwrap();
         // Total is 3, so next push should overflow
-        assert!(stacks.push1(4).is_err());
+        assert_eq!(stacks.push_first(4), Err(StackOverflow));
+        assert_eq!(stacks.push_second(4), Err(StackOverflow));
+    }
+
+    #[test]
+    fn test_two_stacks_fill_eight_slots() {
+        let mut stacks = TwoStacks::new(8);
+
+        // Uneven split: five on the first stack, three on the second
+        for i in 0..5 {
+            assert_eq!(stacks.push_first(i), Ok(()));
+        }
+        for i in 0..2 {
+            assert_eq!(stacks.push_second(100 + i), Ok(()));
+        }
+        assert!(!stacks.is_full());
+        assert_eq!(stacks.push_second(102), Ok(()));
+
+        // All eight slots are used; overflow fires on either side
+        assert!(stacks.is_full());
+        assert_eq!(stacks.len(), 8);
+        assert_eq!(stacks.push_first(5), Err(StackOverflow));
+        assert_eq!(stacks.push_second(103), Err(StackOverflow));
+
+        // Freeing a slot on one side lets the other side grow into it
+        assert_eq!(stacks.pop_first(), Some(4));
+        assert_eq!(stacks.push_second(103), Ok(()));
+        assert_eq!(stacks.push_first(4), Err(StackOverflow));
+
+        assert_eq!(stacks.pop_second(), Some(103));
+        assert_eq!(stacks.pop_second(), Some(102));
+        assert_eq!(stacks.pop_first(), Some(3));
     }
 }
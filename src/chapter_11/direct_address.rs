@@ -110,7 +110,9 @@ impl<T> DirectAddressTable<T> {
 /// Bit vector for representing a dynamic set (Exercise 11.1-2)
 ///
 /// A bit vector uses an array of bits to represent a set of distinct elements
-/// with no satellite data. All operations run in O(1) time.
+/// with no satellite data. Dictionary operations run in O(1) time, and sets
+/// over the same universe can be combined with union, intersection and
+/// difference in O(m) time.
 ///
 /// # Example
 /// ```
@@ -190,6 +192,68 @@ impl BitVector {
         self.arr[k] = false;
         Ok(())
     }
+
+    /// Returns the size of the universe `0..m` the set is drawn from
+    pub fn universe_size(&self) -> usize {
+        self.size
+    }
+
+    /// Iterates over the keys in the set in increasing order
+    ///
+    /// # Complexity
+    /// - Time: O(m)
+    pub fn iter(&self) -> impl Iterator<Item = usize> + '_ {
+        (0..self.size).filter(|&k| self.arr[k])
+    }
+
+    /// Returns the set of keys in `self` or `other`
+    ///
+    /// # Panics
+    /// Panics if the two sets have different universe sizes.
+    ///
+    /// # Complexity
+    /// - Time: O(m)
+    pub fn union(&self, other: &BitVector) -> BitVector {
+        self.combine(other, |a, b| a || b)
+    }
+
+    /// Returns the set of keys in both `self` and `other`
+    ///
+    /// # Panics
+    /// Panics if the two sets have different universe sizes.
+    ///
+    /// # Complexity
+    /// - Time: O(m)
+    pub fn intersection(&self, other: &BitVector) -> BitVector {
+        self.combine(other, |a, b| a && b)
+    }
+
+    /// Returns the set of keys in `self` but not in `other`
+    ///
+    /// # Panics
+    /// Panics if the two sets have different universe sizes.
+    ///
+    /// # Complexity
+    /// - Time: O(m)
+    pub fn difference(&self, other: &BitVector) -> BitVector {
+        self.combine(other, |a, b| a && !b)
+    }
+
+    fn combine(&self, other: &BitVector, op: impl Fn(bool, bool) -> bool) -> BitVector {
+        assert_eq!(
+            self.size, other.size,
+            "set operations require the same universe"
+        );
+        BitVector {
+            arr: self
+                .arr
+                .iter()
+                .zip(&other.arr)
+                .map(|(&a, &b)| op(a, b))
+                .collect(),
+            size: self.size,
+        }
+    }
 }
 
 #[cfg(test)]
@@ -247,4 +311,38 @@ mod tests {
         bv.delete(42).unwrap();
         assert!(!bv.search(42));
     }
+
+    #[test]
+    fn test_bit_vector_set_operations() {
+        let mut evens = BitVector::new(64);
+        let mut multiples_of_three = BitVector::new(64);
+        for k in 0..64 {
+            if k % 2 == 0 {
+                evens.insert(k).unwrap();
+            }
+            if k % 3 == 0 {
+                multiples_of_three.insert(k).unwrap();
+            }
+        }
+
+        let union = evens.union(&multiples_of_three);
+        let intersection = evens.intersection(&multiples_of_three);
+        let difference = evens.difference(&multiples_of_three);
+        for k in 0..64 {
+            assert_eq!(union.search(k), k % 2 == 0 || k % 3 == 0);
+            assert_eq!(intersection.search(k), k % 6 == 0);
+            assert_eq!(difference.search(k), k % 2 == 0 && k % 3 != 0);
+        }
+        assert_eq!(
+            intersection.iter().collect::<Vec<_>>(),
+            (0..64).step_by(6).collect::<Vec<_>>()
+        );
+        assert_eq!(union.universe_size(), 64);
+    }
+
+    #[test]
+    #[should_panic(expected = "same universe")]
+    fn test_bit_vector_set_operations_require_same_universe() {
+        BitVector::new(8).union(&BitVector::new(16));
+    }
 }
@@ -10,6 +10,15 @@ pub struct HashNode<K, V> {
     pub value: V,
 }
 
+/// How a [`HashTableChaining`] maps keys to slots
+#[derive(Debug, Clone, Copy)]
+enum SlotHasher<K> {
+    /// Computes the slot directly from the key and table size
+    Sized(fn(K, usize) -> usize),
+    /// Computes a raw hash that is reduced modulo the table size
+    Key(fn(&K) -> usize),
+}
+
 /// Hash table with chaining
 ///
 /// This corresponds to the hash table implementation from CLRS Section 11.2.
@@ -26,7 +35,7 @@ pub struct HashNode<K, V> {
 pub struct HashTableChaining<K: PartialEq + Clone, V> {
     arr: Vec<Vec<HashNode<K, V>>>,
     size: usize,
-    hash_fn: fn(K, usize) -> usize,
+    hasher: SlotHasher<K>,
 }
 
 impl<K: PartialEq + Clone, V> HashTableChaining<K, V> {
@@ -42,15 +51,68 @@ impl<K: PartialEq + Clone, V> HashTableChaining<K, V> {
     /// let table: HashTableChaining<usize, i32> = HashTableChaining::new(11, |k, m| k % m);
     /// ```
     pub fn new(m: usize, hash_fn: fn(K, usize) -> usize) -> Self {
+        Self::with_slot_hasher(m, SlotHasher::Sized(hash_fn))
+    }
+
+    /// Creates a new hash table with chaining that hashes keys with `hasher`
+    ///
+    /// The slot of key `k` is `hasher(&k) mod m`, so `hasher` need not know
+    /// the table size.
+    ///
+    /// # Arguments
+    /// * `m` - The size of the hash table
+    /// * `hasher` - Maps a key to an arbitrary hash value
+    ///
+    /// # Example
+    /// ```
+    /// use clrs::chapter_11::HashTableChaining;
+    /// let mut table = HashTableChaining::with_hasher(8, |k: &String| k.len());
+    /// table.insert("abc".to_string(), 1);
+    /// assert_eq!(table.search("abc".to_string()), Some(&1));
+    /// ```
+    pub fn with_hasher(m: usize, hasher: fn(&K) -> usize) -> Self {
+        Self::with_slot_hasher(m, SlotHasher::Key(hasher))
+    }
+
+    fn with_slot_hasher(m: usize, hasher: SlotHasher<K>) -> Self {
         let mut arr = Vec::with_capacity(m);
         arr.resize_with(m, Vec::new);
         HashTableChaining {
             arr,
             size: m,
-            hash_fn,
+            hasher,
+        }
+    }
+
+    /// Returns the slot that key `k` hashes to
+    fn slot(&self, k: &K) -> usize {
+        match self.hasher {
+            SlotHasher::Sized(hash_fn) => hash_fn(k.clone(), self.size),
+            SlotHasher::Key(hasher) => hasher(k) % self.size,
         }
     }
 
+    /// Reports how keys are spread over the chains
+    ///
+    /// # Returns
+    /// A tuple `(max_len, non_empty, avg_non_empty)`: the length of the longest
+    /// chain, the number of non-empty slots, and the mean length of the
+    /// non-empty chains (0.0 for an empty table)
+    ///
+    /// # Complexity
+    /// - Time: O(m)
+    pub fn chain_length_stats(&self) -> (usize, usize, f64) {
+        let max_len = self.arr.iter().map(Vec::len).max().unwrap_or(0);
+        let non_empty = self.arr.iter().filter(|chain| !chain.is_empty()).count();
+        let total: usize = self.arr.iter().map(Vec::len).sum();
+        let avg_non_empty = if non_empty == 0 {
+            0.0
+        } else {
+            total as f64 / non_empty as f64
+        };
+        (max_len, non_empty, avg_non_empty)
+    }
+
     /// Searches for an element with key `k`
     ///
     /// This corresponds to CHAINED-HASH-SEARCH from CLRS Section 11.2.
@@ -64,7 +126,7 @@ impl<K: PartialEq + Clone, V> HashTableChaining<K, V> {
     /// # Complexity
     /// - Time: O(1 + α) where α is the load factor
     pub fn search(&self, k: K) -> Option<&V> {
-        let h = (self.hash_fn)(k.clone(), self.size);
+        let h = self.slot(&k);
         self.arr[h]
             .iter()
             .find(|node| node.key == k)
@@ -82,7 +144,7 @@ impl<K: PartialEq + Clone, V> HashTableChaining<K, V> {
     /// # Complexity
     /// - Time: O(1) average case
     pub fn insert(&mut self, k: K, v: V) {
-        let h = (self.hash_fn)(k.clone(), self.size);
+        let h = self.slot(&k);
         let node = HashNode {
             key: k.clone(),
             value: v,
@@ -109,7 +171,7 @@ impl<K: PartialEq + Clone, V> HashTableChaining<K, V> {
     /// # Complexity
     /// - Time: O(1 + α) average case
     pub fn delete(&mut self, k: K) -> Option<V> {
-        let h = (self.hash_fn)(k.clone(), self.size);
+        let h = self.slot(&k);
         if let Some(pos) = self.arr[h].iter().position(|node| node.key == k) {
             Some(self.arr[h].remove(pos).value)
         } else {
@@ -163,4 +225,36 @@ mod tests {
         assert_eq!(table.search(5), None);
         assert_eq!(table.search(16), Some(&"value16"));
     }
+
+    #[test]
+    fn test_hash_table_chaining_chain_stats() {
+        use rand::Rng;
+
+        let empty: HashTableChaining<u64, ()> = HashTableChaining::with_hasher(7, |_| 0);
+        assert_eq!(empty.chain_length_stats(), (0, 0, 0.0));
+
+        let mut colliding = HashTableChaining::with_hasher(101, |_: &u64| 42);
+        for key in 0..1000 {
+            colliding.insert(key, ());
+        }
+        assert_eq!(colliding.chain_length_stats(), (1000, 1, 1000.0));
+        assert_eq!(colliding.search(999), Some(&()));
+
+        let m = 101;
+        let mut spread = HashTableChaining::with_hasher(m, |k: &u64| *k as usize);
+        let mut rng = rand::thread_rng();
+        for _ in 0..1000 {
+            spread.insert(rng.gen::<u64>(), ());
+        }
+        let alpha = 1000.0 / m as f64;
+        let (max_len, non_empty, avg) = spread.chain_length_stats();
+        assert!(max_len < 40);
+        assert!(non_empty > 90);
+        assert!(
+            (avg - alpha).abs() < 1.0,
+            "average {} vs alpha {}",
+            avg,
+            alpha
+        );
+    }
 }
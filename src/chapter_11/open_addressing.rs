@@ -1,9 +1,11 @@
 //! Open Addressing (Section 11.4)
 //!
 //! Hash tables that resolve collisions by open addressing methods:
-//! linear probing, quadratic probing, and double hashing.
+//! linear probing, quadratic probing, double hashing, and Robin Hood hashing.
 
+use std::fmt;
 use std::hash::Hash;
+use std::sync::Arc;
 
 /// Marker for deleted slots in open addressing
 #[derive(Debug, Clone, PartialEq)]
@@ -13,10 +15,20 @@ pub enum Slot<K, V> {
     Occupied(K, V),
 }
 
+/// A probe sequence `h(hash, i, m) -> slot`, mapping a key's hash and the probe
+/// number `i` to a slot in a table of size `m`
+///
+/// The function must be `Send + Sync` so that tables holding one can still
+/// be moved and shared across threads.
+pub type ProbeFn = dyn Fn(usize, usize, usize) -> usize + Send + Sync;
+
 /// Hash table with open addressing
 ///
 /// This corresponds to the hash table implementation from CLRS Section 11.4.
-/// Collisions are resolved by probing through the table.
+/// Collisions are resolved by probing through the table. Every table probes
+/// through a single probe function; [`OpenAddressingHashTable::new`] builds
+/// one from a [`ProbeType`], and [`OpenAddressingHashTable::with_probe_fn`]
+/// accepts an arbitrary one.
 ///
 /// # Example
 /// ```
@@ -25,13 +37,32 @@ pub enum Slot<K, V> {
 /// table.insert(42, "value");
 /// assert_eq!(table.search(42), Some(&"value"));
 /// ```
-#[derive(Debug, Clone)]
+#[derive(Clone)]
 pub struct OpenAddressingHashTable<K: PartialEq + Clone + Hash, V> {
     arr: Vec<Slot<K, V>>,
     size: usize,
-    probe_type: ProbeType,
-    hash_fn1: fn(usize, usize) -> usize,
-    hash_fn2: Option<fn(usize, usize) -> usize>,
+    /// Number of `Occupied` slots
+    occupied: usize,
+    probe_type: Option<ProbeType>,
+    probe_fn: Arc<ProbeFn>,
+}
+
+// A custom probe function must not cost the table its `Send` and `Sync` impls.
+const _: fn() = || {
+    fn assert_send_sync<T: Send + Sync>() {}
+    assert_send_sync::<OpenAddressingHashTable<u64, String>>();
+};
+
+impl<K: PartialEq + Clone + Hash + fmt::Debug, V: fmt::Debug> fmt::Debug
+    for OpenAddressingHashTable<K, V>
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("OpenAddressingHashTable")
+            .field("arr", &self.arr)
+            .field("size", &self.size)
+            .field("probe_type", &self.probe_type)
+            .finish_non_exhaustive()
+    }
 }
 
 /// Type of probing method
@@ -43,6 +74,15 @@ pub enum ProbeType {
     Quadratic { c1: usize, c2: usize },
     /// Double hashing: h(k, i) = (h1(k) + i*h2(k)) mod m
     DoubleHashing,
+    /// Robin Hood hashing: linear probing in which an inserted key takes the
+    /// slot of any key it meets that sits closer to its own home slot h'(k)
+    ///
+    /// Keys along a run then appear in order of their home slots, so probe
+    /// lengths stay close to the average, and an unsuccessful search can
+    /// stop at the first key closer to home than the searched key would be.
+    /// Deletion shifts the following keys back instead of leaving a
+    /// `Deleted` marker.
+    RobinHood,
 }
 
 impl<K: PartialEq + Clone + Hash, V> OpenAddressingHashTable<K, V> {
@@ -54,6 +94,9 @@ impl<K: PartialEq + Clone + Hash, V> OpenAddressingHashTable<K, V> {
     /// * `hash_fn1` - The primary hash function
     /// * `hash_fn2` - Optional secondary hash function (required for double hashing)
     ///
+    /// # Panics
+    /// Panics if `probe_type` is `DoubleHashing` and `hash_fn2` is `None`.
+    ///
     /// # Example
     /// ```
     /// use clrs::chapter_11::open_addressing::{OpenAddressingHashTable, ProbeType};
@@ -70,33 +113,83 @@ impl<K: PartialEq + Clone + Hash, V> OpenAddressingHashTable<K, V> {
         hash_fn1: fn(usize, usize) -> usize,
         hash_fn2: Option<fn(usize, usize) -> usize>,
     ) -> Self {
+        let probe_fn: Box<ProbeFn> = match probe_type {
+            ProbeType::Linear | ProbeType::RobinHood => {
+                Box::new(move |k, i, m| (hash_fn1(k, m) + i) % m)
+            }
+            ProbeType::Quadratic { c1, c2 } => {
+                Box::new(move |k, i, m| (hash_fn1(k, m) + c1 * i + c2 * i * i) % m)
+            }
+            ProbeType::DoubleHashing => {
+                let hash_fn2 = hash_fn2.expect("Double hashing requires hash_fn2");
+                Box::new(move |k, i, m| (hash_fn1(k, m) + i * hash_fn2(k, m)) % m)
+            }
+        };
+        let mut table = Self::with_probe_fn(m, probe_fn);
+        table.probe_type = Some(probe_type);
+        table
+    }
+
+    /// Creates a new hash table that probes with a user-supplied function
+    ///
+    /// # Arguments
+    /// * `m` - The size of the hash table
+    /// * `probe` - Maps `(hash, i, m)` to the slot examined at probe number
+    ///   `i` for a key with the given hash. It should return values below
+    ///   `m`; for inserts to succeed whenever a slot is free, the sequence for
+    ///   `i` in `0..m` should be a permutation of `0..m`.
+    ///
+    /// # Example
+    /// ```
+    /// use clrs::chapter_11::open_addressing::OpenAddressingHashTable;
+    /// let mut table = OpenAddressingHashTable::with_probe_fn(
+    ///     7,
+    ///     Box::new(|hash, i, m| (hash % m + 3 * i) % m),
+    /// );
+    /// table.insert(1, "one").unwrap();
+    /// assert_eq!(table.search(&1), Some(&"one"));
+    /// ```
+    pub fn with_probe_fn(m: usize, probe: Box<ProbeFn>) -> Self {
         let mut arr = Vec::with_capacity(m);
         arr.resize_with(m, || Slot::Empty);
         OpenAddressingHashTable {
             arr,
             size: m,
-            probe_type,
-            hash_fn1,
-            hash_fn2,
+            occupied: 0,
+            probe_type: None,
+            probe_fn: Arc::from(probe),
         }
     }
 
     /// Computes the probe sequence for key `k` at probe number `i`
     fn probe(&self, k: &K, i: usize) -> usize {
-        // Convert key to usize for hashing (assuming keys can be converted)
-        // In practice, we'd use a proper hash function, but for simplicity
-        // we'll require K: Into<usize> or use a hash function
         let k_hash = self.key_to_hash(k);
-        let h1 = (self.hash_fn1)(k_hash, self.size);
-        match self.probe_type {
-            ProbeType::Linear => (h1 + i) % self.size,
-            ProbeType::Quadratic { c1, c2 } => (h1 + c1 * i + c2 * i * i) % self.size,
-            ProbeType::DoubleHashing => {
-                let h2 =
-                    self.hash_fn2.expect("Double hashing requires hash_fn2")(k_hash, self.size);
-                (h1 + i * h2) % self.size
+        (self.probe_fn)(k_hash, i, self.size) % self.size
+    }
+
+    /// Returns how many slots past its home slot the key stored at slot `j`
+    /// lies, for the linear probe sequence of a Robin Hood table
+    fn displacement(&self, key: &K, j: usize) -> usize {
+        (j + self.size - self.probe(key, 0)) % self.size
+    }
+
+    fn is_robin_hood(&self) -> bool {
+        self.probe_type == Some(ProbeType::RobinHood)
+    }
+
+    /// Returns the slot holding key `k` in a Robin Hood table
+    fn robin_hood_find(&self, k: &K) -> Option<usize> {
+        let home = self.probe(k, 0);
+        for i in 0..self.size {
+            let j = (home + i) % self.size;
+            match &self.arr[j] {
+                Slot::Occupied(key, _) if key == k => return Some(j),
+                // `k` would have displaced a key closer to its home slot.
+                Slot::Occupied(key, _) if self.displacement(key, j) >= i => {}
+                _ => return None,
             }
         }
+        None
     }
 
     /// Helper to convert key to hash value
@@ -121,6 +214,12 @@ impl<K: PartialEq + Clone + Hash, V> OpenAddressingHashTable<K, V> {
     /// # Complexity
     /// - Time: O(1/(1-α)) expected for unsuccessful search
     pub fn search(&self, k: &K) -> Option<&V> {
+        if self.is_robin_hood() {
+            return match &self.arr[self.robin_hood_find(k)?] {
+                Slot::Occupied(_, value) => Some(value),
+                _ => unreachable!("robin_hood_find returns occupied slots"),
+            };
+        }
         let mut i = 0;
         loop {
             let j = self.probe(k, i);
@@ -159,12 +258,16 @@ impl<K: PartialEq + Clone + Hash, V> OpenAddressingHashTable<K, V> {
     /// # Complexity
     /// - Time: O(1/(1-α)) expected
     pub fn insert(&mut self, k: K, v: V) -> Result<usize, &'static str> {
+        if self.is_robin_hood() {
+            return self.robin_hood_insert(k, v);
+        }
         let mut i = 0;
         loop {
             let j = self.probe(&k, i);
             match &self.arr[j] {
                 Slot::Empty | Slot::Deleted => {
                     self.arr[j] = Slot::Occupied(k, v);
+                    self.occupied += 1;
                     return Ok(j);
                 }
                 Slot::Occupied(key, _) => {
@@ -195,6 +298,9 @@ impl<K: PartialEq + Clone + Hash, V> OpenAddressingHashTable<K, V> {
     /// # Complexity
     /// - Time: O(1/(1-α)) expected
     pub fn delete(&mut self, k: &K) -> Option<V> {
+        if self.is_robin_hood() {
+            return self.robin_hood_delete(k);
+        }
         let mut i = 0;
         loop {
             let j = self.probe(k, i);
@@ -211,6 +317,7 @@ impl<K: PartialEq + Clone + Hash, V> OpenAddressingHashTable<K, V> {
                         if let Slot::Occupied(_, value) =
                             std::mem::replace(&mut self.arr[j], Slot::Deleted)
                         {
+                            self.occupied -= 1;
                             return Some(value);
                         }
                     }
@@ -224,6 +331,95 @@ impl<K: PartialEq + Clone + Hash, V> OpenAddressingHashTable<K, V> {
     }
 }
 
+impl<K: PartialEq + Clone + Hash, V> OpenAddressingHashTable<K, V> {
+    fn robin_hood_insert(&mut self, k: K, v: V) -> Result<usize, &'static str> {
+        if let Some(j) = self.robin_hood_find(&k) {
+            self.arr[j] = Slot::Occupied(k, v);
+            return Ok(j);
+        }
+        // Robin Hood tables leave no `Deleted` markers, so every slot that
+        // is not occupied is empty.
+        if self.occupied == self.size {
+            return Err("hash table overflow");
+        }
+        self.occupied += 1;
+
+        // Carry the key along the run, swapping it with every key that is
+        // closer to home, until an empty slot takes whatever is carried.
+        let mut j = self.probe(&k, 0);
+        let mut carried = Slot::Occupied(k, v);
+        let mut distance = 0;
+        let mut placed_at = None;
+        loop {
+            match &self.arr[j] {
+                Slot::Occupied(key, _) => {
+                    let resident_distance = self.displacement(key, j);
+                    if resident_distance < distance {
+                        std::mem::swap(&mut self.arr[j], &mut carried);
+                        placed_at.get_or_insert(j);
+                        distance = resident_distance;
+                    }
+                }
+                _ => {
+                    self.arr[j] = carried;
+                    return Ok(placed_at.unwrap_or(j));
+                }
+            }
+            j = (j + 1) % self.size;
+            distance += 1;
+        }
+    }
+
+    fn robin_hood_delete(&mut self, k: &K) -> Option<V> {
+        let mut hole = self.robin_hood_find(k)?;
+        let removed = std::mem::replace(&mut self.arr[hole], Slot::Empty);
+        self.occupied -= 1;
+
+        // Backward-shift deletion: pull each following displaced key one
+        // slot nearer its home until the run ends or a key is already home.
+        loop {
+            let next = (hole + 1) % self.size;
+            match &self.arr[next] {
+                Slot::Occupied(key, _) if self.displacement(key, next) > 0 => {
+                    self.arr.swap(hole, next);
+                    hole = next;
+                }
+                _ => break,
+            }
+        }
+
+        match removed {
+            Slot::Occupied(_, value) => Some(value),
+            _ => unreachable!("robin_hood_find returns occupied slots"),
+        }
+    }
+
+    /// Returns the largest number of probes a successful search makes
+    ///
+    /// A key found at probe number `i` of its probe sequence takes `i + 1`
+    /// probes. This is the worst case over the keys currently stored, and
+    /// 0 for an empty table.
+    ///
+    /// # Complexity
+    /// - Time: O(m) times the longest probe sequence
+    pub fn max_probe_length(&self) -> usize {
+        self.arr
+            .iter()
+            .filter_map(|slot| match slot {
+                Slot::Occupied(key, _) => {
+                    (0..self.size).position(|i| match &self.arr[self.probe(key, i)] {
+                        Slot::Occupied(other, _) => other == key,
+                        _ => false,
+                    })
+                }
+                _ => None,
+            })
+            .map(|i| i + 1)
+            .max()
+            .unwrap_or(0)
+    }
+}
+
 /// Helper function for linear probing hash table
 pub fn linear_probe_hash_fn(k: usize, m: usize) -> usize {
     k % m
@@ -319,4 +515,123 @@ mod tests {
             assert!(table.search(key).is_some());
         }
     }
+
+    #[test]
+    fn test_open_addressing_custom_probe_fills_table() {
+        let m = 13;
+        // Step through every slot, starting from a key-dependent offset.
+        let mut table =
+            OpenAddressingHashTable::with_probe_fn(m, Box::new(|hash, i, m| (hash % m + i) % m));
+
+        for key in 0..m - 1 {
+            table.insert(key, key * 10).unwrap();
+        }
+        for key in 0..m - 1 {
+            assert_eq!(table.search(&key), Some(&(key * 10)));
+        }
+
+        // The last free slot is still found, and only then is the table full.
+        table.insert(100, 1000).unwrap();
+        assert_eq!(table.insert(101, 1010), Err("hash table overflow"));
+
+        assert_eq!(table.delete(&3), Some(30));
+        table.insert(101, 1010).unwrap();
+        assert_eq!(table.search(&101), Some(&1010));
+    }
+
+    #[test]
+    fn test_open_addressing_probe_type_matches_probe_fn() {
+        let keys = [10, 22, 31, 4, 15, 28, 17, 88, 59];
+        let mut builtin = OpenAddressingHashTable::new(
+            11,
+            ProbeType::Quadratic { c1: 1, c2: 3 },
+            linear_probe_hash_fn,
+            None,
+        );
+        let mut custom = OpenAddressingHashTable::with_probe_fn(
+            11,
+            Box::new(|k, i, m| (k % m + i + 3 * i * i) % m),
+        );
+
+        for &key in &keys {
+            assert_eq!(builtin.insert(key, ()), custom.insert(key, ()));
+        }
+    }
+
+    #[test]
+    fn test_open_addressing_robin_hood() {
+        let mut table =
+            OpenAddressingHashTable::new(11, ProbeType::RobinHood, linear_probe_hash_fn, None);
+        for key in 0..11 {
+            table.insert(key, key * 2).unwrap();
+        }
+        assert_eq!(table.insert(11, 22), Err("hash table overflow"));
+        for key in 0..11 {
+            assert_eq!(table.search(&key), Some(&(key * 2)));
+        }
+
+        // Updating a key in a full table still succeeds.
+        let slot = table.insert(4, 0).unwrap();
+        assert_eq!(table.arr[slot], Slot::Occupied(4, 0));
+        table.insert(4, 8).unwrap();
+
+        for key in (0..11).step_by(2) {
+            assert_eq!(table.delete(&key), Some(key * 2));
+            assert_eq!(table.delete(&key), None);
+        }
+        for key in 0..11 {
+            let expected = (key % 2 == 1).then_some(key * 2);
+            assert_eq!(table.search(&key).copied(), expected);
+        }
+        // Backward shifting leaves no `Deleted` markers behind.
+        assert!(table.arr.iter().all(|slot| !matches!(slot, Slot::Deleted)));
+
+        // The six freed slots can be filled again before the table overflows.
+        for key in 20..26 {
+            table.insert(key, key).unwrap();
+        }
+        assert_eq!(table.insert(26, 26), Err("hash table overflow"));
+        assert_eq!(table.occupied, 11);
+    }
+
+    #[test]
+    fn test_robin_hood_shortens_longest_probe() {
+        use rand::rngs::StdRng;
+        use rand::{Rng, SeedableRng};
+
+        let m = 1000;
+        let mut rng = StdRng::seed_from_u64(11);
+        let mut linear =
+            OpenAddressingHashTable::new(m, ProbeType::Linear, linear_probe_hash_fn, None);
+        let mut robin_hood =
+            OpenAddressingHashTable::new(m, ProbeType::RobinHood, linear_probe_hash_fn, None);
+
+        let mut keys = Vec::new();
+        while keys.len() < 850 {
+            let key: u64 = rng.gen();
+            if !keys.contains(&key) {
+                keys.push(key);
+                linear.insert(key, ()).unwrap();
+                robin_hood.insert(key, ()).unwrap();
+            }
+        }
+        for key in &keys {
+            assert_eq!(robin_hood.search(key), Some(&()));
+        }
+
+        let linear_max = linear.max_probe_length();
+        let robin_hood_max = robin_hood.max_probe_length();
+        assert!(
+            2 * robin_hood_max < linear_max,
+            "Robin Hood {robin_hood_max} vs linear {linear_max}"
+        );
+
+        // Deleting half the keys keeps every remaining key reachable.
+        for key in keys.iter().step_by(2) {
+            assert_eq!(robin_hood.delete(key), Some(()));
+        }
+        for (index, key) in keys.iter().enumerate() {
+            assert_eq!(robin_hood.search(key).is_some(), index % 2 == 1);
+        }
+    }
 }
@@ -0,0 +1,396 @@
+//! Arena-Backed Binary Search Trees (Sections 12.1-12.3)
+//!
+//! The same unbalanced binary search tree as [`BinarySearchTree`], but with
+//! every node stored in one `Vec` and linked by index instead of by `Box`.
+//! Nodes sit next to each other in memory, cloning the tree copies a single
+//! buffer, and the slots of deleted nodes are reused by later insertions.
+//!
+//! [`BinarySearchTree`]: super::BinarySearchTree
+
+use std::cmp::Ordering;
+
+use super::SortedMap;
+
+/// Index standing for a missing child or parent, like NIL in CLRS
+const NIL: usize = usize::MAX;
+
+#[derive(Debug, Clone)]
+struct ArenaNode<K, V> {
+    key: K,
+    value: V,
+    left: usize,
+    right: usize,
+    parent: usize,
+}
+
+/// Binary search tree whose nodes live in a single arena
+///
+/// Child and parent links are indices into the arena, with [`usize::MAX`]
+/// as the null link. Deleted nodes leave an empty slot that is pushed onto a
+/// free list and handed out again by the next insertion.
+///
+/// # Example
+/// ```
+/// use clrs::chapter_12::ArenaBst;
+/// let mut tree = ArenaBst::new();
+/// tree.insert(5, "five");
+/// tree.insert(3, "three");
+/// tree.insert(8, "eight");
+/// assert_eq!(tree.search(&3), Some(&"three"));
+/// assert_eq!(tree.delete(&5), Some("five"));
+/// let keys: Vec<_> = tree.iter().map(|(k, _)| *k).collect();
+/// assert_eq!(keys, vec![3, 8]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct ArenaBst<K: Ord, V> {
+    nodes: Vec<Option<ArenaNode<K, V>>>,
+    free: Vec<usize>,
+    root: usize,
+    len: usize,
+}
+
+impl<K: Ord, V> ArenaBst<K, V> {
+    /// Creates a new empty tree
+    pub fn new() -> Self {
+        ArenaBst {
+            nodes: Vec::new(),
+            free: Vec::new(),
+            root: NIL,
+            len: 0,
+        }
+    }
+
+    /// Returns the number of keys in the tree
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Checks whether the tree is empty
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    fn node(&self, i: usize) -> &ArenaNode<K, V> {
+        self.nodes[i].as_ref().expect("link points at a live node")
+    }
+
+    fn node_mut(&mut self, i: usize) -> &mut ArenaNode<K, V> {
+        self.nodes[i].as_mut().expect("link points at a live node")
+    }
+
+    /// Returns the index of the node holding `key`, or `NIL`
+    fn find(&self, key: &K) -> usize {
+        let mut x = self.root;
+        while x != NIL {
+            let node = self.node(x);
+            x = match key.cmp(&node.key) {
+                Ordering::Less => node.left,
+                Ordering::Greater => node.right,
+                Ordering::Equal => return x,
+            };
+        }
+        NIL
+    }
+
+    fn subtree_minimum(&self, mut x: usize) -> usize {
+        while self.node(x).left != NIL {
+            x = self.node(x).left;
+        }
+        x
+    }
+
+    fn subtree_maximum(&self, mut x: usize) -> usize {
+        while self.node(x).right != NIL {
+            x = self.node(x).right;
+        }
+        x
+    }
+
+    /// Returns the in-order successor of node `x`, or `NIL`
+    fn successor(&self, mut x: usize) -> usize {
+        if self.node(x).right != NIL {
+            return self.subtree_minimum(self.node(x).right);
+        }
+        let mut y = self.node(x).parent;
+        while y != NIL && x == self.node(y).right {
+            x = y;
+            y = self.node(y).parent;
+        }
+        y
+    }
+
+    /// Searches for `key`, returning its value if present
+    ///
+    /// This corresponds to ITERATIVE-TREE-SEARCH from CLRS Section 12.2.
+    ///
+    /// # Complexity
+    /// - Time: O(h) where h is the height of the tree
+    pub fn search(&self, key: &K) -> Option<&V> {
+        match self.find(key) {
+            NIL => None,
+            x => Some(&self.node(x).value),
+        }
+    }
+
+    /// Returns the smallest key and its value
+    pub fn minimum(&self) -> Option<(&K, &V)> {
+        if self.root == NIL {
+            return None;
+        }
+        let node = self.node(self.subtree_minimum(self.root));
+        Some((&node.key, &node.value))
+    }
+
+    /// Returns the largest key and its value
+    pub fn maximum(&self) -> Option<(&K, &V)> {
+        if self.root == NIL {
+            return None;
+        }
+        let node = self.node(self.subtree_maximum(self.root));
+        Some((&node.key, &node.value))
+    }
+
+    /// Inserts a key-value pair into the tree
+    ///
+    /// This corresponds to TREE-INSERT from CLRS Section 12.3. The new node
+    /// takes a slot from the free list when one is available. If the key is
+    /// already present its value is replaced instead.
+    ///
+    /// # Returns
+    /// The previous value stored under `key`, if any
+    ///
+    /// # Complexity
+    /// - Time: O(h) where h is the height of the tree
+    pub fn insert(&mut self, key: K, value: V) -> Option<V> {
+        let mut y = NIL;
+        let mut x = self.root;
+        let mut went_left = false;
+        while x != NIL {
+            y = x;
+            let node = self.node_mut(x);
+            match key.cmp(&node.key) {
+                Ordering::Less => {
+                    x = node.left;
+                    went_left = true;
+                }
+                Ordering::Greater => {
+                    x = node.right;
+                    went_left = false;
+                }
+                Ordering::Equal => return Some(std::mem::replace(&mut node.value, value)),
+            }
+        }
+
+        let z = self.allocate(ArenaNode {
+            key,
+            value,
+            left: NIL,
+            right: NIL,
+            parent: y,
+        });
+        if y == NIL {
+            self.root = z;
+        } else if went_left {
+            self.node_mut(y).left = z;
+        } else {
+            self.node_mut(y).right = z;
+        }
+        self.len += 1;
+        None
+    }
+
+    fn allocate(&mut self, node: ArenaNode<K, V>) -> usize {
+        match self.free.pop() {
+            Some(i) => {
+                self.nodes[i] = Some(node);
+                i
+            }
+            None => {
+                self.nodes.push(Some(node));
+                self.nodes.len() - 1
+            }
+        }
+    }
+
+    /// Replaces the subtree rooted at `u` with the one rooted at `v`
+    ///
+    /// This corresponds to TRANSPLANT from CLRS Section 12.3.
+    fn transplant(&mut self, u: usize, v: usize) {
+        let p = self.node(u).parent;
+        if p == NIL {
+            self.root = v;
+        } else if self.node(p).left == u {
+            self.node_mut(p).left = v;
+        } else {
+            self.node_mut(p).right = v;
+        }
+        if v != NIL {
+            self.node_mut(v).parent = p;
+        }
+    }
+
+    /// Deletes `key` from the tree
+    ///
+    /// This corresponds to TREE-DELETE from CLRS Section 12.3. The slot of
+    /// the removed node goes onto the free list.
+    ///
+    /// # Returns
+    /// The deleted value if the key was present
+    ///
+    /// # Complexity
+    /// - Time: O(h) where h is the height of the tree
+    pub fn delete(&mut self, key: &K) -> Option<V> {
+        let z = self.find(key);
+        if z == NIL {
+            return None;
+        }
+        let (z_left, z_right) = (self.node(z).left, self.node(z).right);
+        if z_left == NIL {
+            self.transplant(z, z_right);
+        } else if z_right == NIL {
+            self.transplant(z, z_left);
+        } else {
+            let y = self.subtree_minimum(z_right);
+            if self.node(y).parent != z {
+                let y_right = self.node(y).right;
+                self.transplant(y, y_right);
+                self.node_mut(y).right = z_right;
+                self.node_mut(z_right).parent = y;
+            }
+            self.transplant(z, y);
+            self.node_mut(y).left = z_left;
+            self.node_mut(z_left).parent = y;
+        }
+
+        let node = self.nodes[z].take().expect("z is a live node");
+        self.free.push(z);
+        self.len -= 1;
+        Some(node.value)
+    }
+
+    /// Iterates over the key-value pairs in increasing key order
+    ///
+    /// The walk follows successor links, so it needs no stack.
+    pub fn iter(&self) -> impl Iterator<Item = (&K, &V)> + '_ {
+        let first = if self.root == NIL {
+            NIL
+        } else {
+            self.subtree_minimum(self.root)
+        };
+        std::iter::successors((first != NIL).then_some(first), move |&x| {
+            let next = self.successor(x);
+            (next != NIL).then_some(next)
+        })
+        .map(move |x| {
+            let node = self.node(x);
+            (&node.key, &node.value)
+        })
+    }
+}
+
+impl<K: Ord, V> Default for ArenaBst<K, V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K: Ord, V> SortedMap<K, V> for ArenaBst<K, V> {
+    fn insert(&mut self, key: K, value: V) -> Option<V> {
+        ArenaBst::insert(self, key, value)
+    }
+
+    fn get(&self, key: &K) -> Option<&V> {
+        self.search(key)
+    }
+
+    fn remove(&mut self, key: &K) -> Option<V> {
+        self.delete(key)
+    }
+
+    fn min(&self) -> Option<(&K, &V)> {
+        self.minimum()
+    }
+
+    fn max(&self) -> Option<(&K, &V)> {
+        self.maximum()
+    }
+
+    fn len(&self) -> usize {
+        ArenaBst::len(self)
+    }
+
+    fn is_empty(&self) -> bool {
+        ArenaBst::is_empty(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::chapter_12::BinarySearchTree;
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+
+    #[test]
+    fn test_arena_bst_agrees_with_boxed_bst() {
+        let mut rng = StdRng::seed_from_u64(450);
+        let mut arena = ArenaBst::new();
+        let mut boxed = BinarySearchTree::new();
+        for _ in 0..100_000 {
+            let key: u32 = rng.gen_range(0..1_000_000);
+            assert_eq!(arena.insert(key, key / 3), boxed.insert(key, key / 3));
+        }
+        assert_eq!(arena.len(), {
+            let mut count = 0;
+            boxed.inorder_walk(|_, _| count += 1);
+            count
+        });
+
+        for _ in 0..100_000 {
+            let key: u32 = rng.gen_range(0..1_000_000);
+            assert_eq!(arena.search(&key), boxed.search(key));
+        }
+        assert_eq!(arena.minimum(), boxed.minimum());
+        assert_eq!(arena.maximum(), boxed.maximum());
+
+        let mut boxed_pairs = Vec::new();
+        boxed.inorder_walk(|k, v| boxed_pairs.push((*k, *v)));
+        let arena_pairs: Vec<_> = arena.iter().map(|(k, v)| (*k, *v)).collect();
+        assert_eq!(arena_pairs, boxed_pairs);
+    }
+
+    #[test]
+    fn test_arena_bst_delete_reuses_slots() {
+        let mut tree = ArenaBst::new();
+        for key in [50, 30, 70, 20, 40, 60, 80, 35, 45, 65] {
+            tree.insert(key, key * 10);
+        }
+        let slots = tree.nodes.len();
+
+        // Leaf, one child, two children with the successor deeper down, root
+        assert_eq!(tree.delete(&20), Some(200));
+        assert_eq!(tree.delete(&60), Some(600));
+        assert_eq!(tree.delete(&30), Some(300));
+        assert_eq!(tree.delete(&50), Some(500));
+        assert_eq!(tree.delete(&50), None);
+        let keys: Vec<_> = tree.iter().map(|(k, _)| *k).collect();
+        assert_eq!(keys, vec![35, 40, 45, 65, 70, 80]);
+        assert_eq!(tree.len(), 6);
+
+        for key in [1, 2, 3, 4] {
+            tree.insert(key, key);
+        }
+        assert_eq!(tree.nodes.len(), slots);
+        assert!(tree.free.is_empty());
+        let keys: Vec<_> = tree.iter().map(|(k, _)| *k).collect();
+        assert_eq!(keys, vec![1, 2, 3, 4, 35, 40, 45, 65, 70, 80]);
+
+        let copy = tree.clone();
+        for key in keys {
+            assert_eq!(tree.delete(&key), copy.search(&key).copied());
+        }
+        assert!(tree.is_empty());
+        assert_eq!(tree.iter().count(), 0);
+        assert_eq!(copy.len(), 10);
+    }
+}
@@ -5,6 +5,10 @@
 //! INSERT, and DELETE.
 
 use std::cmp::Ordering;
+use std::fmt::{Display, Write};
+
+use super::flat_bytes::write_flag;
+use super::{ByteReader, DeserializeError, FromBytes, SortedMap, ToBytes};
 
 /// Node in a binary search tree
 #[derive(Debug, Clone)]
@@ -15,6 +19,9 @@ pub struct BSTNode<K: Ord, V> {
     pub right: Option<Box<BSTNode<K, V>>>,
 }
 
+/// An owned, possibly empty subtree
+type Link<K, V> = Option<Box<BSTNode<K, V>>>;
+
 /// Binary search tree
 ///
 /// This corresponds to the binary search tree implementation from CLRS Chapter 12.
@@ -45,6 +52,81 @@ impl<K: Ord, V> BinarySearchTree<K, V> {
         BinarySearchTree { root: None }
     }
 
+    /// Builds a balanced tree from key-value pairs sorted by key
+    ///
+    /// The middle pair of each range becomes the subtree root, so the
+    /// resulting tree has height ⌈lg(n + 1)⌉ instead of the linear height
+    /// produced by inserting sorted keys one at a time.
+    ///
+    /// # Arguments
+    /// * `pairs` - Key-value pairs with strictly increasing keys
+    ///
+    /// # Panics
+    /// In debug builds, panics if the keys are not strictly increasing
+    ///
+    /// # Complexity
+    /// - Time: O(n) where n is the number of pairs
+    ///
+    /// # Example
+    /// ```
+    /// use clrs::chapter_12::BinarySearchTree;
+    /// let tree = BinarySearchTree::from_sorted(vec![(1, "a"), (2, "b"), (3, "c")]);
+    /// assert_eq!(tree.root.as_ref().unwrap().key, 2);
+    /// assert_eq!(tree.height(), 2);
+    /// ```
+    pub fn from_sorted(pairs: Vec<(K, V)>) -> Self {
+        debug_assert!(
+            pairs.windows(2).all(|w| w[0].0 < w[1].0),
+            "keys must be strictly increasing"
+        );
+        let n = pairs.len();
+        let mut iter = pairs.into_iter();
+        BinarySearchTree {
+            root: Self::build_balanced(&mut iter, n),
+        }
+    }
+
+    /// Builds a balanced subtree from the next `n` pairs of `iter`
+    ///
+    /// The left subtree is built first so that pairs are consumed in key order.
+    fn build_balanced<I>(iter: &mut I, n: usize) -> Option<Box<BSTNode<K, V>>>
+    where
+        I: Iterator<Item = (K, V)>,
+    {
+        if n == 0 {
+            return None;
+        }
+        let left_size = n / 2;
+        let left = Self::build_balanced(iter, left_size);
+        let (key, value) = iter.next()?;
+        let right = Self::build_balanced(iter, n - left_size - 1);
+        Some(Box::new(BSTNode {
+            key,
+            value,
+            left,
+            right,
+        }))
+    }
+
+    /// Returns the height of the tree
+    ///
+    /// The height is measured as the number of nodes on the longest path
+    /// from the root to a leaf, so an empty tree has height 0 and a single
+    /// node has height 1.
+    ///
+    /// # Complexity
+    /// - Time: O(n) where n is the number of nodes
+    pub fn height(&self) -> usize {
+        Self::height_node(&self.root)
+    }
+
+    fn height_node(node: &Option<Box<BSTNode<K, V>>>) -> usize {
+        match node {
+            None => 0,
+            Some(n) => 1 + Self::height_node(&n.left).max(Self::height_node(&n.right)),
+        }
+    }
+
     /// Searches for a key in the tree
     ///
     /// This corresponds to TREE-SEARCH from CLRS Section 12.2.
@@ -234,17 +316,63 @@ impl<K: Ord, V> BinarySearchTree<K, V> {
         }
     }
 
+    /// Returns an owned copy of the successor key of `k`
+    ///
+    /// Like [`successor`](Self::successor), this returns `None` if `k` is not
+    /// in the tree.
+    pub fn successor_key(&self, k: &K) -> Option<K>
+    where
+        K: Clone,
+    {
+        self.successor(k).map(|(key, _)| key.clone())
+    }
+
+    /// Returns an owned copy of the predecessor key of `k`
+    ///
+    /// Like [`predecessor`](Self::predecessor), this returns `None` if `k` is
+    /// not in the tree.
+    pub fn predecessor_key(&self, k: &K) -> Option<K>
+    where
+        K: Clone,
+    {
+        self.predecessor(k).map(|(key, _)| key.clone())
+    }
+
+    /// Finds the entry with the smallest key strictly greater than `k`
+    ///
+    /// Unlike [`successor`](Self::successor), `k` need not be present in the tree.
+    ///
+    /// # Complexity
+    /// - Time: O(h) where h is the height of the tree
+    pub fn successor_of_value(&self, k: &K) -> Option<(&K, &V)> {
+        Self::find_successor_ancestor(&self.root, k, None)
+    }
+
+    /// Finds the entry with the largest key strictly less than `k`
+    ///
+    /// Unlike [`predecessor`](Self::predecessor), `k` need not be present in the tree.
+    ///
+    /// # Complexity
+    /// - Time: O(h) where h is the height of the tree
+    pub fn predecessor_of_value(&self, k: &K) -> Option<(&K, &V)> {
+        Self::find_predecessor_ancestor(&self.root, k, None)
+    }
+
     /// Inserts a key-value pair into the tree
     ///
-    /// This corresponds to TREE-INSERT from CLRS Section 12.3.
+    /// This corresponds to TREE-INSERT from CLRS Section 12.3. If the key is
+    /// already present, its value is replaced.
     ///
     /// # Arguments
     /// * `k` - The key to insert
     /// * `v` - The value to insert
     ///
+    /// # Returns
+    /// The previous value for `k`, or `None` if the key was new
+    ///
     /// # Complexity
     /// - Time: O(h) where h is the height of the tree
-    pub fn insert(&mut self, k: K, v: V) {
+    pub fn insert(&mut self, k: K, v: V) -> Option<V> {
         let new_node = Box::new(BSTNode {
             key: k,
             value: v,
@@ -252,23 +380,25 @@ impl<K: Ord, V> BinarySearchTree<K, V> {
             right: None,
         });
 
-        if self.root.is_none() {
-            self.root = Some(new_node);
-        } else {
-            Self::insert_node(&mut self.root, new_node);
-        }
+        Self::insert_node(&mut self.root, new_node)
     }
 
-    fn insert_node(node: &mut Option<Box<BSTNode<K, V>>>, new_node: Box<BSTNode<K, V>>) {
+    fn insert_node(
+        node: &mut Option<Box<BSTNode<K, V>>>,
+        new_node: Box<BSTNode<K, V>>,
+    ) -> Option<V> {
         match node {
-            None => *node = Some(new_node),
+            None => {
+                *node = Some(new_node);
+                None
+            }
             Some(n) => {
                 match new_node.key.cmp(&n.key) {
                     Ordering::Less => Self::insert_node(&mut n.left, new_node),
                     Ordering::Greater => Self::insert_node(&mut n.right, new_node),
                     Ordering::Equal => {
                         // Key already exists, update value
-                        n.value = new_node.value;
+                        Some(std::mem::replace(&mut n.value, new_node.value))
                     }
                 }
             }
@@ -363,6 +493,166 @@ impl<K: Ord, V> BinarySearchTree<K, V> {
         }
     }
 
+    /// Moves every node of `other` into this tree
+    ///
+    /// When every key of `other` is greater than every key of `self`, the
+    /// root of `other` becomes the right child of this tree's maximum, and
+    /// symmetrically when every key of `other` is smaller it hangs off the
+    /// minimum. Either way only two root-to-extreme paths are walked. If the
+    /// key ranges overlap, the nodes of `other` are detached one by one and
+    /// re-linked with TREE-INSERT; a key present in both trees ends up with
+    /// the value from `other`, as with [`insert`](Self::insert). No node is
+    /// cloned or reallocated in either case.
+    ///
+    /// # Arguments
+    /// * `other` - The tree to consume
+    ///
+    /// # Complexity
+    /// - Time: O(h1 + h2) for disjoint key ranges, O(m h) otherwise, where m
+    ///   is the number of nodes in `other` and h the height of the merged tree
+    ///
+    /// # Example
+    /// ```
+    /// use clrs::chapter_12::BinarySearchTree;
+    /// let mut low = BinarySearchTree::from_sorted(vec![(1, "a"), (2, "b")]);
+    /// let high = BinarySearchTree::from_sorted(vec![(3, "c"), (4, "d")]);
+    /// low.merge(high);
+    /// assert_eq!(low.search(4), Some(&"d"));
+    /// assert_eq!(low.maximum(), Some((&4, &"d")));
+    /// ```
+    pub fn merge(&mut self, other: BinarySearchTree<K, V>) {
+        let (Some((other_min, _)), Some((other_max, _))) = (other.minimum(), other.maximum())
+        else {
+            return;
+        };
+        let (other_above, other_below) = match (self.minimum(), self.maximum()) {
+            (Some((self_min, _)), Some((self_max, _))) => {
+                (self_max < other_min, other_max < self_min)
+            }
+            _ => (true, false),
+        };
+        let other_root = other.root.expect("a tree with a minimum has a root");
+
+        if other_above {
+            let mut slot = &mut self.root;
+            while let Some(node) = slot {
+                slot = &mut node.right;
+            }
+            *slot = Some(other_root);
+        } else if other_below {
+            let mut slot = &mut self.root;
+            while let Some(node) = slot {
+                slot = &mut node.left;
+            }
+            *slot = Some(other_root);
+        } else {
+            let mut pending = vec![other_root];
+            while let Some(mut node) = pending.pop() {
+                pending.extend(node.left.take());
+                pending.extend(node.right.take());
+                Self::insert_node(&mut self.root, node);
+            }
+        }
+    }
+
+    /// Splits the tree at `k` into the keys less than `k` and the rest
+    ///
+    /// The search path for `k` is walked once; each node on it is handed to
+    /// the left tree if its key is less than `k` and to the right tree
+    /// otherwise, taking along its subtree on the far side of the path. The
+    /// nodes off the path keep their shape and no node is reallocated.
+    ///
+    /// # Arguments
+    /// * `k` - The split key, which need not be present in the tree
+    ///
+    /// # Returns
+    /// A pair of trees holding the keys `< k` and `>= k` respectively
+    ///
+    /// # Complexity
+    /// - Time: O(h) where h is the height of the tree
+    ///
+    /// # Example
+    /// ```
+    /// use clrs::chapter_12::BinarySearchTree;
+    /// let tree = BinarySearchTree::from_sorted((0..10).map(|k| (k, ())).collect());
+    /// let (low, high) = tree.split(&4);
+    /// assert_eq!(low.maximum(), Some((&3, &())));
+    /// assert_eq!(high.minimum(), Some((&4, &())));
+    /// ```
+    pub fn split(self, k: &K) -> (BinarySearchTree<K, V>, BinarySearchTree<K, V>) {
+        let (left, right) = Self::split_node(self.root, k);
+        (
+            BinarySearchTree { root: left },
+            BinarySearchTree { root: right },
+        )
+    }
+
+    fn split_node(node: Link<K, V>, k: &K) -> (Link<K, V>, Link<K, V>) {
+        match node {
+            None => (None, None),
+            Some(mut n) => {
+                if n.key < *k {
+                    let (less, rest) = Self::split_node(n.right.take(), k);
+                    n.right = less;
+                    (Some(n), rest)
+                } else {
+                    let (rest, greater) = Self::split_node(n.left.take(), k);
+                    n.left = greater;
+                    (rest, Some(n))
+                }
+            }
+        }
+    }
+
+    /// Joins two trees whose key ranges do not overlap
+    ///
+    /// The minimum of `right` is detached and becomes the new root, with
+    /// `left` and the rest of `right` as its subtrees, so the height of the
+    /// result is at most one more than the taller input.
+    ///
+    /// # Arguments
+    /// * `left` - A tree whose keys are all less than every key of `right`
+    /// * `right` - A tree whose keys are all greater than every key of `left`
+    ///
+    /// # Panics
+    /// In debug builds, panics if some key of `left` is not less than every
+    /// key of `right`
+    ///
+    /// # Complexity
+    /// - Time: O(h1 + h2) where h1 and h2 are the heights of the two trees
+    ///
+    /// # Example
+    /// ```
+    /// use clrs::chapter_12::BinarySearchTree;
+    /// let low = BinarySearchTree::from_sorted(vec![(1, "a"), (2, "b")]);
+    /// let high = BinarySearchTree::from_sorted(vec![(3, "c"), (4, "d")]);
+    /// let tree = BinarySearchTree::join(low, high);
+    /// assert_eq!(tree.root.as_ref().unwrap().key, 3);
+    /// assert_eq!(tree.minimum(), Some((&1, &"a")));
+    /// ```
+    pub fn join(left: Self, right: Self) -> Self {
+        debug_assert!(
+            match (left.maximum(), right.minimum()) {
+                (Some((left_max, _)), Some((right_min, _))) => left_max < right_min,
+                _ => true,
+            },
+            "every key of left must be less than every key of right"
+        );
+        let mut rest = right.root;
+        if rest.is_none() {
+            return left;
+        }
+        let (key, value) = Self::extract_minimum(&mut rest);
+        BinarySearchTree {
+            root: Some(Box::new(BSTNode {
+                key,
+                value,
+                left: left.root,
+                right: rest,
+            })),
+        }
+    }
+
     /// Performs an in-order tree walk
     ///
     /// This corresponds to INORDER-TREE-WALK from CLRS Section 12.1.
@@ -445,12 +735,225 @@ impl<K: Ord, V> BinarySearchTree<K, V> {
     }
 }
 
+impl<K: Ord + Display, V> BinarySearchTree<K, V> {
+    /// Draws the tree sideways, one key per line
+    ///
+    /// The root is at the left margin and each level is indented four more
+    /// spaces. The right subtree is drawn above its parent and the left
+    /// subtree below, so turning the output a quarter turn clockwise shows
+    /// the tree with its root at the top. An empty tree draws as an empty
+    /// string.
+    ///
+    /// # Example
+    /// ```
+    /// use clrs::chapter_12::BinarySearchTree;
+    /// let mut tree = BinarySearchTree::new();
+    /// tree.insert(2, ());
+    /// tree.insert(1, ());
+    /// tree.insert(3, ());
+    /// assert_eq!(tree.to_ascii(), "    3\n2\n    1\n");
+    /// ```
+    pub fn to_ascii(&self) -> String {
+        let mut out = String::new();
+        Self::draw_node(&self.root, 0, &mut out);
+        out
+    }
+
+    fn draw_node(node: &Option<Box<BSTNode<K, V>>>, depth: usize, out: &mut String) {
+        if let Some(n) = node {
+            Self::draw_node(&n.right, depth + 1, out);
+            writeln!(out, "{:indent$}{}", "", n.key, indent = 4 * depth).unwrap();
+            Self::draw_node(&n.left, depth + 1, out);
+        }
+    }
+}
+
+impl<K: Ord + ToBytes, V: ToBytes> BinarySearchTree<K, V> {
+    /// Writes the tree to a flat byte buffer
+    ///
+    /// Nodes are written in preorder, each as a presence byte 1 followed by
+    /// its key and value, with a single byte 0 standing for each empty
+    /// subtree. The exact shape of the tree is kept, so
+    /// [`deserialize`](Self::deserialize) rebuilds an identical tree.
+    ///
+    /// # Complexity
+    /// - Time: O(n) where n is the number of nodes
+    ///
+    /// # Example
+    /// ```
+    /// use clrs::chapter_12::BinarySearchTree;
+    /// let mut tree = BinarySearchTree::new();
+    /// tree.insert(2u8, 20u8);
+    /// tree.insert(1, 10);
+    /// assert_eq!(tree.serialize(), vec![1, 2, 20, 1, 1, 10, 0, 0, 0]);
+    /// ```
+    pub fn serialize(&self) -> Vec<u8> {
+        let mut out = Vec::new();
+        // An explicit stack, so that a degenerate tree cannot overflow the
+        // call stack
+        let mut pending = vec![&self.root];
+        while let Some(link) = pending.pop() {
+            write_flag(link.is_some(), &mut out);
+            if let Some(n) = link {
+                n.key.write_bytes(&mut out);
+                n.value.write_bytes(&mut out);
+                pending.push(&n.right);
+                pending.push(&n.left);
+            }
+        }
+        out
+    }
+}
+
+impl<K: Ord + FromBytes, V: FromBytes> BinarySearchTree<K, V> {
+    /// Rebuilds a tree written by [`serialize`](Self::serialize)
+    ///
+    /// # Errors
+    /// Returns a [`DeserializeError`] if the bytes are truncated, contain
+    /// extra data after the tree, or describe keys that violate the
+    /// binary-search-tree property
+    ///
+    /// # Complexity
+    /// - Time: O(n) where n is the number of nodes
+    ///
+    /// # Example
+    /// ```
+    /// use clrs::chapter_12::BinarySearchTree;
+    /// let tree: BinarySearchTree<u32, String> =
+    ///     [(3, "c".to_string()), (1, "a".to_string())].into_iter().collect();
+    /// let copy = BinarySearchTree::<u32, String>::deserialize(&tree.serialize()).unwrap();
+    /// assert_eq!(copy.search(1), Some(&"a".to_string()));
+    /// ```
+    pub fn deserialize(bytes: &[u8]) -> Result<Self, DeserializeError> {
+        let mut reader = ByteReader::new(bytes);
+        let root = Self::deserialize_nodes(&mut reader)?;
+        reader.finish()?;
+        Ok(BinarySearchTree { root })
+    }
+
+    /// Reads the preorder encoding of a whole tree with an explicit stack,
+    /// so that even a degenerate tree decodes without deep recursion
+    ///
+    /// The stack holds the ancestors of the subtree being read. An ancestor
+    /// whose left subtree is still open bounds the current keys from above,
+    /// one whose left subtree is complete bounds them from below, and the
+    /// nearest of each kind is recorded in every frame by stack index.
+    fn deserialize_nodes(reader: &mut ByteReader<'_>) -> Result<Link<K, V>, DeserializeError> {
+        struct Frame<K: Ord, V> {
+            key: K,
+            value: V,
+            /// `None` while the left subtree is being read
+            left: Option<Link<K, V>>,
+            lower: Option<usize>,
+            upper: Option<usize>,
+        }
+
+        let mut stack: Vec<Frame<K, V>> = Vec::new();
+        loop {
+            let mut subtree: Link<K, V> = None;
+            if reader.read_flag()? {
+                let key = K::read_bytes(reader)?;
+                let value = V::read_bytes(reader)?;
+                let (lower, upper) = match stack.last() {
+                    None => (None, None),
+                    Some(parent) if parent.left.is_none() => (parent.lower, Some(stack.len() - 1)),
+                    Some(parent) => (Some(stack.len() - 1), parent.upper),
+                };
+                if lower.is_some_and(|low| key <= stack[low].key)
+                    || upper.is_some_and(|high| key >= stack[high].key)
+                {
+                    return Err(DeserializeError::Malformed("keys are out of order"));
+                }
+                stack.push(Frame {
+                    key,
+                    value,
+                    left: None,
+                    lower,
+                    upper,
+                });
+                continue;
+            }
+
+            // A subtree is complete: attach it as a left subtree and go on to
+            // the right one, or finish every node whose right subtree it was.
+            loop {
+                match stack.last_mut() {
+                    None => return Ok(subtree),
+                    Some(top) if top.left.is_none() => {
+                        top.left = Some(subtree);
+                        break;
+                    }
+                    Some(_) => {
+                        let frame = stack.pop().expect("stack is not empty");
+                        subtree = Some(Box::new(BSTNode {
+                            key: frame.key,
+                            value: frame.value,
+                            left: frame.left.expect("left subtree is complete"),
+                            right: subtree,
+                        }));
+                    }
+                }
+            }
+        }
+    }
+}
+
 impl<K: Ord, V> Default for BinarySearchTree<K, V> {
     fn default() -> Self {
         Self::new()
     }
 }
 
+impl<K: Ord, V> Extend<(K, V)> for BinarySearchTree<K, V> {
+    /// Inserts every pair with [`insert`](BinarySearchTree::insert); a
+    /// repeated key keeps the last value.
+    fn extend<I: IntoIterator<Item = (K, V)>>(&mut self, iter: I) {
+        for (key, value) in iter {
+            self.insert(key, value);
+        }
+    }
+}
+
+impl<K: Ord, V> FromIterator<(K, V)> for BinarySearchTree<K, V> {
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        let mut tree = Self::new();
+        tree.extend(iter);
+        tree
+    }
+}
+
+impl<K: Ord, V> SortedMap<K, V> for BinarySearchTree<K, V> {
+    fn insert(&mut self, key: K, value: V) -> Option<V> {
+        BinarySearchTree::insert(self, key, value)
+    }
+
+    fn get(&self, key: &K) -> Option<&V> {
+        self.search_node(&self.root, key)
+    }
+
+    fn remove(&mut self, key: &K) -> Option<V> {
+        self.delete(key)
+    }
+
+    fn min(&self) -> Option<(&K, &V)> {
+        self.minimum()
+    }
+
+    fn max(&self) -> Option<(&K, &V)> {
+        self.maximum()
+    }
+
+    fn len(&self) -> usize {
+        let mut count = 0;
+        self.inorder_walk(|_, _| count += 1);
+        count
+    }
+
+    fn is_empty(&self) -> bool {
+        self.root.is_none()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -511,6 +1014,61 @@ mod tests {
         assert_eq!(tree.predecessor(&1), None);
     }
 
+    #[test]
+    fn test_bst_successor_predecessor_key() {
+        let mut tree = BinarySearchTree::new();
+        for k in [5, 3, 7, 1, 9] {
+            tree.insert(k.to_string(), k);
+        }
+
+        let succ: Option<String> = tree.successor_key(&"5".to_string());
+        assert_eq!(succ, Some("7".to_string()));
+        assert_eq!(
+            tree.predecessor_key(&"5".to_string()),
+            Some("3".to_string())
+        );
+        assert_eq!(tree.successor_key(&"9".to_string()), None);
+        assert_eq!(tree.predecessor_key(&"1".to_string()), None);
+
+        // Absent keys have no successor/predecessor in the CLRS sense
+        assert_eq!(tree.successor_key(&"4".to_string()), None);
+        assert_eq!(tree.predecessor_key(&"4".to_string()), None);
+    }
+
+    #[test]
+    fn test_bst_successor_of_value() {
+        let mut tree = BinarySearchTree::new();
+        for k in [10, 5, 15, 3, 7, 20] {
+            tree.insert(k, k * 10);
+        }
+
+        // Present keys
+        assert_eq!(tree.successor_of_value(&7), Some((&10, &100)));
+        assert_eq!(tree.successor_of_value(&15), Some((&20, &200)));
+        assert_eq!(tree.successor_of_value(&20), None);
+
+        // Absent keys
+        assert_eq!(tree.successor_of_value(&0), Some((&3, &30)));
+        assert_eq!(tree.successor_of_value(&8), Some((&10, &100)));
+        assert_eq!(tree.successor_of_value(&12), Some((&15, &150)));
+        assert_eq!(tree.successor_of_value(&25), None);
+    }
+
+    #[test]
+    fn test_bst_predecessor_of_value() {
+        let mut tree = BinarySearchTree::new();
+        for k in [10, 5, 15, 3, 7, 20] {
+            tree.insert(k, k * 10);
+        }
+
+        assert_eq!(tree.predecessor_of_value(&10), Some((&7, &70)));
+        assert_eq!(tree.predecessor_of_value(&3), None);
+        assert_eq!(tree.predecessor_of_value(&8), Some((&7, &70)));
+        assert_eq!(tree.predecessor_of_value(&16), Some((&15, &150)));
+        assert_eq!(tree.predecessor_of_value(&100), Some((&20, &200)));
+        assert_eq!(tree.predecessor_of_value(&1), None);
+    }
+
     #[test]
     fn test_bst_delete_no_children() {
         let mut tree = BinarySearchTree::new();
@@ -588,4 +1146,241 @@ mod tests {
         tree.postorder_walk(|k, _| keys.push(*k));
         assert_eq!(keys, vec![3, 7, 5]);
     }
+
+    #[test]
+    fn test_bst_height() {
+        let mut tree = BinarySearchTree::new();
+        assert_eq!(tree.height(), 0);
+        tree.insert(5, ());
+        assert_eq!(tree.height(), 1);
+        tree.insert(3, ());
+        tree.insert(1, ());
+        tree.insert(7, ());
+        assert_eq!(tree.height(), 3);
+    }
+
+    #[test]
+    fn test_bst_from_sorted_is_balanced() {
+        let pairs: Vec<(i32, i32)> = (0..1023).map(|k| (k, k * 2)).collect();
+        let tree = BinarySearchTree::from_sorted(pairs);
+
+        assert_eq!(tree.height(), 10);
+        for k in 0..1023 {
+            assert_eq!(tree.search(k), Some(&(k * 2)));
+        }
+
+        let mut keys = Vec::new();
+        tree.inorder_walk(|k, _| keys.push(*k));
+        assert_eq!(keys, (0..1023).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_bst_from_sorted_empty() {
+        let tree: BinarySearchTree<i32, ()> = BinarySearchTree::from_sorted(Vec::new());
+        assert!(tree.root.is_none());
+    }
+
+    #[test]
+    fn test_bst_merge_disjoint_attaches_subtree() {
+        let mut low = BinarySearchTree::from_sorted((0..7).map(|k| (k, k)).collect());
+        let high = BinarySearchTree::from_sorted((10..17).map(|k| (k, k)).collect());
+        low.merge(high);
+
+        // The old maximum 6 now has the other tree's root as its right child.
+        let mut node = low.root.as_ref().unwrap();
+        while node.key != 6 {
+            node = node.right.as_ref().unwrap();
+        }
+        assert_eq!(node.right.as_ref().unwrap().key, 13);
+        assert_eq!(low.height(), 6);
+
+        let below = BinarySearchTree::from_sorted(vec![(-2, -2), (-1, -1)]);
+        low.merge(below);
+        let mut keys = Vec::new();
+        low.inorder_walk(|k, v| {
+            assert_eq!(k, v);
+            keys.push(*k);
+        });
+        let expected: Vec<i32> = (-2..0).chain(0..7).chain(10..17).collect();
+        assert_eq!(keys, expected);
+
+        let mut empty = BinarySearchTree::new();
+        empty.merge(low);
+        assert_eq!(empty.minimum(), Some((&-2, &-2)));
+        empty.merge(BinarySearchTree::new());
+        assert_eq!(empty.maximum(), Some((&16, &16)));
+    }
+
+    #[test]
+    fn test_bst_merge_overlapping_reinserts() {
+        let mut evens = BinarySearchTree::new();
+        for k in [8, 4, 12, 0, 16] {
+            evens.insert(k, "self");
+        }
+        let mut mixed = BinarySearchTree::new();
+        for k in [5, 12, 3, 20] {
+            mixed.insert(k, "other");
+        }
+        evens.merge(mixed);
+
+        let mut pairs = Vec::new();
+        evens.inorder_walk(|k, v| pairs.push((*k, *v)));
+        assert_eq!(
+            pairs,
+            vec![
+                (0, "self"),
+                (3, "other"),
+                (4, "self"),
+                (5, "other"),
+                (8, "self"),
+                (12, "other"),
+                (16, "self"),
+                (20, "other"),
+            ]
+        );
+        assert_eq!(evens.root.as_ref().unwrap().key, 8);
+    }
+
+    #[test]
+    fn test_bst_split_and_join() {
+        let tree: BinarySearchTree<i32, i32> = (0..100).map(|k| ((k * 37) % 100, k)).collect();
+        let (low, high) = tree.split(&50);
+
+        let mut low_keys = Vec::new();
+        low.inorder_walk(|k, _| low_keys.push(*k));
+        assert_eq!(low_keys, (0..50).collect::<Vec<_>>());
+        let mut high_keys = Vec::new();
+        high.inorder_walk(|k, _| high_keys.push(*k));
+        assert_eq!(high_keys, (50..100).collect::<Vec<_>>());
+
+        let joined = BinarySearchTree::join(low, high);
+        let mut pairs = Vec::new();
+        joined.inorder_walk(|k, v| pairs.push((*k, *v)));
+        let expected: Vec<(i32, i32)> = (0..100)
+            .map(|key| (key, (0..100).find(|k| (k * 37) % 100 == key).unwrap()))
+            .collect();
+        assert_eq!(pairs, expected);
+
+        // Split keys outside the key range leave one side empty.
+        let (empty, all) = joined.split(&-1);
+        assert!(empty.root.is_none());
+        let (all, empty) = all.split(&100);
+        assert!(empty.root.is_none());
+        assert_eq!(all.minimum(), Some((&0, &0)));
+        let joined = BinarySearchTree::join(all, empty);
+        assert_eq!(joined.maximum(), Some((&99, &27)));
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "less than every key of right")]
+    fn test_bst_join_rejects_overlap() {
+        let low = BinarySearchTree::from_sorted(vec![(1, ()), (5, ())]);
+        let high = BinarySearchTree::from_sorted(vec![(3, ()), (7, ())]);
+        let _ = BinarySearchTree::join(low, high);
+    }
+
+    #[test]
+    fn test_bst_serialize_round_trip() {
+        let mut tree = BinarySearchTree::new();
+        for k in [50i64, 20, 80, 10, 30, 70, 90, -5, 35] {
+            tree.insert(k, format!("value {k}"));
+        }
+        let bytes = tree.serialize();
+        let copy = BinarySearchTree::<i64, String>::deserialize(&bytes).unwrap();
+
+        assert_eq!(copy.to_ascii(), tree.to_ascii());
+        assert_eq!(copy.serialize(), bytes);
+        for k in -10..100 {
+            assert_eq!(copy.search(k), tree.search(k));
+        }
+
+        // Keys inserted in order make a path far deeper than any recursion
+        // limit a decoder might impose.
+        let mut path = BinarySearchTree::new();
+        for k in 0u16..2000 {
+            path.insert(k, k % 7);
+        }
+        let bytes = path.serialize();
+        let copy = BinarySearchTree::<u16, u16>::deserialize(&bytes).unwrap();
+        assert_eq!(copy.serialize(), bytes);
+        assert_eq!(copy.minimum(), Some((&0, &0)));
+        assert_eq!(copy.maximum(), Some((&1999, &(1999 % 7))));
+
+        let empty: BinarySearchTree<u8, u8> = BinarySearchTree::new();
+        assert_eq!(empty.serialize(), vec![0]);
+        assert!(BinarySearchTree::<u8, u8>::deserialize(&[0])
+            .unwrap()
+            .root
+            .is_none());
+    }
+
+    #[test]
+    fn test_bst_deserialize_rejects_bad_input() {
+        type Tree = BinarySearchTree<u8, u8>;
+        assert_eq!(
+            Tree::deserialize(&[]).unwrap_err(),
+            DeserializeError::UnexpectedEnd
+        );
+        assert_eq!(
+            Tree::deserialize(&[1, 2, 20]).unwrap_err(),
+            DeserializeError::UnexpectedEnd
+        );
+        assert_eq!(
+            Tree::deserialize(&[0, 0]).unwrap_err(),
+            DeserializeError::TrailingBytes(1)
+        );
+        assert_eq!(
+            Tree::deserialize(&[3]).unwrap_err(),
+            DeserializeError::InvalidTag(3)
+        );
+        // Key 3 stored as the left child of key 2
+        assert_eq!(
+            Tree::deserialize(&[1, 2, 20, 1, 3, 30, 0, 0, 0]).unwrap_err(),
+            DeserializeError::Malformed("keys are out of order")
+        );
+        // Key 3 stored as the right child of key 1, inside the left subtree
+        // of key 2
+        assert_eq!(
+            Tree::deserialize(&[1, 2, 20, 1, 1, 10, 0, 1, 3, 30, 0, 0, 0]).unwrap_err(),
+            DeserializeError::Malformed("keys are out of order")
+        );
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "strictly increasing")]
+    fn test_bst_from_sorted_rejects_unsorted() {
+        let _ = BinarySearchTree::from_sorted(vec![(2, ()), (1, ())]);
+    }
+
+    #[test]
+    fn test_bst_collect_and_extend() {
+        let pairs = vec![(5, "five"), (2, "two"), (8, "eight"), (2, "deux")];
+        let mut tree: BinarySearchTree<_, _> = pairs.into_iter().collect();
+        assert_eq!(tree.search(5), Some(&"five"));
+        assert_eq!(tree.search(2), Some(&"deux"));
+        assert_eq!(tree.search(8), Some(&"eight"));
+
+        tree.extend([(1, "one"), (9, "nine")]);
+        let mut keys = Vec::new();
+        tree.inorder_walk(|k, _| keys.push(*k));
+        assert_eq!(keys, vec![1, 2, 5, 8, 9]);
+    }
+
+    #[test]
+    fn test_bst_to_ascii() {
+        let tree: BinarySearchTree<_, _> =
+            [5, 3, 8, 1, 4, 9].into_iter().map(|k| (k, ())).collect();
+        let expected = "
+        9
+    8
+5
+        4
+    3
+        1
+";
+        assert_eq!(tree.to_ascii(), expected.strip_prefix('\n').unwrap());
+        assert_eq!(BinarySearchTree::<i32, ()>::new().to_ascii(), "");
+    }
 }
@@ -0,0 +1,226 @@
+//! A flat byte format for persisting search trees
+//!
+//! The binary search tree of this chapter and the B-tree of Chapter 18 can be
+//! written to a `Vec<u8>` and read back without an external serialization
+//! library. Keys and values only need to implement [`ToBytes`] and
+//! [`FromBytes`], which are provided here for the integer types and `String`.
+//! Integers are stored little-endian at their full width, `usize` and `isize`
+//! as 64-bit values, and strings and lengths as a `u64` count followed by the
+//! bytes.
+
+use std::fmt;
+
+/// Error returned when bytes cannot be decoded into a value or tree
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DeserializeError {
+    /// The input ended in the middle of a value
+    UnexpectedEnd,
+    /// A string's bytes were not valid UTF-8
+    InvalidUtf8,
+    /// A number does not fit the type it is decoded into
+    OutOfRange,
+    /// A presence or leaf marker was neither 0 nor 1
+    InvalidTag(u8),
+    /// The decoded data does not form a valid tree
+    Malformed(&'static str),
+    /// Bytes were left over after the tree was decoded
+    TrailingBytes(usize),
+}
+
+impl fmt::Display for DeserializeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DeserializeError::UnexpectedEnd => write!(f, "unexpected end of input"),
+            DeserializeError::InvalidUtf8 => write!(f, "string is not valid UTF-8"),
+            DeserializeError::OutOfRange => write!(f, "number out of range"),
+            DeserializeError::InvalidTag(tag) => write!(f, "invalid tag byte {tag}"),
+            DeserializeError::Malformed(reason) => write!(f, "malformed tree: {reason}"),
+            DeserializeError::TrailingBytes(count) => {
+                write!(f, "{count} bytes left over after the tree")
+            }
+        }
+    }
+}
+
+impl std::error::Error for DeserializeError {}
+
+/// A value that can append its flat byte encoding to a buffer
+pub trait ToBytes {
+    /// Appends the encoding of `self` to `out`
+    fn write_bytes(&self, out: &mut Vec<u8>);
+}
+
+/// A value that can be decoded from the encoding written by [`ToBytes`]
+pub trait FromBytes: Sized {
+    /// Decodes one value from the front of `reader`
+    fn read_bytes(reader: &mut ByteReader<'_>) -> Result<Self, DeserializeError>;
+}
+
+/// A cursor over a byte slice that decodes values from its front
+///
+/// # Example
+/// ```
+/// use clrs::chapter_12::{ByteReader, FromBytes, ToBytes};
+/// let mut bytes = Vec::new();
+/// 7u16.write_bytes(&mut bytes);
+/// String::from("hi").write_bytes(&mut bytes);
+///
+/// let mut reader = ByteReader::new(&bytes);
+/// assert_eq!(u16::read_bytes(&mut reader), Ok(7));
+/// assert_eq!(String::read_bytes(&mut reader), Ok("hi".to_string()));
+/// assert_eq!(reader.finish(), Ok(()));
+/// ```
+#[derive(Debug, Clone)]
+pub struct ByteReader<'a> {
+    bytes: &'a [u8],
+}
+
+impl<'a> ByteReader<'a> {
+    /// Creates a reader positioned at the start of `bytes`
+    pub fn new(bytes: &'a [u8]) -> Self {
+        ByteReader { bytes }
+    }
+
+    /// Removes and returns the next `n` bytes
+    pub fn take(&mut self, n: usize) -> Result<&'a [u8], DeserializeError> {
+        if n > self.bytes.len() {
+            return Err(DeserializeError::UnexpectedEnd);
+        }
+        let (front, rest) = self.bytes.split_at(n);
+        self.bytes = rest;
+        Ok(front)
+    }
+
+    /// Reads a presence or leaf marker, which must be 0 or 1
+    pub fn read_flag(&mut self) -> Result<bool, DeserializeError> {
+        match self.take(1)?[0] {
+            0 => Ok(false),
+            1 => Ok(true),
+            tag => Err(DeserializeError::InvalidTag(tag)),
+        }
+    }
+
+    /// Reads a length or count written as a `u64`
+    pub fn read_len(&mut self) -> Result<usize, DeserializeError> {
+        usize::read_bytes(self)
+    }
+
+    /// Checks that every byte has been consumed
+    pub fn finish(self) -> Result<(), DeserializeError> {
+        if self.bytes.is_empty() {
+            Ok(())
+        } else {
+            Err(DeserializeError::TrailingBytes(self.bytes.len()))
+        }
+    }
+}
+
+/// Appends a marker byte, 1 for `true` and 0 for `false`
+pub(crate) fn write_flag(flag: bool, out: &mut Vec<u8>) {
+    out.push(u8::from(flag));
+}
+
+/// Appends a length or count as a `u64`
+pub(crate) fn write_len(len: usize, out: &mut Vec<u8>) {
+    len.write_bytes(out);
+}
+
+macro_rules! impl_int_bytes {
+    ($($t:ty),*) => {$(
+        impl ToBytes for $t {
+            fn write_bytes(&self, out: &mut Vec<u8>) {
+                out.extend_from_slice(&self.to_le_bytes());
+            }
+        }
+
+        impl FromBytes for $t {
+            fn read_bytes(reader: &mut ByteReader<'_>) -> Result<Self, DeserializeError> {
+                let bytes = reader.take(std::mem::size_of::<$t>())?;
+                Ok(<$t>::from_le_bytes(bytes.try_into().expect("slice has the right length")))
+            }
+        }
+    )*};
+}
+
+impl_int_bytes!(u8, u16, u32, u64, u128, i8, i16, i32, i64, i128);
+
+impl ToBytes for usize {
+    fn write_bytes(&self, out: &mut Vec<u8>) {
+        (*self as u64).write_bytes(out);
+    }
+}
+
+impl FromBytes for usize {
+    fn read_bytes(reader: &mut ByteReader<'_>) -> Result<Self, DeserializeError> {
+        usize::try_from(u64::read_bytes(reader)?).map_err(|_| DeserializeError::OutOfRange)
+    }
+}
+
+impl ToBytes for isize {
+    fn write_bytes(&self, out: &mut Vec<u8>) {
+        (*self as i64).write_bytes(out);
+    }
+}
+
+impl FromBytes for isize {
+    fn read_bytes(reader: &mut ByteReader<'_>) -> Result<Self, DeserializeError> {
+        isize::try_from(i64::read_bytes(reader)?).map_err(|_| DeserializeError::OutOfRange)
+    }
+}
+
+impl ToBytes for String {
+    fn write_bytes(&self, out: &mut Vec<u8>) {
+        write_len(self.len(), out);
+        out.extend_from_slice(self.as_bytes());
+    }
+}
+
+impl FromBytes for String {
+    fn read_bytes(reader: &mut ByteReader<'_>) -> Result<Self, DeserializeError> {
+        let len = reader.read_len()?;
+        let bytes = reader.take(len)?;
+        String::from_utf8(bytes.to_vec()).map_err(|_| DeserializeError::InvalidUtf8)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_round_trip_primitives() {
+        let mut bytes = Vec::new();
+        (-5i8).write_bytes(&mut bytes);
+        u128::MAX.write_bytes(&mut bytes);
+        (-1isize).write_bytes(&mut bytes);
+        String::from("héllo").write_bytes(&mut bytes);
+        assert_eq!(bytes.len(), 1 + 16 + 8 + 8 + 6);
+
+        let mut reader = ByteReader::new(&bytes);
+        assert_eq!(i8::read_bytes(&mut reader), Ok(-5));
+        assert_eq!(u128::read_bytes(&mut reader), Ok(u128::MAX));
+        assert_eq!(isize::read_bytes(&mut reader), Ok(-1));
+        assert_eq!(String::read_bytes(&mut reader), Ok("héllo".to_string()));
+        assert_eq!(reader.finish(), Ok(()));
+    }
+
+    #[test]
+    fn test_decoding_errors() {
+        assert_eq!(
+            u32::read_bytes(&mut ByteReader::new(&[1, 2])),
+            Err(DeserializeError::UnexpectedEnd)
+        );
+        assert_eq!(
+            String::read_bytes(&mut ByteReader::new(&[1, 0, 0, 0, 0, 0, 0, 0, 0xff])),
+            Err(DeserializeError::InvalidUtf8)
+        );
+        assert_eq!(
+            ByteReader::new(&[2]).read_flag(),
+            Err(DeserializeError::InvalidTag(2))
+        );
+        assert_eq!(
+            ByteReader::new(&[0, 0]).finish(),
+            Err(DeserializeError::TrailingBytes(2))
+        );
+    }
+}
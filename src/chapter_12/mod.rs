@@ -3,6 +3,12 @@
 //! This chapter covers binary search tree implementations including
 //! querying, insertion, deletion, and tree walks.
 
+pub mod arena_bst;
 pub mod binary_search_tree;
+pub mod flat_bytes;
+pub mod sorted_map;
 
+pub use arena_bst::*;
 pub use binary_search_tree::*;
+pub use flat_bytes::*;
+pub use sorted_map::*;
@@ -0,0 +1,118 @@
+//! A common interface for ordered maps
+//!
+//! The binary search tree of this chapter, the red-black tree of Chapter 13
+//! and the B-tree of Chapter 18 all implement the dynamic-set operations of
+//! Section 12.2 over ordered keys. [`SortedMap`] gives those operations one
+//! set of names so that generic code can run against any of them.
+
+/// An ordered key-value map supporting the basic dynamic-set operations
+///
+/// # Example
+/// ```
+/// use clrs::chapter_12::{BinarySearchTree, SortedMap};
+/// use clrs::chapter_18::BTree;
+///
+/// fn smallest_key<M: SortedMap<i32, &'static str>>(map: &mut M) -> Option<i32> {
+///     map.insert(5, "five");
+///     map.insert(2, "two");
+///     map.min().map(|(k, _)| *k)
+/// }
+///
+/// assert_eq!(smallest_key(&mut BinarySearchTree::new()), Some(2));
+/// assert_eq!(smallest_key(&mut BTree::new(2)), Some(2));
+/// ```
+pub trait SortedMap<K: Ord, V> {
+    /// Inserts `value` under `key`, returning the value it replaced, if any
+    fn insert(&mut self, key: K, value: V) -> Option<V>;
+
+    /// Returns the value stored under `key`
+    fn get(&self, key: &K) -> Option<&V>;
+
+    /// Removes `key`, returning its value if it was present
+    fn remove(&mut self, key: &K) -> Option<V>;
+
+    /// Returns the pair with the smallest key
+    fn min(&self) -> Option<(&K, &V)>;
+
+    /// Returns the pair with the largest key
+    fn max(&self) -> Option<(&K, &V)>;
+
+    /// Returns the number of keys in the map
+    fn len(&self) -> usize;
+
+    /// Returns `true` if the map holds no keys
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns `true` if `key` is present
+    fn contains_key(&self, key: &K) -> bool {
+        self.get(key).is_some()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::chapter_12::BinarySearchTree;
+    use crate::chapter_13::RedBlackTree;
+    use crate::chapter_18::BTree;
+    use rand::rngs::StdRng;
+    use rand::seq::SliceRandom;
+    use rand::SeedableRng;
+
+    fn exercise_map<M: SortedMap<i32, i32>>(m: &mut M) {
+        assert!(m.is_empty());
+        assert_eq!(m.min(), None);
+        assert_eq!(m.max(), None);
+        assert_eq!(m.remove(&1), None);
+
+        let mut keys: Vec<i32> = (0..200).collect();
+        keys.shuffle(&mut StdRng::seed_from_u64(11));
+        for &k in &keys {
+            assert_eq!(m.insert(k, k * 10), None);
+        }
+        assert_eq!(m.len(), 200);
+        assert_eq!(m.min(), Some((&0, &0)));
+        assert_eq!(m.max(), Some((&199, &1990)));
+
+        // Re-inserting replaces the value without growing the map.
+        assert_eq!(m.insert(42, -1), Some(420));
+        assert_eq!(m.get(&42), Some(&-1));
+        assert_eq!(m.len(), 200);
+
+        for k in (0..200).step_by(3) {
+            let expected = if k == 42 { -1 } else { k * 10 };
+            assert_eq!(m.remove(&k), Some(expected));
+            assert_eq!(m.remove(&k), None);
+        }
+        assert_eq!(m.len(), 200 - 67);
+        for k in 0..200 {
+            assert_eq!(m.contains_key(&k), k % 3 != 0);
+        }
+        assert_eq!(m.get(&500), None);
+        assert_eq!(m.min(), Some((&1, &10)));
+        assert_eq!(m.max(), Some((&199, &1990)));
+
+        for k in 0..200 {
+            m.remove(&k);
+        }
+        assert!(m.is_empty());
+    }
+
+    #[test]
+    fn test_binary_search_tree_sorted_map() {
+        exercise_map(&mut BinarySearchTree::new());
+    }
+
+    #[test]
+    fn test_red_black_tree_sorted_map() {
+        exercise_map(&mut RedBlackTree::new());
+    }
+
+    #[test]
+    fn test_b_tree_sorted_map() {
+        exercise_map(&mut BTree::new(2));
+        exercise_map(&mut BTree::new(4));
+    }
+}
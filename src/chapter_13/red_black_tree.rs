@@ -6,6 +6,9 @@
 //! such path is more than twice as long as any other, so the tree is approximately balanced.
 
 use std::cmp::Ordering;
+use std::fmt::{Display, Write};
+
+use crate::chapter_12::SortedMap;
 
 /// Color of a red-black tree node
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -85,15 +88,25 @@ impl<K: Ord, V> RedBlackTree<K, V> {
 
     /// Inserts a key-value pair into the tree
     ///
-    /// This corresponds to RB-INSERT from CLRS Section 13.3.
+    /// This corresponds to RB-INSERT from CLRS Section 13.3. If the key is
+    /// already present, its value is replaced.
+    ///
+    /// The nodes have no parent pointers, so RB-INSERT-FIXUP runs as the
+    /// recursion unwinds: a red child with a red child of its own is repaired
+    /// at its grandparent by recoloring (case 1) or by one or two rotations
+    /// (cases 2 and 3), and a red-red violation pushed up by case 1 is
+    /// repaired one level higher.
     ///
     /// # Arguments
     /// * `k` - The key to insert
     /// * `v` - The value to insert
     ///
+    /// # Returns
+    /// The previous value for `k`, or `None` if the key was new
+    ///
     /// # Complexity
     /// - Time: O(lg n) where n is the number of nodes
-    pub fn insert(&mut self, k: K, v: V) {
+    pub fn insert(&mut self, k: K, v: V) -> Option<V> {
         let new_node = Box::new(RBNode {
             key: k,
             value: v,
@@ -102,37 +115,308 @@ impl<K: Ord, V> RedBlackTree<K, V> {
             right: None,
         });
 
-        // Insert like a regular BST
-        if self.root.is_none() {
-            self.root = Some(new_node);
-        } else {
-            Self::insert_node(&mut self.root, new_node);
-        }
+        let previous = Self::insert_node(&mut self.root, new_node);
 
-        // Fix red-black properties
-        // Note: In a full implementation, we'd track the path and fix up
-        // For now, we'll ensure the root is black
+        // CLRS: T.root.color = BLACK
         if let Some(root) = &mut self.root {
             root.color = Color::Black;
         }
+        previous
     }
 
-    fn insert_node(node: &mut Option<Box<RBNode<K, V>>>, new_node: Box<RBNode<K, V>>) {
+    fn insert_node(node: &mut Option<Box<RBNode<K, V>>>, new_node: Box<RBNode<K, V>>) -> Option<V> {
         match node {
-            None => *node = Some(new_node),
+            None => {
+                *node = Some(new_node);
+                None
+            }
             Some(n) => {
-                match new_node.key.cmp(&n.key) {
+                let previous = match new_node.key.cmp(&n.key) {
                     Ordering::Less => Self::insert_node(&mut n.left, new_node),
                     Ordering::Greater => Self::insert_node(&mut n.right, new_node),
                     Ordering::Equal => {
                         // Key already exists, update value
-                        n.value = new_node.value;
+                        return Some(std::mem::replace(&mut n.value, new_node.value));
                     }
+                };
+                Self::insert_fixup(n);
+                previous
+            }
+        }
+    }
+
+    /// Repairs a red child of `z` that has a red child of its own, with `z`
+    /// playing the grandparent z.p.p of RB-INSERT-FIXUP.
+    fn insert_fixup(z: &mut Box<RBNode<K, V>>) {
+        let red_red = |child: &Option<Box<RBNode<K, V>>>| match child {
+            Some(c) => c.color == Color::Red && (is_red(&c.left) || is_red(&c.right)),
+            None => false,
+        };
+
+        if red_red(&z.left) {
+            if is_red(&z.right) {
+                // Case 1: the uncle is red, so recolor and move up
+                Self::push_blackness_down(z);
+            } else {
+                let parent = z.left.as_mut().expect("checked above");
+                if is_red(&parent.right) {
+                    // Case 2: turn the inner grandchild into an outer one
+                    Self::left_rotate(parent);
+                }
+                // Case 3
+                Self::right_rotate(z);
+                z.color = Color::Black;
+                set_color(&mut z.right, Color::Red);
+            }
+        } else if red_red(&z.right) {
+            if is_red(&z.left) {
+                Self::push_blackness_down(z);
+            } else {
+                let parent = z.right.as_mut().expect("checked above");
+                if is_red(&parent.left) {
+                    Self::right_rotate(parent);
                 }
+                Self::left_rotate(z);
+                z.color = Color::Black;
+                set_color(&mut z.left, Color::Red);
+            }
+        }
+    }
+
+    /// Colors `z` red and both its children black.
+    fn push_blackness_down(z: &mut Box<RBNode<K, V>>) {
+        z.color = Color::Red;
+        set_color(&mut z.left, Color::Black);
+        set_color(&mut z.right, Color::Black);
+    }
+
+    /// Deletes a key from the tree
+    ///
+    /// This corresponds to RB-DELETE from CLRS Section 13.4. A node with two
+    /// children takes the key and value of its successor, which keeps the
+    /// node's color, and the successor is removed instead, so the node that
+    /// actually leaves the tree has at most one child. Removing a black node
+    /// leaves its subtree one black node short; as the recursion unwinds,
+    /// RB-DELETE-FIXUP either absorbs the missing black into a red node or
+    /// restores it with the four sibling cases, passing the deficit up only
+    /// from case 2 with a black parent.
+    ///
+    /// # Arguments
+    /// * `k` - The key to delete
+    ///
+    /// # Returns
+    /// The deleted value if found, `None` otherwise
+    ///
+    /// # Complexity
+    /// - Time: O(lg n) where n is the number of nodes
+    pub fn delete(&mut self, k: &K) -> Option<V> {
+        let (value, _) = Self::delete_node(&mut self.root, k);
+        if let Some(root) = &mut self.root {
+            root.color = Color::Black;
+        }
+        value
+    }
+
+    /// Deletes `k` from the subtree at `node`, returning the value and
+    /// whether the subtree's black-height dropped by one.
+    fn delete_node(node: &mut Option<Box<RBNode<K, V>>>, k: &K) -> (Option<V>, bool) {
+        let Some(n) = node.as_mut() else {
+            return (None, false);
+        };
+        match k.cmp(&n.key) {
+            Ordering::Less => {
+                let (value, shortened) = Self::delete_node(&mut n.left, k);
+                (value, shortened && Self::delete_fixup_left(n))
+            }
+            Ordering::Greater => {
+                let (value, shortened) = Self::delete_node(&mut n.right, k);
+                (value, shortened && Self::delete_fixup_right(n))
+            }
+            Ordering::Equal if n.left.is_some() && n.right.is_some() => {
+                let (key, value, shortened) = Self::extract_minimum(&mut n.right);
+                n.key = key;
+                let value = std::mem::replace(&mut n.value, value);
+                (Some(value), shortened && Self::delete_fixup_right(n))
+            }
+            Ordering::Equal => {
+                let (_, value, shortened) = Self::splice_out(node);
+                (Some(value), shortened)
             }
         }
     }
 
+    /// Removes the minimum of a nonempty subtree, returning its key, its
+    /// value and whether the subtree's black-height dropped by one.
+    fn extract_minimum(node: &mut Option<Box<RBNode<K, V>>>) -> (K, V, bool) {
+        let n = node.as_mut().expect("extract_minimum called on None");
+        if n.left.is_some() {
+            let (key, value, shortened) = Self::extract_minimum(&mut n.left);
+            return (key, value, shortened && Self::delete_fixup_left(n));
+        }
+        Self::splice_out(node)
+    }
+
+    /// Replaces a node that has at most one child by that child.
+    ///
+    /// The only child of such a node must be red, so removing a black node
+    /// either blackens that child or, without one, shortens the subtree.
+    fn splice_out(node: &mut Option<Box<RBNode<K, V>>>) -> (K, V, bool) {
+        let mut n = node.take().expect("splice_out called on None");
+        debug_assert!(n.left.is_none() || n.right.is_none());
+        *node = n.left.take().or(n.right.take());
+        let shortened = match node {
+            _ if n.color == Color::Red => false,
+            Some(child) => {
+                child.color = Color::Black;
+                false
+            }
+            None => true,
+        };
+        (n.key, n.value, shortened)
+    }
+
+    /// Restores the black-heights below `x_parent` after its left subtree
+    /// lost one black node, and reports whether `x_parent`'s whole subtree
+    /// is now one black node short.
+    ///
+    /// This is one iteration of the RB-DELETE-FIXUP loop with x the left
+    /// child of `x_parent`.
+    fn delete_fixup_left(x_parent: &mut Box<RBNode<K, V>>) -> bool {
+        if is_red(&x_parent.left) {
+            // CLRS: x.color = BLACK
+            set_color(&mut x_parent.left, Color::Black);
+            return false;
+        }
+
+        let w = x_parent
+            .right
+            .as_mut()
+            .expect("a short subtree has a sibling");
+        if w.color == Color::Red {
+            // Case 1: make the sibling black by rotating the red one up
+            w.color = Color::Black;
+            x_parent.color = Color::Red;
+            Self::left_rotate(x_parent);
+            let below = x_parent.left.as_mut().expect("rotated down");
+            let shortened = Self::delete_fixup_left(below);
+            debug_assert!(!shortened, "a red parent absorbs the deficit");
+            return false;
+        }
+
+        if !is_red(&w.left) && !is_red(&w.right) {
+            // Case 2: take one black off both sides and move up
+            w.color = Color::Red;
+            if x_parent.color == Color::Red {
+                x_parent.color = Color::Black;
+                return false;
+            }
+            return true;
+        }
+
+        if !is_red(&w.right) {
+            // Case 3: make the sibling's far child red
+            set_color(&mut w.left, Color::Black);
+            w.color = Color::Red;
+            Self::right_rotate(w);
+        }
+
+        // Case 4
+        let parent_color = x_parent.color;
+        Self::left_rotate(x_parent);
+        x_parent.color = parent_color;
+        set_color(&mut x_parent.left, Color::Black);
+        set_color(&mut x_parent.right, Color::Black);
+        false
+    }
+
+    /// Mirror image of [`delete_fixup_left`](Self::delete_fixup_left) for a
+    /// right subtree that lost one black node.
+    fn delete_fixup_right(x_parent: &mut Box<RBNode<K, V>>) -> bool {
+        if is_red(&x_parent.right) {
+            set_color(&mut x_parent.right, Color::Black);
+            return false;
+        }
+
+        let w = x_parent
+            .left
+            .as_mut()
+            .expect("a short subtree has a sibling");
+        if w.color == Color::Red {
+            w.color = Color::Black;
+            x_parent.color = Color::Red;
+            Self::right_rotate(x_parent);
+            let below = x_parent.right.as_mut().expect("rotated down");
+            let shortened = Self::delete_fixup_right(below);
+            debug_assert!(!shortened, "a red parent absorbs the deficit");
+            return false;
+        }
+
+        if !is_red(&w.left) && !is_red(&w.right) {
+            w.color = Color::Red;
+            if x_parent.color == Color::Red {
+                x_parent.color = Color::Black;
+                return false;
+            }
+            return true;
+        }
+
+        if !is_red(&w.left) {
+            set_color(&mut w.right, Color::Black);
+            w.color = Color::Red;
+            Self::left_rotate(w);
+        }
+
+        let parent_color = x_parent.color;
+        Self::right_rotate(x_parent);
+        x_parent.color = parent_color;
+        set_color(&mut x_parent.left, Color::Black);
+        set_color(&mut x_parent.right, Color::Black);
+        false
+    }
+
+    /// Checks the binary-search-tree order and the red-black properties
+    ///
+    /// Verifies that keys increase in order, that the root is black, that no
+    /// red node has a red child, and that every path from a node down to a
+    /// missing child passes the same number of black nodes.
+    ///
+    /// # Returns
+    /// The black-height of the tree, counting the black nodes on any path
+    /// from the root down to a missing child (0 for an empty tree), or a
+    /// description of the first violated property
+    ///
+    /// # Complexity
+    /// - Time: O(n) where n is the number of nodes
+    pub fn check_properties(&self) -> Result<usize, &'static str> {
+        if is_red(&self.root) {
+            return Err("root is red");
+        }
+        let mut previous = None;
+        Self::check_node(&self.root, &mut previous)
+    }
+
+    fn check_node<'a>(
+        node: &'a Option<Box<RBNode<K, V>>>,
+        previous: &mut Option<&'a K>,
+    ) -> Result<usize, &'static str> {
+        let Some(n) = node else {
+            return Ok(0);
+        };
+        if n.color == Color::Red && (is_red(&n.left) || is_red(&n.right)) {
+            return Err("red node has a red child");
+        }
+        let left_height = Self::check_node(&n.left, previous)?;
+        if previous.is_some_and(|key| *key >= n.key) {
+            return Err("keys are out of order");
+        }
+        *previous = Some(&n.key);
+        let right_height = Self::check_node(&n.right, previous)?;
+        if left_height != right_height {
+            return Err("black-heights differ");
+        }
+        Ok(left_height + usize::from(n.color == Color::Black))
+    }
+
     /// Performs a left rotation around the given node.
     ///
     /// This is the `LEFT-ROTATE` procedure from CLRS Section 13.2. The rotation
@@ -247,6 +531,110 @@ impl<K: Ord, V> RedBlackTree<K, V> {
             self.inorder_walk_node(&n.right, visitor);
         }
     }
+
+    /// Returns an iterator over the key-value pairs in ascending key order
+    ///
+    /// The iterator performs an in-order traversal with an explicit stack,
+    /// so it holds at most O(h) node references at a time.
+    ///
+    /// # Example
+    /// ```
+    /// use clrs::chapter_13::RedBlackTree;
+    /// let mut tree = RedBlackTree::new();
+    /// tree.insert(2, "b");
+    /// tree.insert(1, "a");
+    /// let pairs: Vec<_> = tree.iter().collect();
+    /// assert_eq!(pairs, vec![(&1, &"a"), (&2, &"b")]);
+    /// ```
+    pub fn iter(&self) -> impl Iterator<Item = (&K, &V)> {
+        let mut iter = InorderIter { stack: Vec::new() };
+        iter.push_left(&self.root);
+        iter
+    }
+
+    /// Returns the number of keys stored in the tree
+    ///
+    /// # Complexity
+    /// - Time: O(n) where n is the number of nodes
+    pub fn len(&self) -> usize {
+        self.iter().count()
+    }
+
+    /// Returns `true` if the tree contains no keys
+    pub fn is_empty(&self) -> bool {
+        self.root.is_none()
+    }
+}
+
+/// Returns whether `node` is a red node; missing children count as black.
+fn is_red<K: Ord, V>(node: &Option<Box<RBNode<K, V>>>) -> bool {
+    node.as_ref().is_some_and(|n| n.color == Color::Red)
+}
+
+fn set_color<K: Ord, V>(node: &mut Option<Box<RBNode<K, V>>>, color: Color) {
+    if let Some(n) = node {
+        n.color = color;
+    }
+}
+
+/// In-order iterator over a red-black tree using an explicit stack
+struct InorderIter<'a, K: Ord, V> {
+    stack: Vec<&'a RBNode<K, V>>,
+}
+
+impl<'a, K: Ord, V> InorderIter<'a, K, V> {
+    fn push_left(&mut self, mut node: &'a Option<Box<RBNode<K, V>>>) {
+        while let Some(n) = node {
+            self.stack.push(n);
+            node = &n.left;
+        }
+    }
+}
+
+impl<'a, K: Ord, V> Iterator for InorderIter<'a, K, V> {
+    type Item = (&'a K, &'a V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let node = self.stack.pop()?;
+        self.push_left(&node.right);
+        Some((&node.key, &node.value))
+    }
+}
+
+impl<K: Ord + Display, V> RedBlackTree<K, V> {
+    /// Draws the tree sideways, one node per line
+    ///
+    /// Each key is followed by its color, `R` or `B`. The layout matches
+    /// [`BinarySearchTree::to_ascii`](crate::chapter_12::BinarySearchTree::to_ascii):
+    /// the root is at the left margin, each level is indented four more
+    /// spaces, and right subtrees are drawn above their parents.
+    ///
+    /// # Example
+    /// ```
+    /// use clrs::chapter_13::RedBlackTree;
+    /// let mut tree = RedBlackTree::new();
+    /// for key in [1, 2, 3] {
+    ///     tree.insert(key, ());
+    /// }
+    /// assert_eq!(tree.to_ascii(), "    3 R\n2 B\n    1 R\n");
+    /// ```
+    pub fn to_ascii(&self) -> String {
+        let mut out = String::new();
+        Self::draw_node(&self.root, 0, &mut out);
+        out
+    }
+
+    fn draw_node(node: &Option<Box<RBNode<K, V>>>, depth: usize, out: &mut String) {
+        if let Some(n) = node {
+            Self::draw_node(&n.right, depth + 1, out);
+            let color = match n.color {
+                Color::Red => 'R',
+                Color::Black => 'B',
+            };
+            writeln!(out, "{:indent$}{} {color}", "", n.key, indent = 4 * depth).unwrap();
+            Self::draw_node(&n.left, depth + 1, out);
+        }
+    }
 }
 
 impl<K: Ord, V> Default for RedBlackTree<K, V> {
@@ -255,9 +643,58 @@ impl<K: Ord, V> Default for RedBlackTree<K, V> {
     }
 }
 
+impl<K: Ord, V> Extend<(K, V)> for RedBlackTree<K, V> {
+    /// Inserts every pair with [`insert`](RedBlackTree::insert), keeping it balanced; a
+    /// repeated key keeps the last value.
+    fn extend<I: IntoIterator<Item = (K, V)>>(&mut self, iter: I) {
+        for (key, value) in iter {
+            self.insert(key, value);
+        }
+    }
+}
+
+impl<K: Ord, V> FromIterator<(K, V)> for RedBlackTree<K, V> {
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        let mut tree = Self::new();
+        tree.extend(iter);
+        tree
+    }
+}
+
+impl<K: Ord, V> SortedMap<K, V> for RedBlackTree<K, V> {
+    fn insert(&mut self, key: K, value: V) -> Option<V> {
+        RedBlackTree::insert(self, key, value)
+    }
+
+    fn get(&self, key: &K) -> Option<&V> {
+        self.search_node(&self.root, key)
+    }
+
+    fn remove(&mut self, key: &K) -> Option<V> {
+        self.delete(key)
+    }
+
+    fn min(&self) -> Option<(&K, &V)> {
+        self.minimum()
+    }
+
+    fn max(&self) -> Option<(&K, &V)> {
+        self.maximum()
+    }
+
+    fn len(&self) -> usize {
+        RedBlackTree::len(self)
+    }
+
+    fn is_empty(&self) -> bool {
+        RedBlackTree::is_empty(self)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use rand::seq::SliceRandom;
 
     #[test]
     fn test_rb_tree_new() {
@@ -331,4 +768,121 @@ mod tests {
         // Verify tree is valid (root is black)
         assert_eq!(tree.root.as_ref().map(|n| n.color), Some(Color::Black));
     }
+
+    #[test]
+    fn test_rb_tree_iter_and_len() {
+        let mut keys: Vec<i32> = (0..100).collect();
+        keys.shuffle(&mut rand::thread_rng());
+
+        let mut tree = RedBlackTree::new();
+        assert_eq!(tree.len(), 0);
+        assert!(tree.is_empty());
+        for &key in &keys {
+            tree.insert(key, key * 2);
+        }
+
+        let pairs: Vec<(i32, i32)> = tree.iter().map(|(k, v)| (*k, *v)).collect();
+        let expected: Vec<(i32, i32)> = (0..100).map(|k| (k, k * 2)).collect();
+        assert_eq!(pairs, expected);
+        assert_eq!(tree.len(), 100);
+        assert!(!tree.is_empty());
+
+        // Re-inserting an existing key does not change the count
+        tree.insert(42, 0);
+        assert_eq!(tree.len(), 100);
+    }
+
+    #[test]
+    fn test_rb_tree_ascending_inserts_stay_balanced() {
+        let mut tree = RedBlackTree::new();
+        for key in 0..1024 {
+            tree.insert(key, ());
+        }
+        let black_height = tree.check_properties().expect("valid red-black tree");
+        // A red-black tree with n nodes has black-height at most lg(n + 1)
+        assert!(black_height <= 10);
+    }
+
+    #[test]
+    fn test_rb_tree_check_properties_detects_violations() {
+        let mut tree = RedBlackTree::new();
+        assert_eq!(tree.check_properties(), Ok(0));
+        for key in [2, 1, 3] {
+            tree.insert(key, ());
+        }
+        assert_eq!(tree.check_properties(), Ok(1));
+
+        tree.root.as_mut().unwrap().color = Color::Red;
+        assert_eq!(tree.check_properties(), Err("root is red"));
+        tree.root.as_mut().unwrap().color = Color::Black;
+
+        tree.root.as_mut().unwrap().left.as_mut().unwrap().color = Color::Black;
+        assert_eq!(tree.check_properties(), Err("black-heights differ"));
+
+        tree.root.as_mut().unwrap().left.as_mut().unwrap().key = 4;
+        assert_eq!(tree.check_properties(), Err("keys are out of order"));
+    }
+
+    #[test]
+    fn test_rb_tree_delete_torture() {
+        use rand::rngs::StdRng;
+        use rand::SeedableRng;
+
+        let mut rng = StdRng::seed_from_u64(422);
+        let mut keys: Vec<u32> = (0..2000).collect();
+        keys.shuffle(&mut rng);
+
+        let mut tree = RedBlackTree::new();
+        for &key in &keys {
+            assert_eq!(tree.insert(key, key * 3), None);
+            assert!(tree.check_properties().is_ok());
+        }
+        assert_eq!(tree.len(), 2000);
+
+        keys.shuffle(&mut rng);
+        for (i, &key) in keys.iter().enumerate() {
+            assert_eq!(tree.delete(&key), Some(key * 3));
+            assert_eq!(tree.delete(&key), None);
+            if let Err(violation) = tree.check_properties() {
+                panic!("{violation} after deleting {key}");
+            }
+            assert_eq!(tree.search(key), None);
+            for &kept in &keys[i + 1..(i + 6).min(keys.len())] {
+                assert_eq!(tree.search(kept), Some(&(kept * 3)));
+            }
+        }
+        assert!(tree.is_empty());
+    }
+
+    #[test]
+    fn test_rb_tree_collect_and_extend() {
+        let pairs: Vec<(i32, i32)> = (0..500).map(|k| (k, -k)).collect();
+        let mut tree: RedBlackTree<_, _> = pairs.into_iter().collect();
+        assert!(tree.check_properties().is_ok());
+        assert_eq!(tree.len(), 500);
+        assert!((0..500).all(|k| tree.search(k) == Some(&-k)));
+
+        tree.extend((500..1000).rev().map(|k| (k, -k)));
+        assert!(tree.check_properties().is_ok());
+        assert_eq!(tree.len(), 1000);
+        assert!((0..1000).all(|k| tree.search(k) == Some(&-k)));
+    }
+
+    #[test]
+    fn test_rb_tree_to_ascii() {
+        // CLRS Exercise 13.3-2: inserting 41, 38, 31, 12, 19, 8
+        let tree: RedBlackTree<_, _> = [41, 38, 31, 12, 19, 8]
+            .into_iter()
+            .map(|k| (k, ()))
+            .collect();
+        let expected = "
+    41 B
+38 B
+        31 B
+    19 R
+        12 B
+            8 R
+";
+        assert_eq!(tree.to_ascii(), expected.strip_prefix('\n').unwrap());
+    }
 }
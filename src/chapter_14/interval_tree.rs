@@ -4,15 +4,19 @@
 //! each with an associated value. It supports efficient interval queries.
 
 use std::cmp::Ordering;
+use std::ops::Sub;
 
 /// An interval with low and high endpoints
+///
+/// Endpoints may be any totally ordered `Copy` type, e.g. `i32` offsets or
+/// `i64` timestamps.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub struct Interval {
-    pub low: i32,
-    pub high: i32,
+pub struct Interval<T = i32> {
+    pub low: T,
+    pub high: T,
 }
 
-impl Interval {
+impl<T: Ord + Copy> Interval<T> {
     /// Creates a new interval
     ///
     /// # Arguments
@@ -21,7 +25,7 @@ impl Interval {
     ///
     /// # Panics
     /// Panics if `low > high`
-    pub fn new(low: i32, high: i32) -> Self {
+    pub fn new(low: T, high: T) -> Self {
         assert!(low <= high, "low must be <= high");
         Interval { low, high }
     }
@@ -29,24 +33,54 @@ impl Interval {
     /// Checks if this interval overlaps with another interval
     ///
     /// Two intervals overlap if they have any point in common.
-    pub fn overlaps(&self, other: &Interval) -> bool {
+    pub fn overlaps(&self, other: &Interval<T>) -> bool {
         self.low <= other.high && other.low <= self.high
     }
 
     /// Checks if this interval exactly matches another interval
-    pub fn exactly_matches(&self, other: &Interval) -> bool {
+    pub fn exactly_matches(&self, other: &Interval<T>) -> bool {
         self.low == other.low && self.high == other.high
     }
+
+    /// Returns the intersection of this interval with another interval
+    ///
+    /// Intervals that only touch at an endpoint intersect in a degenerate
+    /// zero-length interval.
+    ///
+    /// # Returns
+    /// The overlapping subinterval, or `None` if the intervals are disjoint
+    pub fn intersection(&self, other: &Interval<T>) -> Option<Interval<T>> {
+        if self.overlaps(other) {
+            Some(Interval::new(
+                self.low.max(other.low),
+                self.high.min(other.high),
+            ))
+        } else {
+            None
+        }
+    }
+
+    /// Checks if the point `p` lies within this closed interval
+    pub fn contains_point(&self, p: T) -> bool {
+        self.low <= p && p <= self.high
+    }
 }
 
-impl PartialOrd for Interval {
-    fn partial_cmp(&self, other: &Interval) -> Option<Ordering> {
-        self.low.partial_cmp(&other.low)
+impl<T: Ord + Copy + Sub<Output = T>> Interval<T> {
+    /// Returns the length `high - low` of this interval
+    pub fn length(&self) -> T {
+        self.high - self.low
     }
 }
 
-impl Ord for Interval {
-    fn cmp(&self, other: &Interval) -> Ordering {
+impl<T: Ord + Copy> PartialOrd for Interval<T> {
+    fn partial_cmp(&self, other: &Interval<T>) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T: Ord + Copy> Ord for Interval<T> {
+    fn cmp(&self, other: &Interval<T>) -> Ordering {
         self.low.cmp(&other.low)
     }
 }
@@ -55,12 +89,12 @@ impl Ord for Interval {
 ///
 /// This is a red-black tree node augmented with interval and max information.
 #[derive(Debug, Clone)]
-pub struct IntervalNode<V> {
-    pub interval: Interval,
+pub struct IntervalNode<V, T = i32> {
+    pub interval: Interval<T>,
     pub value: V,
-    pub max: i32, // Maximum high endpoint in subtree rooted at this node
-    pub left: Option<Box<IntervalNode<V>>>,
-    pub right: Option<Box<IntervalNode<V>>>,
+    pub max: T, // Maximum high endpoint in subtree rooted at this node
+    pub left: Option<Box<IntervalNode<V, T>>>,
+    pub right: Option<Box<IntervalNode<V, T>>>,
 }
 
 /// Interval tree
@@ -77,11 +111,11 @@ pub struct IntervalNode<V> {
 /// assert!(tree.search(Interval::new(4, 6)).is_some());
 /// ```
 #[derive(Debug, Clone)]
-pub struct IntervalTree<V> {
-    pub root: Option<Box<IntervalNode<V>>>,
+pub struct IntervalTree<V, T = i32> {
+    pub root: Option<Box<IntervalNode<V, T>>>,
 }
 
-impl<V> IntervalTree<V> {
+impl<V, T: Ord + Copy> IntervalTree<V, T> {
     /// Creates a new empty interval tree
     ///
     /// # Example
@@ -105,11 +139,11 @@ impl<V> IntervalTree<V> {
     ///
     /// # Complexity
     /// - Time: O(lg n) where n is the number of intervals
-    pub fn search(&self, i: Interval) -> Option<&V> {
+    pub fn search(&self, i: Interval<T>) -> Option<&V> {
         Self::search_node(&self.root, i)
     }
 
-    fn search_node(node: &Option<Box<IntervalNode<V>>>, i: Interval) -> Option<&V> {
+    fn search_node(node: &Option<Box<IntervalNode<V, T>>>, i: Interval<T>) -> Option<&V> {
         match node {
             None => None,
             Some(n) => {
@@ -138,11 +172,11 @@ impl<V> IntervalTree<V> {
     ///
     /// # Complexity
     /// - Time: O(lg n) where n is the number of intervals
-    pub fn search_exactly(&self, i: Interval) -> Option<&V> {
+    pub fn search_exactly(&self, i: Interval<T>) -> Option<&V> {
         Self::search_exactly_node(&self.root, i)
     }
 
-    fn search_exactly_node(node: &Option<Box<IntervalNode<V>>>, i: Interval) -> Option<&V> {
+    fn search_exactly_node(node: &Option<Box<IntervalNode<V, T>>>, i: Interval<T>) -> Option<&V> {
         match node {
             None => None,
             Some(n) => {
@@ -163,6 +197,49 @@ impl<V> IntervalTree<V> {
         }
     }
 
+    /// Counts the stored intervals that contain `point`
+    ///
+    /// A subtree whose `max` is below `point` holds no interval reaching it
+    /// and is skipped, as is the right subtree of a node whose low endpoint
+    /// is above `point`, since every interval there starts even later.
+    ///
+    /// # Arguments
+    /// * `point` - The point to stab the intervals with
+    ///
+    /// # Returns
+    /// The number of intervals `[low, high]` with `low <= point <= high`
+    ///
+    /// # Complexity
+    /// - Time: O(n) worst case; subtrees that cannot contain `point` are not visited
+    ///
+    /// # Example
+    /// ```
+    /// use clrs::chapter_14::{Interval, IntervalTree};
+    /// let mut tree = IntervalTree::new();
+    /// tree.insert(Interval::new(1, 5), "a");
+    /// tree.insert(Interval::new(3, 7), "b");
+    /// assert_eq!(tree.count_stabbing(4), 2);
+    /// assert_eq!(tree.count_stabbing(6), 1);
+    /// assert_eq!(tree.count_stabbing(8), 0);
+    /// ```
+    pub fn count_stabbing(&self, point: T) -> usize {
+        Self::count_stabbing_node(&self.root, point)
+    }
+
+    fn count_stabbing_node(node: &Option<Box<IntervalNode<V, T>>>, point: T) -> usize {
+        match node {
+            Some(n) if n.max >= point => {
+                let mut count = Self::count_stabbing_node(&n.left, point);
+                if n.interval.low <= point {
+                    count += usize::from(n.interval.contains_point(point));
+                    count += Self::count_stabbing_node(&n.right, point);
+                }
+                count
+            }
+            _ => 0,
+        }
+    }
+
     /// Inserts an interval-value pair into the tree
     ///
     /// This augments TREE-INSERT with max maintenance.
@@ -173,7 +250,7 @@ impl<V> IntervalTree<V> {
     ///
     /// # Complexity
     /// - Time: O(lg n) where n is the number of intervals
-    pub fn insert(&mut self, interval: Interval, value: V) {
+    pub fn insert(&mut self, interval: Interval<T>, value: V) {
         let new_node = Box::new(IntervalNode {
             interval,
             value,
@@ -190,7 +267,7 @@ impl<V> IntervalTree<V> {
         }
     }
 
-    fn insert_node(node: &mut Option<Box<IntervalNode<V>>>, new_node: Box<IntervalNode<V>>) {
+    fn insert_node(node: &mut Option<Box<IntervalNode<V, T>>>, new_node: Box<IntervalNode<V, T>>) {
         match node {
             None => *node = Some(new_node),
             Some(n) => {
@@ -216,16 +293,21 @@ impl<V> IntervalTree<V> {
     /// Updates the max attribute of a node
     ///
     /// This corresponds to maintaining the max attribute during rotations and insertions.
-    fn update_max(node: &mut Option<Box<IntervalNode<V>>>) {
+    fn update_max(node: &mut Option<Box<IntervalNode<V, T>>>) {
         if let Some(n) = node {
-            let left_max = n.left.as_ref().map(|l| l.max).unwrap_or(i32::MIN);
-            let right_max = n.right.as_ref().map(|r| r.max).unwrap_or(i32::MIN);
-            n.max = n.interval.high.max(left_max).max(right_max);
+            let mut max = n.interval.high;
+            if let Some(l) = &n.left {
+                max = max.max(l.max);
+            }
+            if let Some(r) = &n.right {
+                max = max.max(r.max);
+            }
+            n.max = max;
         }
     }
 }
 
-impl<V> Default for IntervalTree<V> {
+impl<V, T: Ord + Copy> Default for IntervalTree<V, T> {
     fn default() -> Self {
         Self::new()
     }
@@ -253,6 +335,48 @@ mod tests {
         assert!(i2.overlaps(&i3));
     }
 
+    #[test]
+    fn test_interval_intersection_disjoint() {
+        let i1 = Interval::new(1, 3);
+        let i2 = Interval::new(5, 8);
+
+        assert_eq!(i1.intersection(&i2), None);
+        assert_eq!(i2.intersection(&i1), None);
+    }
+
+    #[test]
+    fn test_interval_intersection_nested() {
+        let outer = Interval::new(1, 10);
+        let inner = Interval::new(3, 6);
+
+        assert_eq!(outer.intersection(&inner), Some(inner));
+        assert_eq!(inner.intersection(&outer), Some(inner));
+    }
+
+    #[test]
+    fn test_interval_intersection_partial_and_touching() {
+        let i1 = Interval::new(1, 5);
+        let i2 = Interval::new(3, 7);
+        assert_eq!(i1.intersection(&i2), Some(Interval::new(3, 5)));
+
+        let i3 = Interval::new(5, 9);
+        let touching = i1.intersection(&i3).unwrap();
+        assert_eq!(touching, Interval::new(5, 5));
+        assert_eq!(touching.length(), 0);
+    }
+
+    #[test]
+    fn test_interval_contains_point_and_length() {
+        let i = Interval::new(2, 6);
+
+        assert!(i.contains_point(2));
+        assert!(i.contains_point(4));
+        assert!(i.contains_point(6));
+        assert!(!i.contains_point(1));
+        assert!(!i.contains_point(7));
+        assert_eq!(i.length(), 4);
+    }
+
     #[test]
     fn test_interval_tree_new() {
         let tree: IntervalTree<&str> = IntervalTree::new();
@@ -297,4 +421,80 @@ mod tests {
             assert_eq!(root.max, 10);
         }
     }
+
+    #[test]
+    fn test_interval_tree_count_stabbing() {
+        let intervals = [(1, 5), (3, 7), (4, 4), (2, 9), (12, 15), (14, 20), (13, 16)];
+        let mut tree = IntervalTree::new();
+        for (i, &(low, high)) in intervals.iter().enumerate() {
+            tree.insert(Interval::new(low, high), i);
+        }
+
+        for point in -1..22 {
+            let expected = intervals
+                .iter()
+                .filter(|&&(low, high)| low <= point && point <= high)
+                .count();
+            assert_eq!(tree.count_stabbing(point), expected, "point {point}");
+        }
+        assert_eq!(tree.count_stabbing(4), 4);
+        // The gap between 9 and 12 is stabbed by nothing.
+        assert_eq!(tree.count_stabbing(10), 0);
+        assert_eq!(tree.count_stabbing(11), 0);
+        assert_eq!(tree.count_stabbing(14), 3);
+        assert_eq!(IntervalTree::<()>::new().count_stabbing(0), 0);
+    }
+
+    #[test]
+    fn test_interval_tree_i64_timestamps() {
+        let base: i64 = 1_700_000_000_000;
+        let mut tree: IntervalTree<&str, i64> = IntervalTree::new();
+        tree.insert(Interval::new(base, base + 3_600_000), "meeting");
+        tree.insert(Interval::new(base + 7_200_000, base + 9_000_000), "lunch");
+        tree.insert(Interval::new(base - 86_400_000, base - 1), "yesterday");
+
+        assert_eq!(
+            tree.search(Interval::new(base + 8_000_000, base + 8_000_001)),
+            Some(&"lunch")
+        );
+        assert_eq!(
+            tree.search(Interval::new(base + 3_600_001, base + 7_199_999)),
+            None
+        );
+        assert_eq!(tree.root.as_ref().unwrap().max, base + 9_000_000);
+        assert_eq!(Interval::new(base, base + 60_000).length(), 60_000);
+    }
+
+    /// Minimal totally ordered `f64` wrapper for testing non-integer endpoints
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    struct OrderedF64(f64);
+
+    impl Eq for OrderedF64 {}
+
+    impl PartialOrd for OrderedF64 {
+        fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+            Some(self.cmp(other))
+        }
+    }
+
+    impl Ord for OrderedF64 {
+        fn cmp(&self, other: &Self) -> Ordering {
+            self.0.total_cmp(&other.0)
+        }
+    }
+
+    #[test]
+    fn test_interval_tree_ordered_float() {
+        let iv = |lo: f64, hi: f64| Interval::new(OrderedF64(lo), OrderedF64(hi));
+        let mut tree = IntervalTree::new();
+        tree.insert(iv(0.5, 1.5), "a");
+        tree.insert(iv(2.25, 3.75), "b");
+        tree.insert(iv(-1.0, 0.25), "c");
+
+        assert_eq!(tree.search(iv(3.5, 4.0)), Some(&"b"));
+        assert_eq!(tree.search(iv(0.0, 0.1)), Some(&"c"));
+        assert_eq!(tree.search(iv(1.75, 2.0)), None);
+        assert_eq!(tree.search_exactly(iv(0.5, 1.5)), Some(&"a"));
+        assert_eq!(tree.root.as_ref().unwrap().max, OrderedF64(3.75));
+    }
 }
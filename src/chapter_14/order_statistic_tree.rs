@@ -82,6 +82,55 @@ impl<K: Ord, V> OrderStatisticTree<K, V> {
         }
     }
 
+    /// Returns the elements whose ranks lie in `[rank_lo, rank_hi]`, in key order
+    ///
+    /// The size fields tell which subtrees hold ranks inside the range, so
+    /// subtrees entirely below or above it are skipped without being visited.
+    ///
+    /// # Arguments
+    /// * `rank_lo` - The smallest rank to include (1-indexed)
+    /// * `rank_hi` - The largest rank to include (1-indexed); ranks above the
+    ///   tree size are ignored
+    ///
+    /// # Returns
+    /// The key-value pairs with those ranks, empty if the range selects none
+    ///
+    /// # Complexity
+    /// - Time: O(lg n + m) where m is the number of elements returned
+    pub fn select_range(&self, rank_lo: usize, rank_hi: usize) -> Vec<(&K, &V)> {
+        let mut result = Vec::new();
+        let rank_lo = rank_lo.max(1);
+        let rank_hi = rank_hi.min(self.size());
+        if rank_lo <= rank_hi {
+            result.reserve(rank_hi - rank_lo + 1);
+            Self::select_range_node(&self.root, rank_lo, rank_hi, &mut result);
+        }
+        result
+    }
+
+    /// Collects the nodes of `node`'s subtree whose ranks within that
+    /// subtree lie in `[lo, hi]`.
+    fn select_range_node<'a>(
+        node: &'a Option<Box<OSTNode<K, V>>>,
+        lo: usize,
+        hi: usize,
+        result: &mut Vec<(&'a K, &'a V)>,
+    ) {
+        let Some(n) = node else {
+            return;
+        };
+        let r = n.left_size() + 1;
+        if lo < r {
+            Self::select_range_node(&n.left, lo, hi.min(r - 1), result);
+        }
+        if lo <= r && r <= hi {
+            result.push((&n.key, &n.value));
+        }
+        if hi > r {
+            Self::select_range_node(&n.right, lo.saturating_sub(r).max(1), hi - r, result);
+        }
+    }
+
     /// Determines the rank of an element with key k
     ///
     /// This corresponds to OS-RANK from CLRS Section 14.1.
@@ -297,4 +346,26 @@ mod tests {
         assert_eq!(tree.key_rank(&5), Some(2));
         assert_eq!(tree.key_rank(&7), Some(3));
     }
+
+    #[test]
+    fn test_ost_select_range() {
+        let mut tree = OrderStatisticTree::new();
+        for k in 1..100 {
+            tree.insert(k, k * 10);
+        }
+
+        let range = tree.select_range(10, 15);
+        let keys: Vec<i32> = range.iter().map(|(k, _)| **k).collect();
+        assert_eq!(keys, (10..=15).collect::<Vec<_>>());
+        assert!(range.iter().all(|(k, v)| **v == **k * 10));
+
+        for (lo, hi) in [(1, 1), (1, 99), (40, 41), (95, 200), (0, 3)] {
+            let expected: Vec<_> = (lo.max(1)..=hi.min(99))
+                .filter_map(|i| tree.select(i))
+                .collect();
+            assert_eq!(tree.select_range(lo, hi), expected);
+        }
+        assert!(tree.select_range(20, 19).is_empty());
+        assert!(tree.select_range(100, 120).is_empty());
+    }
 }
@@ -4,6 +4,8 @@
 //! A subsequence is a sequence that appears in the same relative order, but
 //! not necessarily contiguous.
 
+use super::Memoize;
+
 /// Computes the length of the longest common subsequence
 ///
 /// This corresponds to LCS-LENGTH from CLRS Section 15.4.
@@ -134,8 +136,18 @@ pub fn print_lcs_without_b<T: Clone + Eq>(
 pub fn memoized_lcs_length<T: Eq>(x: &[T], y: &[T]) -> Vec<Vec<usize>> {
     let m = x.len();
     let n = y.len();
+    let mut memo = Memoize::new();
+    memoized_lcs_length_aux(x, y, m, n, &mut memo);
+
+    // Subproblems the recursion never reached are reported as usize::MAX
     let mut c = vec![vec![usize::MAX; n + 1]; m + 1];
-    memoized_lcs_length_aux(x, y, m, n, &mut c);
+    for (i, row) in c.iter_mut().enumerate() {
+        for (j, entry) in row.iter_mut().enumerate() {
+            if let Some(&length) = memo.get(&(i, j)) {
+                *entry = length;
+            }
+        }
+    }
     c
 }
 
@@ -144,27 +156,30 @@ fn memoized_lcs_length_aux<T: Eq>(
     y: &[T],
     i: usize,
     j: usize,
-    c: &mut [Vec<usize>],
+    c: &mut Memoize<(usize, usize), usize>,
 ) -> usize {
-    if c[i][j] != usize::MAX {
-        return c[i][j];
-    }
-
-    let result = if i == 0 || j == 0 {
-        0
-    } else if x[i - 1] == y[j - 1] {
-        memoized_lcs_length_aux(x, y, i - 1, j - 1, c) + 1
-    } else {
-        memoized_lcs_length_aux(x, y, i - 1, j, c).max(memoized_lcs_length_aux(x, y, i, j - 1, c))
-    };
-
-    c[i][j] = result;
-    result
+    c.get_or_compute((i, j), |c| {
+        if i == 0 || j == 0 {
+            0
+        } else if x[i - 1] == y[j - 1] {
+            memoized_lcs_length_aux(x, y, i - 1, j - 1, c) + 1
+        } else {
+            memoized_lcs_length_aux(x, y, i - 1, j, c).max(memoized_lcs_length_aux(
+                x,
+                y,
+                i,
+                j - 1,
+                c,
+            ))
+        }
+    })
 }
 
 /// Computes LCS length using only O(min(m, n)) space
 ///
 /// This corresponds to the space-optimized version from CLRS Exercise 15.4-4.
+/// It is kept under its original name and delegates to
+/// [`lcs_length_linear_space`].
 ///
 /// # Arguments
 /// * `x` - First sequence
@@ -177,7 +192,34 @@ fn memoized_lcs_length_aux<T: Eq>(
 /// - Time: O(mn)
 /// - Space: O(min(m, n))
 pub fn lcs_length_space_optimized<T: Eq>(x: &[T], y: &[T]) -> usize {
-    let (shorter, longer) = if x.len() <= y.len() { (x, y) } else { (y, x) };
+    lcs_length_linear_space(x, y)
+}
+
+/// Computes LCS length keeping only two rows of the `c` table
+///
+/// Row i of the table from LCS-LENGTH depends only on row i - 1, so the
+/// shorter sequence indexes the columns and the longer one is scanned row by
+/// row, swapping two buffers (CLRS Exercise 15.4-4). The result equals
+/// `c[m][n]` from [`lcs_length`], but no subsequence can be reconstructed.
+///
+/// # Arguments
+/// * `a` - First sequence
+/// * `b` - Second sequence
+///
+/// # Returns
+/// The length of the LCS
+///
+/// # Complexity
+/// - Time: O(mn)
+/// - Space: O(min(m, n))
+///
+/// # Example
+/// ```
+/// use clrs::chapter_15::lcs_length_linear_space;
+/// assert_eq!(lcs_length_linear_space(b"ABCBDAB", b"BDCABA"), 4);
+/// ```
+pub fn lcs_length_linear_space<T: Eq>(a: &[T], b: &[T]) -> usize {
+    let (shorter, longer) = if a.len() <= b.len() { (a, b) } else { (b, a) };
 
     let mut prev = vec![0; shorter.len() + 1];
     let mut curr = vec![0; shorter.len() + 1];
@@ -201,6 +243,7 @@ pub fn lcs_length_space_optimized<T: Eq>(x: &[T], y: &[T]) -> usize {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use rand::Rng;
 
     #[test]
     fn test_lcs_length() {
@@ -244,4 +287,30 @@ mod tests {
         let length = lcs_length_space_optimized(x, y);
         assert_eq!(length, 4);
     }
+
+    #[test]
+    fn test_lcs_length_linear_space_matches_full_table() {
+        let mut rng = rand::thread_rng();
+        for _ in 0..200 {
+            let x: Vec<u8> = (0..rng.gen_range(0..40))
+                .map(|_| rng.gen_range(b'A'..b'E'))
+                .collect();
+            let y: Vec<u8> = (0..rng.gen_range(0..40))
+                .map(|_| rng.gen_range(b'A'..b'E'))
+                .collect();
+            let (c, _) = lcs_length(&x, &y);
+            assert_eq!(lcs_length_linear_space(&x, &y), c[x.len()][y.len()]);
+            assert_eq!(lcs_length_linear_space(&y, &x), c[x.len()][y.len()]);
+        }
+    }
+
+    #[test]
+    #[ignore = "2.5 billion table cells; run with `cargo test --release -- --ignored`"]
+    fn test_lcs_length_linear_space_long_inputs() {
+        // A full table for these inputs would hold 2.5 billion entries.
+        let x: Vec<u8> = (0..50_000).map(|i| b"ACGT"[i % 4]).collect();
+        let y: Vec<u8> = (0..50_000).map(|i| b"ACGT"[(i / 2) % 4]).collect();
+        let length = lcs_length_linear_space(&x, &y);
+        assert!((25_000..=50_000).contains(&length));
+    }
 }
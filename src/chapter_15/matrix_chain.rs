@@ -4,6 +4,8 @@
 //! them together. The problem is not actually to perform the multiplications,
 //! but merely to decide the sequence of the matrix multiplications involved.
 
+use crate::chapter_04::standard_matrix_multiply;
+
 /// Solves the matrix-chain multiplication problem
 ///
 /// This corresponds to MATRIX-CHAIN-ORDER from CLRS Section 15.2.
@@ -143,6 +145,171 @@ fn lookup_chain(p: &[usize], m: &mut [Vec<Option<usize>>], i: usize, j: usize) -
     cost
 }
 
+/// Multiplies a chain of matrices in an optimal order
+///
+/// This corresponds to MATRIX-CHAIN-MULTIPLY from CLRS Exercise 15.2-2. The
+/// dimension array is read off the matrices and passed to
+/// [`matrix_chain_order`], and the products are then formed recursively
+/// along the split table, each one with `standard_matrix_multiply`.
+///
+/// # Arguments
+/// * `matrices` - The chain A_1, ..., A_n; every matrix must be nonempty and
+///   rectangular
+///
+/// # Returns
+/// The product A_1 A_2 ... A_n
+///
+/// # Panics
+/// Panics if the chain is empty, if a matrix is empty or has rows of
+/// different lengths, or if the column count of A_i differs from the row
+/// count of A_{i+1}
+///
+/// # Complexity
+/// - Time: O(n³) to plan plus the scalar multiplications of the optimal
+///   order
+///
+/// # Example
+/// ```
+/// use clrs::chapter_15::multiply_chain_optimally;
+/// let a = vec![vec![1, 2]];
+/// let b = vec![vec![3], vec![4]];
+/// let c = vec![vec![5, 6]];
+/// assert_eq!(multiply_chain_optimally(&[a, b, c]), vec![vec![55, 66]]);
+/// ```
+pub fn multiply_chain_optimally(matrices: &[Vec<Vec<i64>>]) -> Vec<Vec<i64>> {
+    assert!(
+        !matrices.is_empty(),
+        "a chain must contain at least one matrix"
+    );
+
+    let mut dims = Vec::with_capacity(matrices.len() + 1);
+    for (index, matrix) in matrices.iter().enumerate() {
+        let rows = matrix.len();
+        let cols = matrix.first().map_or(0, Vec::len);
+        assert!(rows > 0 && cols > 0, "matrix A{} is empty", index + 1);
+        assert!(
+            matrix.iter().all(|row| row.len() == cols),
+            "matrix A{} has rows of different lengths",
+            index + 1
+        );
+        match dims.last() {
+            None => dims.push(rows),
+            Some(&previous_cols) => assert_eq!(
+                previous_cols,
+                rows,
+                "A{} has {} columns but A{} has {} rows",
+                index,
+                previous_cols,
+                index + 1,
+                rows
+            ),
+        }
+        dims.push(cols);
+    }
+
+    let (_, s) = matrix_chain_order(&dims);
+    matrix_chain_multiply(matrices, &s, 1, matrices.len())
+}
+
+fn matrix_chain_multiply(
+    matrices: &[Vec<Vec<i64>>],
+    s: &[Vec<usize>],
+    i: usize,
+    j: usize,
+) -> Vec<Vec<i64>> {
+    if i == j {
+        return matrices[i - 1].clone();
+    }
+    let k = s[i][j];
+    let left = matrix_chain_multiply(matrices, s, i, k);
+    let right = matrix_chain_multiply(matrices, s, k + 1, j);
+    standard_matrix_multiply(&left, &right)
+}
+
+/// A full parenthesization of a matrix chain A_i...A_j
+///
+/// Leaves name single matrices by their 1-indexed position in the chain, and
+/// each `Product` multiplies the results of two adjacent subchains. This
+/// allows the cost of any parenthesization, optimal or not, to be evaluated.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Parens {
+    /// The single matrix A_i
+    Matrix(usize),
+    /// The product of two adjacent subchains
+    Product(Box<Parens>, Box<Parens>),
+}
+
+impl Parens {
+    /// Builds the left-to-right parenthesization ((A_1 A_2) A_3)...A_n
+    ///
+    /// # Panics
+    /// Panics if `n == 0`
+    pub fn left_to_right(n: usize) -> Self {
+        assert!(n >= 1, "a chain must contain at least one matrix");
+        (2..=n).fold(Parens::Matrix(1), |acc, i| {
+            Parens::Product(Box::new(acc), Box::new(Parens::Matrix(i)))
+        })
+    }
+
+    /// Builds the parenthesization of A_i...A_j recorded in the split table
+    /// `s` returned by [`matrix_chain_order`]
+    pub fn from_split_table(s: &[Vec<usize>], i: usize, j: usize) -> Self {
+        if i == j {
+            Parens::Matrix(i)
+        } else {
+            let k = s[i][j];
+            Parens::Product(
+                Box::new(Parens::from_split_table(s, i, k)),
+                Box::new(Parens::from_split_table(s, k + 1, j)),
+            )
+        }
+    }
+
+    /// Returns the number of scalar multiplications this parenthesization
+    /// performs, where matrix A_i has dimensions `dims[i-1] × dims[i]`
+    ///
+    /// # Panics
+    /// Panics if a `Product` does not combine adjacent subchains or a matrix
+    /// index lies outside `dims`
+    ///
+    /// # Complexity
+    /// - Time: O(n) where n is the number of matrices
+    pub fn evaluate_cost(&self, dims: &[usize]) -> u64 {
+        self.evaluate(dims).2
+    }
+
+    /// Returns the chain range (i, j) covered and the cost of computing it
+    fn evaluate(&self, dims: &[usize]) -> (usize, usize, u64) {
+        match self {
+            Parens::Matrix(i) => {
+                assert!(
+                    *i >= 1 && *i < dims.len(),
+                    "matrix index {} out of range",
+                    i
+                );
+                (*i, *i, 0)
+            }
+            Parens::Product(left, right) => {
+                let (i, k, left_cost) = left.evaluate(dims);
+                let (k1, j, right_cost) = right.evaluate(dims);
+                assert_eq!(k + 1, k1, "product must combine adjacent subchains");
+                let multiply = (dims[i - 1] * dims[k] * dims[j]) as u64;
+                (i, j, left_cost + right_cost + multiply)
+            }
+        }
+    }
+}
+
+impl std::fmt::Display for Parens {
+    /// Formats the parenthesization as PRINT-OPTIMAL-PARENS does
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Parens::Matrix(i) => write!(f, "A{}", i),
+            Parens::Product(left, right) => write!(f, "({}{})", left, right),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -182,4 +349,93 @@ mod tests {
         let cost = memoized_matrix_chain(&dims);
         assert_eq!(cost, 9375);
     }
+
+    #[test]
+    fn test_parens_left_to_right_costs_more_than_optimal() {
+        let dims = vec![30, 35, 15, 5, 10, 20, 25];
+        let (m, s) = matrix_chain_order(&dims);
+        assert_eq!(m[1][6], 15125);
+
+        let optimal = Parens::from_split_table(&s, 1, 6);
+        assert_eq!(optimal.evaluate_cost(&dims), 15125);
+        assert_eq!(optimal.to_string(), print_optimal_parens(&s, 1, 6));
+
+        let naive = Parens::left_to_right(6);
+        assert_eq!(naive.to_string(), "(((((A1A2)A3)A4)A5)A6)");
+        // 30·35·15 + 30·15·5 + 30·5·10 + 30·10·20 + 30·20·25
+        assert_eq!(naive.evaluate_cost(&dims), 40500);
+        assert!(naive.evaluate_cost(&dims) > 15125);
+    }
+
+    #[test]
+    fn test_parens_single_matrix() {
+        assert_eq!(Parens::left_to_right(1).evaluate_cost(&[4, 7]), 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "adjacent")]
+    fn test_parens_rejects_non_adjacent_product() {
+        let bad = Parens::Product(Box::new(Parens::Matrix(1)), Box::new(Parens::Matrix(3)));
+        bad.evaluate_cost(&[1, 2, 3, 4]);
+    }
+
+    /// A `rows`×`cols` matrix of small entries that vary with `seed`
+    fn sample_matrix(rows: usize, cols: usize, seed: i64) -> Vec<Vec<i64>> {
+        (0..rows as i64)
+            .map(|i| {
+                (0..cols as i64)
+                    .map(|j| (seed + 7 * i + 3 * j) % 11 - 5)
+                    .collect()
+            })
+            .collect()
+    }
+
+    fn left_to_right_product(chain: &[Vec<Vec<i64>>]) -> Vec<Vec<i64>> {
+        chain[1..].iter().fold(chain[0].clone(), |acc, next| {
+            standard_matrix_multiply(&acc, next)
+        })
+    }
+
+    #[test]
+    fn test_multiply_chain_optimally() {
+        // A_1 is 10×100, A_2 100×5, A_3 5×50 and A_4 50×1; the optimum
+        // A_1(A_2(A_3 A_4)) costs 250 + 500 + 1000 = 1750 multiplications and
+        // splits every subchain after its first matrix, unlike left to right.
+        let dims = [10, 100, 5, 50, 1];
+        let (m, s) = matrix_chain_order(&dims);
+        assert_eq!(m[1][4], 1750);
+        assert_eq!(print_optimal_parens(&s, 1, 4), "(A1(A2(A3A4)))");
+
+        let chain: Vec<_> = dims
+            .windows(2)
+            .enumerate()
+            .map(|(k, d)| sample_matrix(d[0], d[1], k as i64))
+            .collect();
+        assert_eq!(
+            multiply_chain_optimally(&chain),
+            left_to_right_product(&chain)
+        );
+
+        // The CLRS chain mixes left and right splits: ((A1(A2A3))((A4A5)A6)).
+        let dims = [30, 35, 15, 5, 10, 20, 25];
+        let chain: Vec<_> = dims
+            .windows(2)
+            .enumerate()
+            .map(|(k, d)| sample_matrix(d[0], d[1], 2 * k as i64))
+            .collect();
+        assert_eq!(
+            multiply_chain_optimally(&chain),
+            left_to_right_product(&chain)
+        );
+
+        assert_eq!(multiply_chain_optimally(&chain[..1]), chain[0]);
+    }
+
+    #[test]
+    #[should_panic(expected = "A1 has 3 columns but A2 has 1 rows")]
+    fn test_multiply_chain_optimally_rejects_mismatch() {
+        let a1 = vec![vec![1, 2, 3]];
+        let a2 = vec![vec![1, 2]];
+        multiply_chain_optimally(&[a1, a2]);
+    }
 }
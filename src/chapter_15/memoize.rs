@@ -0,0 +1,138 @@
+//! Memoization (Section 15.3)
+//!
+//! A top-down dynamic-programming algorithm stores each subproblem's solution
+//! the first time it is computed and looks it up on every later call. This
+//! module provides that table for arbitrary hashable subproblem arguments.
+
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// Table of solved subproblems for top-down dynamic programming
+///
+/// The computation passed to [`get_or_compute`](Memoize::get_or_compute)
+/// receives the table itself, so a recursive procedure can look up its own
+/// subproblems through the same table.
+///
+/// # Example
+/// ```
+/// use clrs::chapter_15::Memoize;
+///
+/// fn fib(memo: &mut Memoize<u64, u64>, n: u64) -> u64 {
+///     memo.get_or_compute(n, |memo| {
+///         if n < 2 {
+///             n
+///         } else {
+///             fib(memo, n - 1) + fib(memo, n - 2)
+///         }
+///     })
+/// }
+///
+/// let mut memo = Memoize::new();
+/// assert_eq!(fib(&mut memo, 90), 2_880_067_194_370_816_120);
+/// assert_eq!(memo.len(), 91);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Memoize<Args, Ret> {
+    table: HashMap<Args, Ret>,
+}
+
+impl<Args: Hash + Eq, Ret: Clone> Memoize<Args, Ret> {
+    /// Creates an empty table
+    pub fn new() -> Self {
+        Memoize {
+            table: HashMap::new(),
+        }
+    }
+
+    /// Returns the stored solution for `args`, computing it with `f` first if
+    /// this is the first request for `args`
+    ///
+    /// `f` is given the table so it can solve smaller subproblems recursively.
+    /// It is called at most once per distinct `args` over the table's life.
+    ///
+    /// # Arguments
+    /// * `args` - The subproblem to solve
+    /// * `f` - Computes the solution to `args`
+    ///
+    /// # Returns
+    /// A clone of the stored solution
+    pub fn get_or_compute<F>(&mut self, args: Args, f: F) -> Ret
+    where
+        F: FnOnce(&mut Self) -> Ret,
+    {
+        if let Some(ret) = self.table.get(&args) {
+            return ret.clone();
+        }
+        let ret = f(self);
+        self.table.insert(args, ret.clone());
+        ret
+    }
+
+    /// Returns the stored solution for `args` without computing anything
+    pub fn get(&self, args: &Args) -> Option<&Ret> {
+        self.table.get(args)
+    }
+
+    /// Returns the number of subproblems solved so far
+    pub fn len(&self) -> usize {
+        self.table.len()
+    }
+
+    /// Returns true if no subproblem has been solved yet
+    pub fn is_empty(&self) -> bool {
+        self.table.is_empty()
+    }
+}
+
+impl<Args: Hash + Eq, Ret: Clone> Default for Memoize<Args, Ret> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Counts lattice paths to (i, j), recording every evaluation of the body.
+    fn paths(
+        memo: &mut Memoize<(usize, usize), u64>,
+        i: usize,
+        j: usize,
+        calls: &mut Vec<(usize, usize)>,
+    ) -> u64 {
+        memo.get_or_compute((i, j), |memo| {
+            calls.push((i, j));
+            if i == 0 || j == 0 {
+                1
+            } else {
+                paths(memo, i - 1, j, calls) + paths(memo, i, j - 1, calls)
+            }
+        })
+    }
+
+    #[test]
+    fn test_memoize_computes_each_argument_once() {
+        let mut memo = Memoize::new();
+        let mut calls = Vec::new();
+        // Every point of the 5 x 5 grid except the unreachable corner (0, 0).
+        assert_eq!(paths(&mut memo, 4, 4, &mut calls), 70);
+        assert_eq!(calls.len(), 24);
+        assert_eq!(memo.len(), 24);
+
+        // Repeated and overlapping requests are answered from the table.
+        assert_eq!(paths(&mut memo, 4, 4, &mut calls), 70);
+        assert_eq!(paths(&mut memo, 2, 3, &mut calls), 10);
+        assert_eq!(calls.len(), 24);
+
+        // A new argument evaluates only the subproblems not yet solved.
+        assert_eq!(paths(&mut memo, 5, 4, &mut calls), 126);
+        assert_eq!(calls.len(), 29);
+
+        calls.sort_unstable();
+        calls.dedup();
+        assert_eq!(calls.len(), 29);
+        assert_eq!(memo.get(&(3, 2)), Some(&10));
+        assert_eq!(memo.get(&(9, 9)), None);
+    }
+}
@@ -5,10 +5,12 @@
 
 pub mod longest_common_subsequence;
 pub mod matrix_chain;
+pub mod memoize;
 pub mod optimal_bst;
 pub mod rod_cutting;
 
 pub use longest_common_subsequence::*;
 pub use matrix_chain::*;
+pub use memoize::*;
 pub use optimal_bst::*;
 pub use rod_cutting::*;
@@ -3,6 +3,8 @@
 //! Given a set of keys and their access probabilities, construct a binary
 //! search tree that minimizes the expected search cost.
 
+use crate::chapter_12::{BSTNode, BinarySearchTree};
+
 /// Computes the cost and structure of an optimal binary search tree
 ///
 /// This corresponds to OPTIMAL-BST from CLRS Section 15.5.
@@ -151,6 +153,75 @@ pub fn optimal_bst_knuth(p: &[f64], q: &[f64], n: usize) -> (Vec<Vec<f64>>, Vec<
     (e, root)
 }
 
+/// Builds an optimal binary search tree over `keys`
+///
+/// Runs OPTIMAL-BST and then follows the root table the way
+/// CONSTRUCT-OPTIMAL-BST (CLRS Exercise 15.5-1) does, but links actual nodes
+/// into a chapter 12 [`BinarySearchTree`] instead of describing them. The
+/// dummy keys d_0...d_n become the empty subtrees.
+///
+/// # Arguments
+/// * `keys` - The keys k_1...k_n in strictly increasing order (0-indexed)
+/// * `p` - Probability array where p[i] is the probability of searching for key k_i (1-indexed)
+/// * `q` - Probability array where q[i] is the probability of searching for dummy key d_i (0-indexed)
+///
+/// # Returns
+/// A search tree whose expected search cost equals `e[1][n]` from [`optimal_bst`]
+///
+/// # Complexity
+/// - Time: O(n³)
+/// - Space: O(n²)
+///
+/// # Panics
+/// Panics if `p` or `q` does not have `keys.len() + 1` entries
+///
+/// # Example
+/// ```
+/// use clrs::chapter_15::build_optimal_bst;
+/// let p = vec![0.0, 0.15, 0.10, 0.05, 0.10, 0.20];
+/// let q = vec![0.05, 0.10, 0.05, 0.05, 0.05, 0.10];
+/// let tree = build_optimal_bst(&[1, 2, 3, 4, 5], &p, &q);
+/// assert_eq!(tree.root.as_ref().unwrap().key, 2);
+/// assert_eq!(tree.search(5), Some(&()));
+/// ```
+pub fn build_optimal_bst<K: Ord + Clone>(
+    keys: &[K],
+    p: &[f64],
+    q: &[f64],
+) -> BinarySearchTree<K, ()> {
+    let n = keys.len();
+    assert_eq!(p.len(), n + 1, "p must have one entry per key plus p[0]");
+    assert_eq!(q.len(), n + 1, "q must have one entry per dummy key");
+    debug_assert!(
+        keys.windows(2).all(|w| w[0] < w[1]),
+        "keys must be strictly increasing"
+    );
+
+    let (_, root) = optimal_bst(p, q, n);
+    BinarySearchTree {
+        root: build_subtree(keys, &root, 1, n),
+    }
+}
+
+fn build_subtree<K: Ord + Clone>(
+    keys: &[K],
+    root: &[Vec<usize>],
+    i: usize,
+    j: usize,
+) -> Option<Box<BSTNode<K, ()>>> {
+    if i > j {
+        return None;
+    }
+
+    let r = root[i][j];
+    Some(Box::new(BSTNode {
+        key: keys[r - 1].clone(),
+        value: (),
+        left: build_subtree(keys, root, i, r - 1),
+        right: build_subtree(keys, root, r + 1, j),
+    }))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -189,4 +260,52 @@ mod tests {
         assert!((e1[1][5] - e2[1][5]).abs() < 0.0001);
         assert_eq!(root1[1][5], root2[1][5]);
     }
+
+    /// Number of nodes on the search path from the root to `key`.
+    fn key_depth<K: Ord>(tree: &BinarySearchTree<K, ()>, key: &K) -> usize {
+        let mut node = tree.root.as_ref();
+        let mut depth = 1;
+        while let Some(n) = node {
+            match key.cmp(&n.key) {
+                std::cmp::Ordering::Equal => return depth,
+                std::cmp::Ordering::Less => node = n.left.as_ref(),
+                std::cmp::Ordering::Greater => node = n.right.as_ref(),
+            }
+            depth += 1;
+        }
+        panic!("key not in tree");
+    }
+
+    #[test]
+    fn test_build_optimal_bst() {
+        let keys = ["apple", "banana", "cherry", "date", "elderberry"];
+        let p = vec![0.0, 0.15, 0.10, 0.05, 0.10, 0.20];
+        let q = vec![0.05, 0.10, 0.05, 0.05, 0.05, 0.10];
+        let (e, root) = optimal_bst(&p, &q, 5);
+
+        let tree = build_optimal_bst(&keys, &p, &q);
+        assert_eq!(tree.root.as_ref().unwrap().key, keys[root[1][5] - 1]);
+        assert_eq!(tree.root.as_ref().unwrap().key, "banana");
+        for key in keys {
+            assert_eq!(tree.search(key), Some(&()));
+        }
+        assert_eq!(tree.search("fig"), None);
+
+        // Expected cost: each k_i costs depth(k_i), and each dummy d_i sits one
+        // level below the deeper of its neighbouring keys.
+        let depths: Vec<usize> = keys.iter().map(|k| key_depth(&tree, k)).collect();
+        let mut cost = 0.0;
+        for i in 1..=5 {
+            cost += p[i] * depths[i - 1] as f64;
+        }
+        for (i, &qi) in q.iter().enumerate() {
+            let left = if i > 0 { depths[i - 1] } else { 0 };
+            let right = if i < 5 { depths[i] } else { 0 };
+            cost += qi * (left.max(right) + 1) as f64;
+        }
+        assert!((cost - e[1][5]).abs() < 1e-9);
+
+        let empty = build_optimal_bst::<i32>(&[], &[0.0], &[1.0]);
+        assert!(empty.root.is_none());
+    }
 }
@@ -4,6 +4,8 @@
 //! determine the maximum revenue obtainable by cutting up the rod and selling
 //! the pieces.
 
+use super::Memoize;
+
 /// Solves the rod-cutting problem using a bottom-up approach
 ///
 /// This corresponds to BOTTOM-UP-CUT-ROD from CLRS Section 15.1.
@@ -60,29 +62,23 @@ pub fn bottom_up_cut_rod(p: &[i32], n: usize) -> i32 {
 /// - Time: O(n²)
 /// - Space: O(n)
 pub fn memoized_cut_rod(p: &[i32], n: usize) -> i32 {
-    let mut r = vec![i32::MIN; n + 1];
-    memoized_cut_rod_aux(p, n, &mut r)
+    memoized_cut_rod_aux(p, n, &mut Memoize::new())
 }
 
-fn memoized_cut_rod_aux(p: &[i32], n: usize, r: &mut [i32]) -> i32 {
-    if r[n] >= 0 {
-        return r[n];
-    }
-
-    let q = if n == 0 {
-        0
-    } else {
-        let mut max_revenue = i32::MIN;
-        for i in 1..=n {
-            if i < p.len() {
-                max_revenue = max_revenue.max(p[i] + memoized_cut_rod_aux(p, n - i, r));
+fn memoized_cut_rod_aux(p: &[i32], n: usize, r: &mut Memoize<usize, i32>) -> i32 {
+    r.get_or_compute(n, |r| {
+        if n == 0 {
+            0
+        } else {
+            let mut max_revenue = i32::MIN;
+            for i in 1..=n {
+                if i < p.len() {
+                    max_revenue = max_revenue.max(p[i] + memoized_cut_rod_aux(p, n - i, r));
+                }
             }
+            max_revenue
         }
-        max_revenue
-    };
-
-    r[n] = q;
-    q
+    })
 }
 
 /// Solves the rod-cutting problem and returns the optimal solution
@@ -179,6 +175,64 @@ pub fn modified_cut_rod(p: &[i32], n: usize, c: i32) -> i32 {
     r[n]
 }
 
+/// Solves the rod-cutting problem with a fixed cost per cut and reports the pieces
+///
+/// This extends MODIFIED-CUT-ROD from CLRS Exercise 15.1-3 in the way
+/// EXTENDED-BOTTOM-UP-CUT-ROD extends BOTTOM-UP-CUT-ROD: `s[j]` records the
+/// first piece of an optimal solution for length j, with `s[j] = j` meaning
+/// the rod is sold whole. Cutting a rod into k pieces costs `(k - 1) * cut_cost`,
+/// and on ties the solution with fewer cuts wins.
+///
+/// # Arguments
+/// * `prices` - Price table where prices[i] is the price for a rod of length i (1-indexed)
+/// * `n` - Length of the rod
+/// * `cut_cost` - Fixed cost per cut
+///
+/// # Returns
+/// A tuple (net_revenue, pieces) where pieces lists the lengths sold
+///
+/// # Complexity
+/// - Time: O(n²)
+/// - Space: O(n)
+///
+/// # Panics
+/// Panics if `prices` has no entry for length `n`
+///
+/// # Example
+/// ```
+/// use clrs::chapter_15::rod_cutting_with_cost;
+/// let prices = vec![0, 1, 5, 8, 9, 10, 17, 17, 20, 24, 30];
+/// assert_eq!(rod_cutting_with_cost(&prices, 7, 1), (17, vec![7]));
+/// ```
+pub fn rod_cutting_with_cost(prices: &[i64], n: usize, cut_cost: i64) -> (i64, Vec<usize>) {
+    assert!(n < prices.len(), "price table must cover the rod length");
+
+    let mut r = vec![0; n + 1];
+    let mut s = vec![0; n + 1];
+
+    for j in 1..=n {
+        let mut q = prices[j];
+        s[j] = j;
+        for i in 1..j {
+            let revenue = prices[i] + r[j - i] - cut_cost;
+            if q < revenue {
+                q = revenue;
+                s[j] = i;
+            }
+        }
+        r[j] = q;
+    }
+
+    let mut pieces = Vec::new();
+    let mut j = n;
+    while j > 0 {
+        pieces.push(s[j]);
+        j -= s[j];
+    }
+
+    (r[n], pieces)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -224,4 +278,29 @@ mod tests {
         // With cost=1, cutting might not always be optimal
         assert!(revenue >= 0);
     }
+
+    #[test]
+    fn test_rod_cutting_with_cost() {
+        let prices: Vec<i64> = vec![0, 1, 5, 8, 9, 10, 17, 17, 20, 24, 30];
+        let prices_i32: Vec<i32> = prices.iter().map(|&p| p as i32).collect();
+        let free_cuts = print_cut_rod_solution(&prices_i32, 7);
+
+        let (revenue, pieces) = rod_cutting_with_cost(&prices, 7, 1);
+        assert_eq!(revenue, 17);
+        assert_eq!(pieces.iter().sum::<usize>(), 7);
+        assert!(pieces.len() < free_cuts.len());
+
+        // Net revenue is the price of the pieces minus one cost per cut.
+        let cuts = pieces.len() - 1;
+        let gross: i64 = pieces.iter().map(|&len| prices[len]).sum();
+        assert_eq!(revenue, gross - cuts as i64);
+
+        // Free cuts reproduce the classic answer, and a rod of length 0 is free.
+        assert_eq!(rod_cutting_with_cost(&prices, 7, 0).0, 18);
+        assert_eq!(rod_cutting_with_cost(&prices, 0, 5), (0, vec![]));
+        for n in 1..=10 {
+            let (revenue, _) = rod_cutting_with_cost(&prices, n, 2);
+            assert_eq!(revenue, modified_cut_rod(&prices_i32, n, 2) as i64);
+        }
+    }
 }
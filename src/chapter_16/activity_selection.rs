@@ -71,6 +71,76 @@ pub fn greedy_activity_selector(activities: &[Activity]) -> Vec<usize> {
     selected
 }
 
+/// Solves the activity-selection problem greedily with an iterative loop
+///
+/// This is GREEDY-ACTIVITY-SELECTOR from CLRS Section 16.1 under the name
+/// paired with [`activity_selection_recursive`]; both return the same
+/// selection on every input. Assumes activities are sorted by finish time.
+///
+/// # Arguments
+/// * `activities` - Vector of activities sorted by finish time
+///
+/// # Returns
+/// A vector of indices of selected activities
+///
+/// # Complexity
+/// - Time: O(n) where n is the number of activities
+/// - Space: O(n)
+pub fn activity_selection_greedy(activities: &[Activity]) -> Vec<usize> {
+    greedy_activity_selector(activities)
+}
+
+/// Solves the activity-selection problem with a recursive greedy algorithm
+///
+/// This corresponds to RECURSIVE-ACTIVITY-SELECTOR from CLRS Section 16.1.
+/// The first activity plays the role of the textbook's fictitious `a_0`
+/// boundary: it is always selected, and each recursive call skips to the
+/// first later activity that starts after the last selected one finishes.
+/// Assumes activities are sorted by finish time.
+///
+/// # Arguments
+/// * `activities` - Vector of activities sorted by finish time
+///
+/// # Returns
+/// A vector of indices of selected activities
+///
+/// # Complexity
+/// - Time: O(n) where n is the number of activities
+/// - Space: O(n) including the recursion stack
+///
+/// # Example
+/// ```
+/// use clrs::chapter_16::{Activity, activity_selection_recursive};
+/// let activities = vec![
+///     Activity::new(1, 4),
+///     Activity::new(3, 5),
+///     Activity::new(5, 7),
+/// ];
+/// assert_eq!(activity_selection_recursive(&activities), vec![0, 2]);
+/// ```
+pub fn activity_selection_recursive(activities: &[Activity]) -> Vec<usize> {
+    if activities.is_empty() {
+        return Vec::new();
+    }
+
+    let mut selected = vec![0];
+    recursive_activity_selector(activities, 0, &mut selected);
+    selected
+}
+
+fn recursive_activity_selector(activities: &[Activity], k: usize, selected: &mut Vec<usize>) {
+    // CLRS: m = k + 1; while m <= n and s[m] < f[k], m = m + 1
+    let mut m = k + 1;
+    while m < activities.len() && activities[m].start < activities[k].finish {
+        m += 1;
+    }
+
+    if m < activities.len() {
+        selected.push(m);
+        recursive_activity_selector(activities, m, selected);
+    }
+}
+
 /// Solves the activity-selection problem using dynamic programming
 ///
 /// This corresponds to DYNAMIC-ACTIVITY-SELECTOR from CLRS Exercise 16.1-1.
@@ -189,9 +259,51 @@ fn find_last_compatible(activities: &[Activity], i: usize) -> usize {
         .unwrap_or(usize::MAX)
 }
 
+/// Computes the minimum number of lecture halls needed to schedule all intervals
+///
+/// This is the interval-partitioning problem from CLRS Exercise 16.1-4. The
+/// answer equals the largest number of intervals that overlap at one instant,
+/// found by sweeping the start and finish events in time order. Intervals are
+/// half-open, so one that finishes exactly when another starts can share its
+/// hall: at equal times, finish events are processed before start events.
+///
+/// # Arguments
+/// * `intervals` - `(start, finish)` pairs in any order
+///
+/// # Returns
+/// The minimum number of halls (0 for no intervals)
+///
+/// # Complexity
+/// - Time: O(n lg n) where n is the number of intervals
+/// - Space: O(n)
+///
+/// # Example
+/// ```
+/// use clrs::chapter_16::min_partitions;
+/// assert_eq!(min_partitions(&[(0, 30), (5, 10), (15, 20)]), 2);
+/// assert_eq!(min_partitions(&[(0, 5), (5, 10)]), 1);
+/// ```
+pub fn min_partitions(intervals: &[(usize, usize)]) -> usize {
+    // (time, delta) sorts a finish (-1) ahead of a start (+1) at the same time.
+    let mut events: Vec<(usize, i32)> = intervals
+        .iter()
+        .flat_map(|&(start, finish)| [(start, 1), (finish, -1)])
+        .collect();
+    events.sort_unstable();
+
+    let mut active = 0;
+    let mut rooms = 0;
+    for (_, delta) in events {
+        active += delta;
+        rooms = rooms.max(active);
+    }
+    rooms as usize
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use rand::Rng;
 
     #[test]
     fn test_greedy_activity_selector() {
@@ -242,4 +354,64 @@ mod tests {
         let max_value = weighted_activity_selector(&activities, &values);
         assert!(max_value > 0);
     }
+
+    fn clrs_figure_16_1() -> Vec<Activity> {
+        let start = [1, 3, 0, 5, 3, 5, 6, 8, 8, 2, 12];
+        let finish = [4, 5, 6, 7, 9, 9, 10, 11, 12, 14, 16];
+        start
+            .iter()
+            .zip(finish.iter())
+            .map(|(&s, &f)| Activity::new(s, f))
+            .collect()
+    }
+
+    #[test]
+    fn test_activity_selection_clrs_figure_16_1() {
+        let activities = clrs_figure_16_1();
+        // a_1, a_4, a_8, a_11 in CLRS's 1-based numbering
+        assert_eq!(activity_selection_recursive(&activities), vec![0, 3, 7, 10]);
+        assert_eq!(activity_selection_greedy(&activities), vec![0, 3, 7, 10]);
+    }
+
+    #[test]
+    fn test_activity_selection_recursive_matches_greedy() {
+        let mut rng = rand::thread_rng();
+        assert!(activity_selection_recursive(&[]).is_empty());
+
+        for _ in 0..200 {
+            let n = rng.gen_range(1..20);
+            let mut activities: Vec<Activity> = (0..n)
+                .map(|_| {
+                    let start = rng.gen_range(0..30);
+                    Activity::new(start, start + rng.gen_range(1..10))
+                })
+                .collect();
+            activities.sort_by_key(|a| a.finish);
+
+            assert_eq!(
+                activity_selection_recursive(&activities),
+                activity_selection_greedy(&activities)
+            );
+        }
+    }
+
+    #[test]
+    fn test_min_partitions() {
+        assert_eq!(min_partitions(&[(0, 30), (5, 10), (15, 20)]), 2);
+        assert_eq!(min_partitions(&[]), 0);
+        // Touching intervals share a hall.
+        assert_eq!(min_partitions(&[(0, 5), (5, 10), (10, 15)]), 1);
+        assert_eq!(min_partitions(&[(1, 4), (2, 5), (3, 6), (4, 7)]), 3);
+
+        let activities = clrs_figure_16_1();
+        let intervals: Vec<(usize, usize)> = activities
+            .iter()
+            .map(|a| (a.start as usize, a.finish as usize))
+            .collect();
+        let brute_force = (0..16)
+            .map(|t| intervals.iter().filter(|&&(s, f)| s <= t && t < f).count())
+            .max()
+            .unwrap();
+        assert_eq!(min_partitions(&intervals), brute_force);
+    }
 }
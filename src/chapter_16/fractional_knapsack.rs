@@ -42,7 +42,11 @@ impl Item {
 /// # Returns
 /// A tuple (total_value, fractions) where:
 /// - total_value is the maximum value achievable
-/// - fractions[i] is the fraction of item i taken (0.0 to 1.0)
+/// - fractions[i] is the fraction of item i taken (0.0 to 1.0), indexed like
+///   `items`, so `sum(fractions[i] * items[i].value) == total_value`
+///
+/// At most one item is taken fractionally. Unless every item fits, the
+/// weights taken add up to exactly `capacity`.
 ///
 /// # Complexity
 /// - Time: O(n log n) where n is the number of items (due to sorting)
@@ -57,7 +61,8 @@ impl Item {
 ///     Item::new(30.0, 120.0),
 /// ];
 /// let (value, fractions) = fractional_knapsack(&items, 50.0);
-/// assert!(value > 0.0);
+/// assert!((value - 240.0).abs() < 1e-9);
+/// assert_eq!(fractions, vec![1.0, 1.0, 2.0 / 3.0]);
 /// ```
 pub fn fractional_knapsack(items: &[Item], capacity: f64) -> (f64, Vec<f64>) {
     let n = items.len();
@@ -76,10 +81,17 @@ pub fn fractional_knapsack(items: &[Item], capacity: f64) -> (f64, Vec<f64>) {
         }
 
         let item = items[idx];
-        let fraction = (remaining / item.weight).min(1.0);
-        fractions[idx] = fraction;
-        total_value += item.value * fraction;
-        remaining -= item.weight * fraction;
+        if item.weight <= remaining {
+            fractions[idx] = 1.0;
+            total_value += item.value;
+            remaining -= item.weight;
+        } else {
+            // The knapsack fills up partway through this item.
+            let fraction = remaining / item.weight;
+            fractions[idx] = fraction;
+            total_value += item.value * fraction;
+            remaining = 0.0;
+        }
     }
 
     (total_value, fractions)
@@ -156,6 +168,38 @@ mod tests {
         assert!((fractions[2] - 2.0 / 3.0).abs() < 0.01);
     }
 
+    #[test]
+    fn test_fractional_knapsack_breakdown() {
+        // CLRS Figure 16.2, with the items listed out of density order.
+        let items = vec![
+            Item::new(30.0, 120.0),
+            Item::new(10.0, 60.0),
+            Item::new(20.0, 100.0),
+        ];
+        let (value, fractions) = fractional_knapsack(&items, 50.0);
+
+        assert!((value - 240.0).abs() < 1e-9);
+        assert_eq!(fractions, vec![2.0 / 3.0, 1.0, 1.0]);
+
+        let value_taken: f64 = items
+            .iter()
+            .zip(&fractions)
+            .map(|(it, f)| it.value * f)
+            .sum();
+        let weight_taken: f64 = items
+            .iter()
+            .zip(&fractions)
+            .map(|(it, f)| it.weight * f)
+            .sum();
+        assert!((value_taken - value).abs() < 1e-9);
+        assert!((weight_taken - 50.0).abs() < 1e-9);
+
+        // Everything fits: all fractions are 1 and the weight is below capacity.
+        let (value, fractions) = fractional_knapsack(&items, 100.0);
+        assert_eq!(value, 280.0);
+        assert_eq!(fractions, vec![1.0; 3]);
+    }
+
     #[test]
     fn test_fractional_knapsack_empty() {
         let items = vec![];
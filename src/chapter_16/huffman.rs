@@ -3,7 +3,7 @@
 //! Huffman coding is a lossless data compression algorithm that assigns
 //! variable-length codes to characters based on their frequencies.
 
-use std::cmp::Ordering;
+use std::cmp::{Ordering, Reverse};
 use std::collections::BinaryHeap;
 
 /// Represents a character with its frequency
@@ -71,6 +71,14 @@ impl Ord for HuffmanNode {
 ///
 /// This corresponds to HUFFMAN from CLRS Section 16.3.
 ///
+/// Ties between equal frequencies are broken first-in, first-out, so the
+/// tree depends only on the input and never on the heap's internal layout.
+/// Every node gets a creation number: leaves are numbered in the order of
+/// `char_freqs`, and each merged node gets the next number after all nodes
+/// created before it. Among nodes of equal frequency, EXTRACT-MIN takes the
+/// one with the smallest number, and the first node extracted in a merge
+/// becomes the left child (code bit `0`).
+///
 /// # Arguments
 /// * `char_freqs` - Vector of character-frequency pairs
 ///
@@ -107,32 +115,38 @@ pub fn build_huffman_tree(char_freqs: &[CharFreq]) -> HuffmanNode {
         };
     }
 
-    let mut heap = BinaryHeap::new();
+    // The heap orders (frequency, creation number) pairs; `nodes` holds the
+    // node with each creation number until it is merged.
+    let mut nodes: Vec<Option<HuffmanNode>> = Vec::with_capacity(2 * char_freqs.len() - 1);
+    let mut heap = BinaryHeap::with_capacity(char_freqs.len());
 
     // Initialize heap with leaf nodes
     for &cf in char_freqs {
-        heap.push(HuffmanNode::Leaf {
+        heap.push(Reverse((cf.frequency, nodes.len())));
+        nodes.push(Some(HuffmanNode::Leaf {
             character: cf.character,
             frequency: cf.frequency,
-        });
+        }));
     }
 
     // Build the tree
     while heap.len() > 1 {
-        let left = heap.pop().unwrap();
-        let right = heap.pop().unwrap();
+        let Reverse((left_freq, left)) = heap.pop().unwrap();
+        let Reverse((right_freq, right)) = heap.pop().unwrap();
 
-        let freq = left.frequency() + right.frequency();
+        let freq = left_freq + right_freq;
         let internal = HuffmanNode::Internal {
             frequency: freq,
-            left: Box::new(left),
-            right: Box::new(right),
+            left: Box::new(nodes[left].take().expect("each node is merged once")),
+            right: Box::new(nodes[right].take().expect("each node is merged once")),
         };
 
-        heap.push(internal);
+        heap.push(Reverse((freq, nodes.len())));
+        nodes.push(Some(internal));
     }
 
-    heap.pop().unwrap()
+    let Reverse((_, root)) = heap.pop().unwrap();
+    nodes[root].take().expect("the root is never merged")
 }
 
 /// Generates Huffman codes from a Huffman tree
@@ -141,14 +155,20 @@ pub fn build_huffman_tree(char_freqs: &[CharFreq]) -> HuffmanNode {
 /// * `tree` - Root of the Huffman tree
 ///
 /// # Returns
-/// A map from characters to their binary codes (as strings of '0' and '1')
+/// A map from characters to their binary codes (as strings of '0' and '1').
+/// A tree that is a single leaf gets the one-bit code `"0"`, so its text
+/// still encodes to a decodable bit string.
 ///
 /// # Complexity
 /// - Time: O(n) where n is the number of leaves
 /// - Space: O(n)
 pub fn generate_codes(tree: &HuffmanNode) -> std::collections::HashMap<char, String> {
     let mut codes = std::collections::HashMap::new();
-    generate_codes_recursive(tree, String::new(), &mut codes);
+    let root_prefix = match tree {
+        HuffmanNode::Leaf { .. } => String::from("0"),
+        HuffmanNode::Internal { .. } => String::new(),
+    };
+    generate_codes_recursive(tree, root_prefix, &mut codes);
     codes
 }
 
@@ -189,9 +209,17 @@ pub fn encode(text: &str, codes: &std::collections::HashMap<char, String>) -> St
 /// * `tree` - Root of the Huffman tree
 ///
 /// # Returns
-/// Decoded text
+/// Decoded text. A single-leaf tree decodes each bit to its character.
 pub fn decode(encoded: &str, tree: &HuffmanNode) -> String {
     let mut result = String::new();
+
+    if let HuffmanNode::Leaf { character, .. } = tree {
+        for _ in encoded.chars().filter(|&bit| bit == '0' || bit == '1') {
+            result.push(*character);
+        }
+        return result;
+    }
+
     let mut current = tree;
     let mut bits = encoded.chars();
 
@@ -215,6 +243,55 @@ pub fn decode(encoded: &str, tree: &HuffmanNode) -> String {
     result
 }
 
+/// A source of bits that can be consumed one at a time
+///
+/// This lets [`huffman_decode_stream`] decode from inputs that are not fully
+/// materialized in memory, such as a file read bit by bit.
+pub trait BitSource {
+    /// Returns the next bit, or `None` once the source is exhausted
+    fn next_bit(&mut self) -> Option<bool>;
+}
+
+/// Decodes bits pulled from a [`BitSource`] using a Huffman tree
+///
+/// Walks the tree one bit at a time (`false` = left, `true` = right) and
+/// emits a character each time a leaf is reached. Decoding stops when the
+/// source is exhausted; bits of an incomplete trailing codeword are dropped.
+/// A tree consisting of a single leaf emits its character once per bit.
+///
+/// # Arguments
+/// * `tree` - Root of the Huffman tree
+/// * `source` - The bit source to read from
+///
+/// # Returns
+/// Decoded text
+///
+/// # Complexity
+/// - Time: O(b) where b is the number of bits read
+pub fn huffman_decode_stream<S: BitSource>(tree: &HuffmanNode, source: &mut S) -> String {
+    let mut result = String::new();
+
+    if let HuffmanNode::Leaf { character, .. } = tree {
+        while source.next_bit().is_some() {
+            result.push(*character);
+        }
+        return result;
+    }
+
+    let mut current = tree;
+    while let Some(bit) = source.next_bit() {
+        if let HuffmanNode::Internal { left, right, .. } = current {
+            current = if bit { right } else { left };
+        }
+        if let HuffmanNode::Leaf { character, .. } = current {
+            result.push(*character);
+            current = tree;
+        }
+    }
+
+    result
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -233,6 +310,51 @@ mod tests {
         assert_eq!(tree.frequency(), 100);
     }
 
+    #[test]
+    fn test_equal_frequencies_give_reproducible_codes() {
+        let freqs = vec![
+            CharFreq::new('a', 3),
+            CharFreq::new('b', 1),
+            CharFreq::new('c', 1),
+            CharFreq::new('d', 3),
+            CharFreq::new('e', 1),
+            CharFreq::new('f', 1),
+            CharFreq::new('g', 2),
+        ];
+        let table = |codes: std::collections::HashMap<char, String>| {
+            let sorted: std::collections::BTreeMap<_, _> = codes.into_iter().collect();
+            format!("{sorted:?}")
+        };
+        let first = table(generate_codes(&build_huffman_tree(&freqs)));
+        let second = table(generate_codes(&build_huffman_tree(&freqs)));
+        assert_eq!(first.as_bytes(), second.as_bytes());
+
+        // b+c and e+f merge first (numbers 7 and 8). The weight-2 leaf g
+        // predates both and pairs with 7; 8 then pairs with a, which precedes
+        // d, and d joins the weight-4 subtree.
+        let codes = generate_codes(&build_huffman_tree(&freqs));
+        let expected = [
+            ('e', "000"),
+            ('f', "001"),
+            ('a', "01"),
+            ('d', "10"),
+            ('g', "110"),
+            ('b', "1110"),
+            ('c', "1111"),
+        ];
+        for (character, code) in expected {
+            assert_eq!(codes[&character], code, "code for {character}");
+        }
+
+        // With every frequency equal, leaves pair off in input order.
+        let uniform: Vec<_> = "wxyz".chars().map(|c| CharFreq::new(c, 1)).collect();
+        let codes = generate_codes(&build_huffman_tree(&uniform));
+        assert_eq!(
+            table(codes),
+            r#"{'w': "00", 'x': "01", 'y': "10", 'z': "11"}"#
+        );
+    }
+
     #[test]
     fn test_generate_codes() {
         let freqs = vec![
@@ -272,4 +394,73 @@ mod tests {
 
         assert_eq!(decoded, text);
     }
+
+    /// Bit source reading from an in-memory vector
+    struct VecBitSource {
+        bits: Vec<bool>,
+        position: usize,
+    }
+
+    impl BitSource for VecBitSource {
+        fn next_bit(&mut self) -> Option<bool> {
+            let bit = self.bits.get(self.position).copied();
+            self.position += 1;
+            bit
+        }
+    }
+
+    fn to_bits(encoded: &str) -> Vec<bool> {
+        encoded.chars().map(|c| c == '1').collect()
+    }
+
+    #[test]
+    fn test_single_symbol_round_trip() {
+        let tree = build_huffman_tree(&[CharFreq::new('a', 5)]);
+        let codes = generate_codes(&tree);
+        assert_eq!(codes.get(&'a').map(String::as_str), Some("0"));
+
+        let encoded = encode("aaaaa", &codes);
+        assert_eq!(encoded, "00000");
+        assert_eq!(decode(&encoded, &tree), "aaaaa");
+    }
+
+    #[test]
+    fn test_decode_stream_round_trip() {
+        let freqs = vec![
+            CharFreq::new('a', 45),
+            CharFreq::new('b', 13),
+            CharFreq::new('c', 12),
+            CharFreq::new('d', 16),
+            CharFreq::new('e', 9),
+            CharFreq::new('f', 5),
+        ];
+        let tree = build_huffman_tree(&freqs);
+        let codes = generate_codes(&tree);
+
+        let text = "deadbeefcafe";
+        let mut source = VecBitSource {
+            bits: to_bits(&encode(text, &codes)),
+            position: 0,
+        };
+        assert_eq!(huffman_decode_stream(&tree, &mut source), text);
+        assert_eq!(source.next_bit(), None);
+    }
+
+    #[test]
+    fn test_decode_stream_drops_partial_codeword() {
+        let freqs = vec![
+            CharFreq::new('a', 45),
+            CharFreq::new('b', 13),
+            CharFreq::new('c', 12),
+        ];
+        let tree = build_huffman_tree(&freqs);
+        let codes = generate_codes(&tree);
+
+        let mut bits = to_bits(&encode("ab", &codes));
+        let c_code = to_bits(&codes[&'c']);
+        bits.extend_from_slice(&c_code[..c_code.len() - 1]);
+
+        let mut source = VecBitSource { bits, position: 0 };
+        assert_eq!(huffman_decode_stream(&tree, &mut source), "ab");
+    }
 }
@@ -1,12 +1,15 @@
 //! Chapter 16: Greedy Algorithms
 //!
 //! This chapter covers greedy algorithms, which make locally optimal choices
-//! at each step with the hope of finding a global optimum.
+//! at each step with the hope of finding a global optimum. The greedy
+//! approximation for set cover from Section 35.3 is included as well.
 
 pub mod activity_selection;
 pub mod fractional_knapsack;
 pub mod huffman;
+pub mod set_cover;
 
 pub use activity_selection::*;
 pub use fractional_knapsack::*;
 pub use huffman::*;
+pub use set_cover::*;
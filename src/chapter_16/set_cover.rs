@@ -0,0 +1,128 @@
+//! Greedy Set Cover (Section 35.3)
+//!
+//! Given a universe of elements and a family of subsets whose union is the
+//! universe, find few subsets that still cover every element. The problem is
+//! NP-hard, but the greedy strategy of always taking the subset that covers
+//! the most uncovered elements comes within a factor of ln n + 1 of optimal.
+
+/// Approximates a minimum set cover greedily
+///
+/// This corresponds to GREEDY-SET-COVER from CLRS Section 35.3. Each round
+/// picks the set covering the most still-uncovered elements, preferring the
+/// lowest index on ties, until every element is covered.
+///
+/// # Arguments
+/// * `universe_size` - The universe is the elements `0..universe_size`
+/// * `sets` - The family of subsets, each listing its elements
+///
+/// # Returns
+/// The indices of the chosen sets in the order they were picked, or an empty
+/// vector if the sets together do not cover the universe (an empty universe
+/// is covered by no sets at all)
+///
+/// # Panics
+/// Panics if a set contains an element outside `0..universe_size`
+///
+/// # Complexity
+/// - Time: O(k · Σ|S|) where k is the number of sets chosen
+/// - Space: O(n + Σ|S|) for a universe of n elements
+///
+/// # Example
+/// ```
+/// use clrs::chapter_16::greedy_set_cover;
+/// let sets = vec![vec![0, 1, 3, 4], vec![0, 1, 2], vec![3, 4, 5]];
+/// // Greedy takes the largest set first and then needs both others,
+/// // although sets 1 and 2 alone would do.
+/// assert_eq!(greedy_set_cover(6, &sets), vec![0, 1, 2]);
+/// assert!(greedy_set_cover(7, &sets).is_empty());
+/// ```
+pub fn greedy_set_cover(universe_size: usize, sets: &[Vec<usize>]) -> Vec<usize> {
+    // Listing an element twice must not inflate a set's gain.
+    let sets: Vec<Vec<usize>> = sets
+        .iter()
+        .map(|set| {
+            let mut set = set.clone();
+            set.sort_unstable();
+            set.dedup();
+            set
+        })
+        .collect();
+
+    let mut covered = vec![false; universe_size];
+    for &x in sets.iter().flatten() {
+        assert!(x < universe_size, "element {} outside the universe", x);
+        covered[x] = true;
+    }
+    if covered.contains(&false) {
+        return Vec::new();
+    }
+
+    covered.fill(false);
+    let mut uncovered = universe_size;
+    let mut chosen = Vec::new();
+    while uncovered > 0 {
+        let mut best = 0;
+        let mut best_gain = 0;
+        for (i, set) in sets.iter().enumerate() {
+            let gain = set.iter().filter(|&&x| !covered[x]).count();
+            if gain > best_gain {
+                best = i;
+                best_gain = gain;
+            }
+        }
+
+        for &x in &sets[best] {
+            covered[x] = true;
+        }
+        uncovered -= best_gain;
+        chosen.push(best);
+    }
+    chosen
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn covers(universe_size: usize, sets: &[Vec<usize>], chosen: &[usize]) -> bool {
+        let mut covered = vec![false; universe_size];
+        for &i in chosen {
+            for &x in &sets[i] {
+                covered[x] = true;
+            }
+        }
+        covered.iter().all(|&c| c)
+    }
+
+    #[test]
+    fn test_greedy_set_cover_hand_computed() {
+        let sets = vec![
+            vec![0, 1, 2, 3, 4, 5],
+            vec![5, 6, 7, 8],
+            vec![0, 1, 6],
+            vec![8, 9],
+            vec![2, 3, 7, 9],
+        ];
+        // Set 0 covers six elements; of 6..=9, set 1 covers three; 9 is
+        // then covered by sets 3 and 4 alike, and the lower index wins.
+        let chosen = greedy_set_cover(10, &sets);
+        assert_eq!(chosen, vec![0, 1, 3]);
+        assert!(covers(10, &sets, &chosen));
+    }
+
+    #[test]
+    fn test_greedy_set_cover_edge_cases() {
+        assert!(greedy_set_cover(0, &[]).is_empty());
+        assert!(greedy_set_cover(3, &[vec![0, 1], vec![1]]).is_empty());
+
+        // Repeated elements do not inflate a set's gain.
+        let sets = vec![vec![0, 0, 0, 0], vec![0, 1, 2]];
+        assert_eq!(greedy_set_cover(3, &sets), vec![1]);
+    }
+
+    #[test]
+    #[should_panic(expected = "outside the universe")]
+    fn test_greedy_set_cover_rejects_foreign_elements() {
+        greedy_set_cover(2, &[vec![0, 2]]);
+    }
+}
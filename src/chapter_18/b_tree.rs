@@ -6,6 +6,60 @@
 //! the minimum degree (`t`) invariants.
 
 use std::cmp::Ordering;
+use std::fmt::{Display, Write};
+use std::sync::atomic::{AtomicU64, Ordering as AtomicOrdering};
+
+use crate::chapter_12::flat_bytes::{write_flag, write_len};
+use crate::chapter_12::{ByteReader, DeserializeError, FromBytes, SortedMap, ToBytes};
+
+/// Deepest node nesting [`BTree::deserialize`] accepts
+///
+/// Nodes are decoded recursively, so crafted input describing a long chain
+/// of nodes would otherwise overflow the stack. A valid B-tree of this height
+/// would hold more than 2^1024 keys.
+const MAX_DEPTH: usize = 1024;
+
+/// Tally of simulated disk accesses made by a B-tree
+///
+/// CLRS measures B-tree operations by the number of DISK-READ calls, treating
+/// each node as one disk page. The counter is atomic so that read-only
+/// operations such as [`BTree::search`] can record their accesses while the
+/// tree stays shareable across threads. The count is a statistic and orders
+/// nothing else, so relaxed ordering suffices.
+#[derive(Debug, Default)]
+pub struct PageCounter {
+    accesses: AtomicU64,
+}
+
+impl PageCounter {
+    /// Creates a counter with no accesses recorded
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Records one node access
+    pub fn record(&self) {
+        self.accesses.fetch_add(1, AtomicOrdering::Relaxed);
+    }
+
+    /// Returns the number of accesses recorded since the last reset
+    pub fn count(&self) -> u64 {
+        self.accesses.load(AtomicOrdering::Relaxed)
+    }
+
+    /// Sets the count back to zero
+    pub fn reset(&self) {
+        self.accesses.store(0, AtomicOrdering::Relaxed);
+    }
+}
+
+impl Clone for PageCounter {
+    fn clone(&self) -> Self {
+        Self {
+            accesses: AtomicU64::new(self.count()),
+        }
+    }
+}
 
 /// A single node in a B-tree
 #[derive(Debug, Clone)]
@@ -30,41 +84,47 @@ impl<K: Ord, V> BTreeNode<K, V> {
         self.keys.len() == 2 * min_degree - 1
     }
 
-    fn search(&self, key: &K) -> Option<&V> {
+    fn search(&self, key: &K, pages: &PageCounter) -> Option<&V> {
+        pages.record();
         match self.keys.binary_search(key) {
             Ok(idx) => Some(&self.values[idx]),
             Err(idx) => {
                 if self.leaf {
                     None
                 } else {
-                    self.children[idx].search(key)
+                    self.children[idx].search(key, pages)
                 }
             }
         }
     }
 
-    fn insert_non_full(&mut self, key: K, value: V, min_degree: usize) {
+    fn insert_non_full(
+        &mut self,
+        key: K,
+        value: V,
+        min_degree: usize,
+        pages: &PageCounter,
+    ) -> Option<V> {
+        pages.record();
         match self.keys.binary_search(&key) {
-            Ok(idx) => {
-                self.values[idx] = value;
-            }
+            Ok(idx) => Some(std::mem::replace(&mut self.values[idx], value)),
             Err(mut idx) => {
                 if self.leaf {
                     self.keys.insert(idx, key);
                     self.values.insert(idx, value);
+                    None
                 } else {
                     if self.children[idx].is_full(min_degree) {
                         self.split_child(idx, min_degree);
                         match self.keys[idx].cmp(&key) {
                             Ordering::Less => idx += 1,
                             Ordering::Equal => {
-                                self.values[idx] = value;
-                                return;
+                                return Some(std::mem::replace(&mut self.values[idx], value));
                             }
                             Ordering::Greater => {}
                         }
                     }
-                    self.children[idx].insert_non_full(key, value, min_degree);
+                    self.children[idx].insert_non_full(key, value, min_degree, pages)
                 }
             }
         }
@@ -96,52 +156,73 @@ impl<K: Ord, V> BTreeNode<K, V> {
         self.children.insert(idx + 1, new_child);
     }
 
-    fn delete(&mut self, key: &K, min_degree: usize) -> Option<V> {
+    fn delete(&mut self, key: &K, min_degree: usize, pages: &PageCounter) -> Option<V> {
+        pages.record();
         match self.keys.binary_search(key) {
             Ok(idx) => {
                 if self.leaf {
                     self.keys.remove(idx);
                     Some(self.values.remove(idx))
                 } else {
-                    self.delete_internal_key(idx, key, min_degree)
+                    self.delete_internal_key(idx, key, min_degree, pages)
                 }
             }
             Err(mut idx) => {
                 if self.leaf {
                     None
                 } else {
-                    idx = self.ensure_child_has_min_keys(idx, min_degree);
-                    self.children[idx].delete(key, min_degree)
+                    idx = self.ensure_child_has_min_keys(idx, min_degree, pages);
+                    self.children[idx].delete(key, min_degree, pages)
                 }
             }
         }
     }
 
-    fn delete_internal_key(&mut self, idx: usize, key: &K, min_degree: usize) -> Option<V> {
+    fn delete_internal_key(
+        &mut self,
+        idx: usize,
+        key: &K,
+        min_degree: usize,
+        pages: &PageCounter,
+    ) -> Option<V> {
         if self.children[idx].keys.len() >= min_degree {
-            let (pred_key, pred_value) = self.children[idx].extract_predecessor(min_degree);
+            let (pred_key, pred_value) = self.children[idx].extract_predecessor(min_degree, pages);
             let old_value = std::mem::replace(&mut self.values[idx], pred_value);
             self.keys[idx] = pred_key;
             Some(old_value)
-        } else if self.children[idx + 1].keys.len() >= min_degree {
-            let (succ_key, succ_value) = self.children[idx + 1].extract_successor(min_degree);
+        } else if Self::read_key_count(&self.children[idx + 1], pages) >= min_degree {
+            let (succ_key, succ_value) =
+                self.children[idx + 1].extract_successor(min_degree, pages);
             let old_value = std::mem::replace(&mut self.values[idx], succ_value);
             self.keys[idx] = succ_key;
             Some(old_value)
         } else {
             self.merge_children(idx);
-            self.children[idx].delete(key, min_degree)
+            self.children[idx].delete(key, min_degree, pages)
         }
     }
 
-    fn ensure_child_has_min_keys(&mut self, mut idx: usize, min_degree: usize) -> usize {
+    /// Reads a sibling's key count, which costs one page access
+    fn read_key_count(node: &BTreeNode<K, V>, pages: &PageCounter) -> usize {
+        pages.record();
+        node.keys.len()
+    }
+
+    fn ensure_child_has_min_keys(
+        &mut self,
+        mut idx: usize,
+        min_degree: usize,
+        pages: &PageCounter,
+    ) -> usize {
         if self.children[idx].keys.len() >= min_degree {
             return idx;
         }
 
-        if idx > 0 && self.children[idx - 1].keys.len() >= min_degree {
+        if idx > 0 && Self::read_key_count(&self.children[idx - 1], pages) >= min_degree {
             self.borrow_from_prev(idx);
-        } else if idx + 1 < self.children.len() && self.children[idx + 1].keys.len() >= min_degree {
+        } else if idx + 1 < self.children.len()
+            && Self::read_key_count(&self.children[idx + 1], pages) >= min_degree
+        {
             self.borrow_from_next(idx);
         } else if idx + 1 < self.children.len() {
             self.merge_children(idx);
@@ -219,28 +300,88 @@ impl<K: Ord, V> BTreeNode<K, V> {
         }
     }
 
-    fn extract_predecessor(&mut self, min_degree: usize) -> (K, V) {
+    fn extract_predecessor(&mut self, min_degree: usize, pages: &PageCounter) -> (K, V) {
+        pages.record();
         if self.leaf {
             let key = self.keys.pop().expect("predecessor from empty leaf");
             let value = self.values.pop().expect("predecessor from empty leaf");
             (key, value)
         } else {
-            let idx = self.ensure_child_has_min_keys(self.children.len() - 1, min_degree);
-            self.children[idx].extract_predecessor(min_degree)
+            let idx = self.ensure_child_has_min_keys(self.children.len() - 1, min_degree, pages);
+            self.children[idx].extract_predecessor(min_degree, pages)
         }
     }
 
-    fn extract_successor(&mut self, min_degree: usize) -> (K, V) {
+    fn extract_successor(&mut self, min_degree: usize, pages: &PageCounter) -> (K, V) {
+        pages.record();
         if self.leaf {
             let key = self.keys.remove(0);
             let value = self.values.remove(0);
             (key, value)
         } else {
-            let idx = self.ensure_child_has_min_keys(0, min_degree);
-            self.children[idx].extract_successor(min_degree)
+            let idx = self.ensure_child_has_min_keys(0, min_degree, pages);
+            self.children[idx].extract_successor(min_degree, pages)
         }
     }
 
+    /// Checks the subtree rooted at this node, whose keys must lie strictly
+    /// between `lower` and `upper`. Records the depth of the first leaf seen in
+    /// `leaf_depth` so that later leaves can be compared against it.
+    fn is_valid(
+        &self,
+        min_degree: usize,
+        is_root: bool,
+        lower: Option<&K>,
+        upper: Option<&K>,
+        depth: usize,
+        leaf_depth: &mut Option<usize>,
+    ) -> bool {
+        let key_count = self.keys.len();
+        let min_keys = if is_root { 1 } else { min_degree - 1 };
+        if key_count < min_keys || key_count > 2 * min_degree - 1 {
+            return false;
+        }
+        if self.values.len() != key_count || !self.keys.windows(2).all(|w| w[0] < w[1]) {
+            return false;
+        }
+        if lower.is_some_and(|low| self.keys[0] <= *low)
+            || upper.is_some_and(|high| self.keys[key_count - 1] >= *high)
+        {
+            return false;
+        }
+
+        if self.leaf {
+            if !self.children.is_empty() {
+                return false;
+            }
+            return *leaf_depth.get_or_insert(depth) == depth;
+        }
+
+        if self.children.len() != key_count + 1 {
+            return false;
+        }
+        self.children.iter().enumerate().all(|(i, child)| {
+            let child_lower = if i == 0 {
+                lower
+            } else {
+                Some(&self.keys[i - 1])
+            };
+            let child_upper = if i == key_count {
+                upper
+            } else {
+                Some(&self.keys[i])
+            };
+            child.is_valid(
+                min_degree,
+                false,
+                child_lower,
+                child_upper,
+                depth + 1,
+                leaf_depth,
+            )
+        })
+    }
+
     fn traverse<F>(&self, visitor: &mut F)
     where
         F: FnMut(&K, &V),
@@ -258,12 +399,23 @@ impl<K: Ord, V> BTreeNode<K, V> {
 }
 
 /// B-tree structure parameterized by key and value types
+///
+/// Every node that [`search`](Self::search), [`insert`](Self::insert) or
+/// [`delete`](Self::delete) reads is counted as one disk access, which
+/// [`disk_accesses`](Self::disk_accesses) reports.
 #[derive(Debug, Clone)]
 pub struct BTree<K: Ord, V> {
     pub root: Option<Box<BTreeNode<K, V>>>,
     pub min_degree: usize,
+    pages: PageCounter,
 }
 
+// Counting reads must not cost `BTree` its `Sync` impl.
+const _: fn() = || {
+    fn assert_sync<T: Sync>() {}
+    assert_sync::<BTree<u64, String>>();
+};
+
 impl<K: Ord, V> BTree<K, V> {
     /// Creates a new empty B-tree with the given minimum degree `t`
     ///
@@ -276,7 +428,90 @@ impl<K: Ord, V> BTree<K, V> {
         Self {
             root: None,
             min_degree,
+            pages: PageCounter::new(),
+        }
+    }
+
+    /// Builds a densely packed B-tree from key-value pairs sorted by key
+    ///
+    /// Leaves are filled to `2t - 1` keys from left to right with a separator
+    /// key between neighbouring leaves; the separators then form the next
+    /// level up, whose nodes are filled to `2t` children, and so on until a
+    /// single root remains. Only the last two nodes of a level may be less
+    /// than full, and they are rebalanced so that every non-root node keeps
+    /// at least `t - 1` keys.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `min_degree < 2` or if the keys are not strictly increasing.
+    ///
+    /// # Complexity
+    /// - Time: O(n) where n is the number of pairs
+    pub fn bulk_load(min_degree: usize, sorted_pairs: Vec<(K, V)>) -> Self {
+        let mut tree = Self::new(min_degree);
+        assert!(
+            sorted_pairs.windows(2).all(|w| w[0].0 < w[1].0),
+            "bulk_load requires strictly increasing keys"
+        );
+        if sorted_pairs.is_empty() {
+            return tree;
+        }
+
+        // Each leaf takes its keys plus the separator that follows it, so
+        // n pairs fill n + 1 slots of at most 2t per leaf.
+        let leaf_slots = Self::group_sizes(sorted_pairs.len() + 1, 2 * min_degree, min_degree);
+        let mut pairs = sorted_pairs.into_iter();
+        let mut nodes = Vec::with_capacity(leaf_slots.len());
+        let mut separators = Vec::with_capacity(leaf_slots.len());
+        for (i, &slots) in leaf_slots.iter().enumerate() {
+            let mut leaf = BTreeNode::new(true);
+            for (key, value) in pairs.by_ref().take(slots - 1) {
+                leaf.keys.push(key);
+                leaf.values.push(value);
+            }
+            nodes.push(Box::new(leaf));
+            if i + 1 < leaf_slots.len() {
+                separators.push(pairs.next().expect("separator must exist"));
+            }
+        }
+
+        while nodes.len() > 1 {
+            let group_sizes = Self::group_sizes(nodes.len(), 2 * min_degree, min_degree);
+            let mut children = nodes.into_iter();
+            let mut keys = separators.into_iter();
+            nodes = Vec::with_capacity(group_sizes.len());
+            separators = Vec::with_capacity(group_sizes.len());
+            for (i, &size) in group_sizes.iter().enumerate() {
+                let mut node = BTreeNode::new(false);
+                node.children.extend(children.by_ref().take(size));
+                for (key, value) in keys.by_ref().take(size - 1) {
+                    node.keys.push(key);
+                    node.values.push(value);
+                }
+                nodes.push(Box::new(node));
+                if i + 1 < group_sizes.len() {
+                    separators.push(keys.next().expect("separator must exist"));
+                }
+            }
+        }
+
+        tree.root = nodes.pop();
+        tree
+    }
+
+    /// Splits `total` units into groups of at most `cap`, all full except the
+    /// last two, which are evened out if the last would fall below `min`.
+    fn group_sizes(total: usize, cap: usize, min: usize) -> Vec<usize> {
+        let groups = total.div_ceil(cap);
+        let mut sizes = vec![cap; groups];
+        let last = total - (groups - 1) * cap;
+        sizes[groups - 1] = last;
+        if groups > 1 && last < min {
+            let combined = cap + last;
+            sizes[groups - 2] = combined.div_ceil(2);
+            sizes[groups - 1] = combined / 2;
         }
+        sizes
     }
 
     /// Returns the minimum degree `t` of the tree
@@ -290,8 +525,39 @@ impl<K: Ord, V> BTree<K, V> {
     }
 
     /// Searches for `key` in the B-tree, returning a reference to the value if found
+    ///
+    /// Reads one node per level, so a search in a tree of height h costs
+    /// exactly h + 1 disk accesses when `key` is in a leaf or absent. Like
+    /// B-TREE-SEARCH, it stops at the node holding `key`, so a key found in
+    /// an internal node costs fewer reads; this deliberately departs from a
+    /// flat h + 1 per search.
     pub fn search(&self, key: &K) -> Option<&V> {
-        self.root.as_ref().and_then(|node| node.search(key))
+        self.root
+            .as_ref()
+            .and_then(|node| node.search(key, &self.pages))
+    }
+
+    /// Returns the number of nodes read by `search`, `insert` and `delete`
+    /// since the tree was created or the count was last reset
+    ///
+    /// # Example
+    /// ```
+    /// use clrs::chapter_18::BTree;
+    /// let mut tree = BTree::new(2);
+    /// for key in 1..=4 {
+    ///     tree.insert(key, ());
+    /// }
+    /// tree.reset_disk_accesses();
+    /// assert!(tree.contains(&4));
+    /// assert_eq!(tree.disk_accesses(), 2);
+    /// ```
+    pub fn disk_accesses(&self) -> u64 {
+        self.pages.count()
+    }
+
+    /// Sets the disk access count back to zero
+    pub fn reset_disk_accesses(&self) {
+        self.pages.reset();
     }
 
     /// Returns `true` if the B-tree contains `key`
@@ -301,14 +567,15 @@ impl<K: Ord, V> BTree<K, V> {
 
     /// Inserts the key-value pair into the B-tree
     ///
-    /// If the key already exists, its value is updated.
-    pub fn insert(&mut self, key: K, value: V) {
+    /// If the key already exists, its value is updated and the previous value
+    /// is returned.
+    pub fn insert(&mut self, key: K, value: V) -> Option<V> {
         if self.root.is_none() {
             let mut root = BTreeNode::new(true);
             root.keys.push(key);
             root.values.push(value);
             self.root = Some(Box::new(root));
-            return;
+            return None;
         }
 
         let min_degree = self.min_degree;
@@ -318,19 +585,51 @@ impl<K: Ord, V> BTree<K, V> {
             let mut new_root = BTreeNode::new(false);
             new_root.children.push(root);
             new_root.split_child(0, min_degree);
-            new_root.insert_non_full(key, value, min_degree);
+            let previous = new_root.insert_non_full(key, value, min_degree, &self.pages);
             self.root = Some(Box::new(new_root));
+            previous
         } else {
-            root.insert_non_full(key, value, min_degree);
+            let previous = root.insert_non_full(key, value, min_degree, &self.pages);
             self.root = Some(root);
+            previous
+        }
+    }
+
+    /// Returns the smallest key and its value, found at the start of the
+    /// leftmost leaf
+    pub fn minimum(&self) -> Option<(&K, &V)> {
+        let mut node = self.root.as_ref()?;
+        while !node.leaf {
+            node = &node.children[0];
+        }
+        Some((node.keys.first()?, node.values.first()?))
+    }
+
+    /// Returns the largest key and its value, found at the end of the
+    /// rightmost leaf
+    pub fn maximum(&self) -> Option<(&K, &V)> {
+        let mut node = self.root.as_ref()?;
+        while !node.leaf {
+            node = node.children.last()?;
         }
+        Some((node.keys.last()?, node.values.last()?))
+    }
+
+    /// Returns the number of keys stored in the tree
+    ///
+    /// # Complexity
+    /// - Time: O(n) where n is the number of keys
+    pub fn len(&self) -> usize {
+        let mut count = 0;
+        self.traverse_inorder(|_, _| count += 1);
+        count
     }
 
     /// Deletes `key` from the B-tree, returning the stored value if it existed
     pub fn delete(&mut self, key: &K) -> Option<V> {
         let mut root = self.root.take()?;
 
-        let result = root.delete(key, self.min_degree);
+        let result = root.delete(key, self.min_degree, &self.pages);
 
         if root.keys.is_empty() {
             if root.leaf {
@@ -345,6 +644,22 @@ impl<K: Ord, V> BTree<K, V> {
         result
     }
 
+    /// Checks that the tree satisfies the B-tree properties of CLRS Section 18.1
+    ///
+    /// Every non-root node must hold between `t - 1` and `2t - 1` keys, a
+    /// nonempty tree's root at least one, all leaves must sit at the same
+    /// depth, and the keys of each node must be sorted and separate the key
+    /// ranges of its children.
+    ///
+    /// # Complexity
+    /// - Time: O(n) where n is the number of keys
+    pub fn is_valid(&self) -> bool {
+        match &self.root {
+            None => true,
+            Some(root) => root.is_valid(self.min_degree, true, None, None, 0, &mut None),
+        }
+    }
+
     /// Applies `visitor` to all key-value pairs in sorted (in-order) order
     pub fn traverse_inorder<F>(&self, mut visitor: F)
     where
@@ -356,6 +671,191 @@ impl<K: Ord, V> BTree<K, V> {
     }
 }
 
+impl<K: Ord + Display, V> BTree<K, V> {
+    /// Draws the tree sideways, one node per line
+    ///
+    /// Each node is shown as its key vector, such as `[4, 7]`. The root is at
+    /// the left margin and each level is indented four more spaces. The
+    /// children of a node with `c` children are drawn last to first, with the
+    /// node itself written after the top `c / 2` of them, so turning the
+    /// output a quarter turn clockwise shows the tree with its root at the
+    /// top and the children in key order.
+    ///
+    /// # Example
+    /// ```
+    /// use clrs::chapter_18::BTree;
+    /// let mut tree = BTree::new(2);
+    /// for key in 1..=4 {
+    ///     tree.insert(key, ());
+    /// }
+    /// assert_eq!(tree.to_ascii(), "    [3, 4]\n[2]\n    [1]\n");
+    /// ```
+    pub fn to_ascii(&self) -> String {
+        let mut out = String::new();
+        if let Some(root) = &self.root {
+            Self::draw_node(root, 0, &mut out);
+        }
+        out
+    }
+
+    fn draw_node(node: &BTreeNode<K, V>, depth: usize, out: &mut String) {
+        let upper = node.children.len() / 2;
+        for child in node.children.iter().rev().take(upper) {
+            Self::draw_node(child, depth + 1, out);
+        }
+        let keys: Vec<String> = node.keys.iter().map(|k| k.to_string()).collect();
+        writeln!(
+            out,
+            "{:indent$}[{}]",
+            "",
+            keys.join(", "),
+            indent = 4 * depth
+        )
+        .unwrap();
+        for child in node.children.iter().rev().skip(upper) {
+            Self::draw_node(child, depth + 1, out);
+        }
+    }
+}
+
+impl<K: Ord + ToBytes, V: ToBytes> BTree<K, V> {
+    /// Writes the tree to a flat byte buffer
+    ///
+    /// The buffer holds the minimum degree and a presence byte, then the
+    /// nodes in preorder. Each node is a leaf byte and a key count followed
+    /// by its key-value pairs and, for an internal node, its children. The
+    /// node layout is kept, so [`deserialize`](Self::deserialize) rebuilds an
+    /// identical tree.
+    ///
+    /// # Complexity
+    /// - Time: O(n) where n is the number of keys
+    pub fn serialize(&self) -> Vec<u8> {
+        let mut out = Vec::new();
+        write_len(self.min_degree, &mut out);
+        write_flag(self.root.is_some(), &mut out);
+        if let Some(root) = &self.root {
+            Self::serialize_node(root, &mut out);
+        }
+        out
+    }
+
+    fn serialize_node(node: &BTreeNode<K, V>, out: &mut Vec<u8>) {
+        write_flag(node.leaf, out);
+        write_len(node.keys.len(), out);
+        for (key, value) in node.keys.iter().zip(&node.values) {
+            key.write_bytes(out);
+            value.write_bytes(out);
+        }
+        for child in &node.children {
+            Self::serialize_node(child, out);
+        }
+    }
+}
+
+impl<K: Ord + FromBytes, V: FromBytes> BTree<K, V> {
+    /// Rebuilds a tree written by [`serialize`](Self::serialize)
+    ///
+    /// The disk access count of the new tree starts at zero.
+    ///
+    /// # Errors
+    /// Returns a [`DeserializeError`] if the bytes are truncated, contain
+    /// extra data after the tree, give a minimum degree too large for the
+    /// node size 2t - 1 to be computed, nest nodes more than 1024 levels
+    /// deep, or describe a tree that breaks the B-tree properties checked by
+    /// [`is_valid`](Self::is_valid)
+    ///
+    /// # Example
+    /// ```
+    /// use clrs::chapter_18::BTree;
+    /// let mut tree = BTree::new(2);
+    /// for key in 0u32..20 {
+    ///     tree.insert(key, key * key);
+    /// }
+    /// let copy = BTree::<u32, u32>::deserialize(&tree.serialize()).unwrap();
+    /// assert_eq!(copy.search(&7), Some(&49));
+    /// ```
+    pub fn deserialize(bytes: &[u8]) -> Result<Self, DeserializeError> {
+        let mut reader = ByteReader::new(bytes);
+        let min_degree = reader.read_len()?;
+        if min_degree < 2 {
+            return Err(DeserializeError::Malformed("minimum degree is below 2"));
+        }
+        if min_degree.checked_mul(2).is_none() {
+            return Err(DeserializeError::Malformed("minimum degree is too large"));
+        }
+        let mut tree = Self::new(min_degree);
+        if reader.read_flag()? {
+            tree.root = Some(Box::new(Self::deserialize_node(
+                &mut reader,
+                min_degree,
+                0,
+            )?));
+        }
+        reader.finish()?;
+        if !tree.is_valid() {
+            return Err(DeserializeError::Malformed("not a valid B-tree"));
+        }
+        Ok(tree)
+    }
+
+    fn deserialize_node(
+        reader: &mut ByteReader<'_>,
+        min_degree: usize,
+        depth: usize,
+    ) -> Result<BTreeNode<K, V>, DeserializeError> {
+        if depth >= MAX_DEPTH {
+            return Err(DeserializeError::Malformed("tree is nested too deeply"));
+        }
+        let mut node = BTreeNode::new(reader.read_flag()?);
+        let key_count = reader.read_len()?;
+        // Checked here so a corrupt count cannot trigger a huge allocation.
+        if key_count > 2 * min_degree - 1 {
+            return Err(DeserializeError::Malformed("node holds too many keys"));
+        }
+        for _ in 0..key_count {
+            node.keys.push(K::read_bytes(reader)?);
+            node.values.push(V::read_bytes(reader)?);
+        }
+        if !node.leaf {
+            for _ in 0..=key_count {
+                let child = Self::deserialize_node(reader, min_degree, depth + 1)?;
+                node.children.push(Box::new(child));
+            }
+        }
+        Ok(node)
+    }
+}
+
+impl<K: Ord, V> SortedMap<K, V> for BTree<K, V> {
+    fn insert(&mut self, key: K, value: V) -> Option<V> {
+        BTree::insert(self, key, value)
+    }
+
+    fn get(&self, key: &K) -> Option<&V> {
+        self.search(key)
+    }
+
+    fn remove(&mut self, key: &K) -> Option<V> {
+        self.delete(key)
+    }
+
+    fn min(&self) -> Option<(&K, &V)> {
+        self.minimum()
+    }
+
+    fn max(&self) -> Option<(&K, &V)> {
+        self.maximum()
+    }
+
+    fn len(&self) -> usize {
+        BTree::len(self)
+    }
+
+    fn is_empty(&self) -> bool {
+        BTree::is_empty(self)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -434,4 +934,297 @@ mod tests {
         sorted.sort();
         assert_eq!(collected, sorted);
     }
+
+    /// Returns (node count, total keys, leaf depths) and checks that every
+    /// non-root node has between `t - 1` and `2t - 1` keys.
+    fn node_stats<K: Ord, V>(tree: &BTree<K, V>) -> (usize, usize, Vec<usize>) {
+        let t = tree.min_degree;
+        let mut stats = (0, 0, Vec::new());
+        let mut stack = Vec::new();
+        if let Some(root) = &tree.root {
+            stack.push((root.as_ref(), 0, true));
+        }
+        while let Some((node, depth, is_root)) = stack.pop() {
+            assert!(node.keys.len() < 2 * t);
+            assert!(is_root || node.keys.len() >= t - 1);
+            stats.0 += 1;
+            stats.1 += node.keys.len();
+            if node.leaf {
+                stats.2.push(depth);
+            } else {
+                assert_eq!(node.children.len(), node.keys.len() + 1);
+                for child in &node.children {
+                    stack.push((child.as_ref(), depth + 1, false));
+                }
+            }
+        }
+        stats
+    }
+
+    #[test]
+    fn test_btree_bulk_load() {
+        let t = 3;
+        let pairs: Vec<(i32, i32)> = (0..10000).map(|i| (i, i * 2)).collect();
+        let tree = BTree::bulk_load(t, pairs);
+
+        for i in 0..10000 {
+            assert_eq!(tree.search(&i), Some(&(i * 2)));
+        }
+        assert_eq!(tree.search(&10000), None);
+
+        let mut collected = Vec::new();
+        tree.traverse_inorder(|k, _| collected.push(*k));
+        assert_eq!(collected, (0..10000).collect::<Vec<_>>());
+
+        assert!(tree.is_valid());
+        let (nodes, keys, leaf_depths) = node_stats(&tree);
+        assert_eq!(keys, 10000);
+        assert!(leaf_depths.iter().all(|&d| d == leaf_depths[0]));
+        let occupancy = keys as f64 / (nodes * (2 * t - 1)) as f64;
+        assert!(occupancy > 0.9, "occupancy was {}", occupancy);
+    }
+
+    #[test]
+    fn test_btree_bulk_load_small_sizes() {
+        for t in 2..5 {
+            for n in 0..200 {
+                let pairs: Vec<(i32, ())> = (0..n).map(|i| (i, ())).collect();
+                let mut tree = BTree::bulk_load(t, pairs);
+                assert!(tree.is_valid());
+                let (_, keys, leaf_depths) = node_stats(&tree);
+                assert_eq!(keys, n as usize);
+                assert!(leaf_depths.iter().all(|&d| d == leaf_depths[0]));
+
+                // The loaded tree must remain a valid target for updates.
+                tree.insert(-1, ());
+                tree.delete(&0);
+                assert!(tree.contains(&-1));
+                assert!(!tree.contains(&0));
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "strictly increasing")]
+    fn test_btree_bulk_load_rejects_unsorted() {
+        BTree::bulk_load(2, vec![(1, ()), (3, ()), (2, ())]);
+    }
+
+    #[test]
+    fn test_btree_is_valid_under_random_operations() {
+        use rand::Rng;
+        use std::collections::BTreeSet;
+
+        let mut rng = rand::thread_rng();
+        let mut tree: BTree<u32, u32> = BTree::new(3);
+        let mut present = BTreeSet::new();
+        assert!(tree.is_valid());
+
+        for _ in 0..5000 {
+            let key = rng.gen_range(0..400);
+            if rng.gen_bool(0.55) {
+                tree.insert(key, key);
+                present.insert(key);
+            } else {
+                assert_eq!(tree.delete(&key).is_some(), present.remove(&key));
+            }
+            assert!(tree.is_valid());
+        }
+
+        let mut collected = Vec::new();
+        tree.traverse_inorder(|k, _| collected.push(*k));
+        assert_eq!(collected, present.into_iter().collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_btree_is_valid_rejects_broken_trees() {
+        let mut tree: BTree<i32, i32> = BTree::new(2);
+        for i in 0..20 {
+            tree.insert(i, i);
+        }
+        assert!(tree.is_valid());
+
+        // A leaf key that no longer fits between its parent's separators.
+        let mut misplaced = tree.clone();
+        let mut node = misplaced.root.as_mut().unwrap();
+        while !node.leaf {
+            node = &mut node.children[0];
+        }
+        *node.keys.last_mut().unwrap() = 1000;
+        assert!(!misplaced.is_valid());
+
+        // A non-root node with fewer than t - 1 keys.
+        let mut underfull = tree.clone();
+        let mut node = underfull.root.as_mut().unwrap();
+        while !node.leaf {
+            node = &mut node.children[0];
+        }
+        node.keys.clear();
+        node.values.clear();
+        assert!(!underfull.is_valid());
+    }
+
+    #[test]
+    fn test_btree_to_ascii() {
+        let mut tree = BTree::new(2);
+        for key in 1..=10 {
+            tree.insert(key, ());
+        }
+        let expected = "
+        [9, 10]
+    [6, 8]
+        [7]
+        [5]
+[4]
+        [3]
+    [2]
+        [1]
+";
+        assert_eq!(tree.to_ascii(), expected.strip_prefix('\n').unwrap());
+        assert_eq!(BTree::<i32, ()>::new(3).to_ascii(), "");
+    }
+
+    #[test]
+    fn test_btree_search_reads_one_page_per_level() {
+        let mut tree = BTree::new(100);
+        for key in 0..100_000 {
+            tree.insert(key, key);
+        }
+        let mut height = 0;
+        let mut node = tree.root.as_ref().unwrap();
+        while !node.leaf {
+            node = &node.children[0];
+            height += 1;
+        }
+        assert_eq!(height, 2);
+
+        tree.reset_disk_accesses();
+        assert_eq!(tree.disk_accesses(), 0);
+        assert_eq!(tree.search(&100_000), None);
+        assert_eq!(tree.disk_accesses(), height + 1);
+
+        let leaf_key = tree.minimum().map(|(k, _)| *k).unwrap();
+        tree.reset_disk_accesses();
+        assert_eq!(tree.search(&leaf_key), Some(&leaf_key));
+        assert_eq!(tree.disk_accesses(), 3);
+
+        // The search stops where the key is, so a key in the root costs a
+        // single read rather than h + 1.
+        let root_key = tree.root.as_ref().unwrap().keys[0];
+        tree.reset_disk_accesses();
+        assert!(tree.contains(&root_key));
+        assert_eq!(tree.disk_accesses(), 1);
+
+        // Searches from several threads all land in the shared count.
+        tree.reset_disk_accesses();
+        std::thread::scope(|scope| {
+            for _ in 0..4 {
+                scope.spawn(|| assert_eq!(tree.search(&100_000), None));
+            }
+        });
+        assert_eq!(tree.disk_accesses(), 4 * (height + 1));
+    }
+
+    #[test]
+    fn test_btree_counts_insert_and_delete_accesses() {
+        let mut tree = BTree::new(3);
+        for key in 0..200 {
+            tree.insert(key, ());
+        }
+        let height = {
+            let mut height = 0;
+            let mut node = tree.root.as_ref().unwrap();
+            while !node.leaf {
+                node = &node.children[0];
+                height += 1;
+            }
+            height
+        };
+
+        tree.reset_disk_accesses();
+        tree.insert(1000, ());
+        assert!(tree.disk_accesses() > height);
+
+        // Deletion may also read a sibling at each level.
+        tree.reset_disk_accesses();
+        assert_eq!(tree.delete(&0), Some(()));
+        let accesses = tree.disk_accesses();
+        assert!(
+            accesses > height && accesses <= 2 * (height + 1),
+            "{accesses}"
+        );
+        assert!(tree.is_valid());
+    }
+
+    #[test]
+    fn test_btree_serialize_round_trip() {
+        let mut tree = BTree::new(3);
+        for key in (0..500u32).map(|k| (k * 7919) % 1000) {
+            tree.insert(key, format!("v{key}"));
+        }
+        for key in (0..1000).step_by(3) {
+            tree.delete(&key);
+        }
+        let bytes = tree.serialize();
+        let copy = BTree::<u32, String>::deserialize(&bytes).unwrap();
+
+        assert_eq!(copy.min_degree(), 3);
+        assert_eq!(copy.to_ascii(), tree.to_ascii());
+        assert_eq!(copy.serialize(), bytes);
+        for key in 0..1000 {
+            assert_eq!(copy.search(&key), tree.search(&key));
+        }
+
+        let empty: BTree<u8, u8> = BTree::new(2);
+        let copy = BTree::<u8, u8>::deserialize(&empty.serialize()).unwrap();
+        assert!(copy.is_empty());
+    }
+
+    #[test]
+    fn test_btree_deserialize_rejects_bad_input() {
+        let mut tree = BTree::new(2);
+        for key in 0u8..10 {
+            tree.insert(key, key);
+        }
+        let bytes = tree.serialize();
+        assert_eq!(
+            BTree::<u8, u8>::deserialize(&bytes[..bytes.len() - 1]).unwrap_err(),
+            DeserializeError::UnexpectedEnd
+        );
+
+        let mut degree_one = bytes.clone();
+        degree_one[0] = 1;
+        assert_eq!(
+            BTree::<u8, u8>::deserialize(&degree_one).unwrap_err(),
+            DeserializeError::Malformed("minimum degree is below 2")
+        );
+
+        let mut huge_degree = bytes.clone();
+        huge_degree[..8].copy_from_slice(&(1u64 << 63).to_le_bytes());
+        assert_eq!(
+            BTree::<u8, u8>::deserialize(&huge_degree).unwrap_err(),
+            DeserializeError::Malformed("minimum degree is too large")
+        );
+
+        // Internal nodes with no keys and a single child each, one level
+        // deeper than allowed.
+        let mut chain = bytes[..9].to_vec();
+        for _ in 0..=MAX_DEPTH {
+            chain.push(0);
+            chain.extend_from_slice(&0u64.to_le_bytes());
+        }
+        assert_eq!(
+            BTree::<u8, u8>::deserialize(&chain).unwrap_err(),
+            DeserializeError::Malformed("tree is nested too deeply")
+        );
+
+        // After the degree, presence byte, leaf byte and key count, the root's
+        // first key is the byte at offset 18; 200 is larger than any key below.
+        let mut unordered = bytes;
+        unordered[18] = 200;
+        assert_eq!(
+            BTree::<u8, u8>::deserialize(&unordered).unwrap_err(),
+            DeserializeError::Malformed("not a valid B-tree")
+        );
+    }
 }
@@ -8,6 +8,7 @@
 //! and decreasing a key.
 
 use std::cell::RefCell;
+use std::collections::HashSet;
 use std::fmt::Debug;
 use std::rc::{Rc, Weak};
 
@@ -215,6 +216,201 @@ impl<K: Ord + Clone, V> FibonacciHeap<K, V> {
         }
     }
 
+    /// Returns the keys on the root list, starting at the minimum and
+    /// following right-sibling links.
+    pub fn root_keys(&self) -> Vec<K> {
+        match &self.min {
+            Some(min_node) => Self::list_nodes(min_node)
+                .iter()
+                .map(|node| node.borrow().key.clone())
+                .collect(),
+            None => Vec::new(),
+        }
+    }
+
+    /// Renders the forest as a Graphviz DOT digraph.
+    ///
+    /// Each node is labelled with its key and degree; marked nodes are
+    /// filled grey and the minimum root is drawn with a double border.
+    /// Edges run from parent to child, and roots are placed on one rank.
+    pub fn to_dot(&self) -> String
+    where
+        K: Debug,
+    {
+        let mut out = String::from("digraph FibonacciHeap {\n    node [shape=circle];\n");
+        if let Some(min_node) = &self.min {
+            let mut next_id = 0;
+            let mut root_ids = Vec::new();
+            let mut edges = Vec::new();
+            let mut stack = Vec::new();
+
+            for root in Self::list_nodes(min_node) {
+                root_ids.push(next_id);
+                stack.push((root, next_id));
+                next_id += 1;
+            }
+
+            while let Some((node, id)) = stack.pop() {
+                let node_ref = node.borrow();
+                let label = format!("{:?}", node_ref.key)
+                    .replace('\\', "\\\\")
+                    .replace('"', "\\\"");
+                let mut attrs = format!("label=\"{}\\nd={}\"", label, node_ref.degree);
+                if node_ref.mark {
+                    attrs.push_str(", style=filled, fillcolor=lightgrey");
+                }
+                if Rc::ptr_eq(&node, min_node) {
+                    attrs.push_str(", peripheries=2");
+                }
+                out.push_str(&format!("    n{} [{}];\n", id, attrs));
+
+                if let Some(child) = &node_ref.child {
+                    for child in Self::list_nodes(child) {
+                        edges.push((id, next_id));
+                        stack.push((child, next_id));
+                        next_id += 1;
+                    }
+                }
+            }
+
+            for (parent, child) in edges {
+                out.push_str(&format!("    n{} -> n{};\n", parent, child));
+            }
+            let roots: Vec<String> = root_ids.iter().map(|id| format!("n{}", id)).collect();
+            out.push_str(&format!("    {{ rank=same; {}; }}\n", roots.join("; ")));
+        }
+        out.push_str("}\n");
+        out
+    }
+
+    /// Collects the nodes of the circular list containing `start`.
+    fn list_nodes(start: &NodeRef<K, V>) -> Vec<NodeRef<K, V>> {
+        let mut nodes = vec![start.clone()];
+        let mut current = start.borrow().right.as_ref().unwrap().clone();
+        while !Rc::ptr_eq(&current, start) {
+            nodes.push(current.clone());
+            let next = current.borrow().right.as_ref().unwrap().clone();
+            current = next;
+        }
+        nodes
+    }
+
+    /// Checks the structural invariants of the heap.
+    ///
+    /// Walks the root list and every child list, verifying that
+    /// - each circular list is doubly linked consistently (`x.left.right == x`),
+    /// - every child points back to its parent and roots have no parent,
+    /// - every node's `degree` equals the length of its child list,
+    /// - keys are heap-ordered and `min` points at a minimum root,
+    /// - no node is reachable twice, and
+    /// - the number of reachable nodes equals `len()`.
+    ///
+    /// Intended for tests that exercise long operation sequences; runs in
+    /// O(n) time.
+    pub fn validate(&self) -> Result<(), String> {
+        let Some(min_node) = &self.min else {
+            return if self.total_nodes == 0 {
+                Ok(())
+            } else {
+                Err(format!(
+                    "heap has no minimum but records {} nodes",
+                    self.total_nodes
+                ))
+            };
+        };
+
+        let mut seen = HashSet::new();
+        let roots = Self::validate_list(min_node, None, &mut seen)?;
+        for root in &roots {
+            if root.borrow().key < min_node.borrow().key {
+                return Err("min does not point at a minimum root".to_string());
+            }
+        }
+
+        let mut stack = roots;
+        while let Some(node) = stack.pop() {
+            let node_ref = node.borrow();
+            let children = match &node_ref.child {
+                Some(child) => Self::validate_list(child, Some(&node), &mut seen)?,
+                None => Vec::new(),
+            };
+            if children.len() != node_ref.degree {
+                return Err(format!(
+                    "node degree is {} but it has {} children",
+                    node_ref.degree,
+                    children.len()
+                ));
+            }
+            for child in &children {
+                if child.borrow().key < node_ref.key {
+                    return Err("child key is smaller than its parent key".to_string());
+                }
+            }
+            stack.extend(children);
+        }
+
+        if seen.len() != self.total_nodes {
+            return Err(format!(
+                "found {} nodes but total_nodes is {}",
+                seen.len(),
+                self.total_nodes
+            ));
+        }
+        Ok(())
+    }
+
+    /// Walks the circular list containing `start`, checking sibling links and
+    /// parent pointers, and returns the nodes of the list in traversal order.
+    fn validate_list(
+        start: &NodeRef<K, V>,
+        parent: Option<&NodeRef<K, V>>,
+        seen: &mut HashSet<*const RefCell<FibNode<K, V>>>,
+    ) -> Result<Vec<NodeRef<K, V>>, String> {
+        let mut nodes = Vec::new();
+        let mut current = start.clone();
+        loop {
+            if !seen.insert(Rc::as_ptr(&current)) {
+                return Err("node appears twice in the heap".to_string());
+            }
+
+            let next = {
+                let node_ref = current.borrow();
+                let (Some(left), Some(right)) = (&node_ref.left, &node_ref.right) else {
+                    return Err("node is missing a sibling link".to_string());
+                };
+                let left_ok = left
+                    .borrow()
+                    .right
+                    .as_ref()
+                    .is_some_and(|r| Rc::ptr_eq(r, &current));
+                let right_ok = right
+                    .borrow()
+                    .left
+                    .as_ref()
+                    .is_some_and(|l| Rc::ptr_eq(l, &current));
+                if !left_ok || !right_ok {
+                    return Err("inconsistent left/right sibling links".to_string());
+                }
+
+                let actual_parent = node_ref.parent.as_ref().and_then(|weak| weak.upgrade());
+                match (parent, actual_parent) {
+                    (None, None) => {}
+                    (Some(expected), Some(actual)) if Rc::ptr_eq(expected, &actual) => {}
+                    (None, Some(_)) => return Err("root node has a parent".to_string()),
+                    _ => return Err("child does not point back to its parent".to_string()),
+                }
+                right.clone()
+            };
+
+            nodes.push(current);
+            if Rc::ptr_eq(&next, start) {
+                break;
+            }
+            current = next;
+        }
+        Ok(nodes)
+    }
+
     fn add_to_root_list(&mut self, node: NodeRef<K, V>) {
         {
             let mut node_mut = node.borrow_mut();
@@ -461,14 +657,19 @@ impl<K: Ord + Clone, V> FibonacciHeap<K, V> {
     }
 
     fn cascading_cut(&mut self, node: NodeRef<K, V>) {
-        if let Some(parent_weak) = node.borrow().parent.clone() {
-            if let Some(parent) = parent_weak.upgrade() {
-                if !node.borrow().mark {
-                    node.borrow_mut().mark = true;
-                } else {
-                    self.cut(node.clone(), parent.clone());
-                    self.cascading_cut(parent);
-                }
+        // Bind the parent first so no borrow of `node` outlives this statement.
+        let parent = node
+            .borrow()
+            .parent
+            .as_ref()
+            .and_then(|weak| weak.upgrade());
+        if let Some(parent) = parent {
+            let marked = node.borrow().mark;
+            if !marked {
+                node.borrow_mut().mark = true;
+            } else {
+                self.cut(node.clone(), parent.clone());
+                self.cascading_cut(parent);
             }
         }
     }
@@ -479,9 +680,31 @@ impl<K: Ord + Clone, V> FibonacciHeap<K, V> {
     }
 }
 
+impl<K: Ord + Clone, V> Extend<(K, V)> for FibonacciHeap<K, V> {
+    /// Adds every pair to the root list with `insert`, discarding the handles.
+    ///
+    /// No consolidation happens here; the roots are only linked into trees by
+    /// the next `extract_min`.
+    fn extend<I: IntoIterator<Item = (K, V)>>(&mut self, iter: I) {
+        for (key, value) in iter {
+            self.insert(key, value);
+        }
+    }
+}
+
+impl<K: Ord + Clone, V> FromIterator<(K, V)> for FibonacciHeap<K, V> {
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        let mut heap = Self::new();
+        heap.extend(iter);
+        heap
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use rand::seq::SliceRandom;
+    use rand::Rng;
 
     fn collect_sorted<K: Ord + Clone, V>(mut heap: FibonacciHeap<K, V>) -> Vec<K> {
         let mut keys = Vec::new();
@@ -542,4 +765,127 @@ mod tests {
         let keys = collect_sorted(union_heap);
         assert_eq!(keys, vec![2, 5, 8, 9]);
     }
+
+    #[test]
+    fn test_validate_empty_and_small() {
+        let mut heap: FibonacciHeap<i32, ()> = FibonacciHeap::new();
+        assert_eq!(heap.validate(), Ok(()));
+
+        for key in [4, 2, 9, 1] {
+            heap.insert(key, ());
+            assert_eq!(heap.validate(), Ok(()));
+        }
+        heap.extract_min();
+        assert_eq!(heap.validate(), Ok(()));
+    }
+
+    #[test]
+    fn test_validate_detects_bad_count() {
+        let mut heap = FibonacciHeap::new();
+        heap.insert(1, ());
+        heap.insert(2, ());
+        heap.total_nodes = 3;
+        assert!(heap.validate().is_err());
+    }
+
+    #[test]
+    fn test_validate_random_operations() {
+        let mut rng = rand::thread_rng();
+        let mut heap = FibonacciHeap::new();
+        let mut live: Vec<(FibNodeHandle<i64, usize>, i64, usize)> = Vec::new();
+        let mut next_id = 0;
+
+        for _ in 0..3000 {
+            match rng.gen_range(0..10) {
+                0..=4 => {
+                    let key = rng.gen_range(0..1000);
+                    live.push((heap.insert(key, next_id), key, next_id));
+                    next_id += 1;
+                }
+                5..=6 => {
+                    if let Some((key, id)) = heap.extract_min() {
+                        let min_key = live.iter().map(|(_, k, _)| *k).min().unwrap();
+                        assert_eq!(key, min_key);
+                        let pos = live.iter().position(|(_, _, i)| *i == id).unwrap();
+                        live.swap_remove(pos);
+                    }
+                }
+                _ => {
+                    if !live.is_empty() {
+                        let idx = rng.gen_range(0..live.len());
+                        let new_key = live[idx].1 - rng.gen_range(0..500);
+                        heap.decrease_key(&live[idx].0, new_key);
+                        live[idx].1 = new_key;
+                    }
+                }
+            }
+            assert_eq!(heap.validate(), Ok(()));
+            assert_eq!(heap.len(), live.len());
+        }
+    }
+
+    #[test]
+    fn test_root_keys_matches_tree_count() {
+        let mut heap = FibonacciHeap::new();
+        for key in [7, 3, 5, 2, 8, 1, 4, 6, 9, 0] {
+            heap.insert(key, ());
+        }
+
+        // Before consolidation every node is its own tree, min first.
+        let roots = heap.root_keys();
+        assert_eq!(roots.len(), 10);
+        assert_eq!(roots[0], 0);
+
+        heap.extract_min();
+
+        // Nine nodes consolidate into binomial trees B3 and B0.
+        let roots = heap.root_keys();
+        assert_eq!(roots.len(), 9usize.count_ones() as usize);
+        assert_eq!(roots[0], 1);
+    }
+
+    #[test]
+    fn test_to_dot_output() {
+        let empty: FibonacciHeap<i32, ()> = FibonacciHeap::new();
+        assert_eq!(
+            empty.to_dot(),
+            "digraph FibonacciHeap {\n    node [shape=circle];\n}\n"
+        );
+
+        let mut heap = FibonacciHeap::new();
+        let handles: Vec<_> = (1..=9).map(|key| heap.insert(key, ())).collect();
+        heap.extract_min();
+        heap.decrease_key(&handles[8], 0);
+
+        let dot = heap.to_dot();
+        assert!(dot.starts_with("digraph FibonacciHeap {"));
+        assert!(dot.trim_end().ends_with('}'));
+        let node_lines = dot.lines().filter(|l| l.contains("[label=")).count();
+        let edge_lines = dot.lines().filter(|l| l.contains("->")).count();
+        assert_eq!(node_lines, heap.len());
+        assert_eq!(edge_lines, heap.len() - heap.root_keys().len());
+        assert!(dot.contains("label=\"0\\nd=0\", peripheries=2"));
+        assert!(dot.contains("rank=same"));
+    }
+
+    #[test]
+    fn test_collect_from_pairs() {
+        let mut keys: Vec<i32> = (0..1000).collect();
+        keys.shuffle(&mut rand::thread_rng());
+
+        let mut heap: FibonacciHeap<_, _> = keys.iter().map(|&k| (k, k.to_string())).collect();
+        assert_eq!(heap.len(), 1000);
+        // Nothing is consolidated until the first extraction
+        assert_eq!(heap.root_keys().len(), 1000);
+        assert!(heap.validate().is_ok());
+
+        for expected in 0..1000 {
+            assert_eq!(heap.extract_min(), Some((expected, expected.to_string())));
+            assert_eq!(heap.len(), 999 - expected as usize);
+        }
+        assert!(heap.is_empty());
+
+        heap.extend([(3, "3".to_string()), (1, "1".to_string())]);
+        assert_eq!(heap.minimum(), Some((1, "1".to_string())));
+    }
 }
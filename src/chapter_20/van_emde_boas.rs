@@ -70,6 +70,34 @@ impl VanEmdeBoasTree {
         }
     }
 
+    /// Creates a tree with universe size `2^universe_power` holding `keys`.
+    ///
+    /// Duplicate keys are inserted once. Panics if any key is outside the
+    /// universe.
+    pub fn from_keys(universe_power: usize, keys: &[usize]) -> Self {
+        let mut tree = Self::new(universe_power);
+        tree.extend(keys);
+        tree
+    }
+
+    /// Inserts every key of `keys`, skipping those already present.
+    ///
+    /// All keys are range-checked before any is inserted, so a panic leaves
+    /// the tree unchanged.
+    pub fn extend(&mut self, keys: &[usize]) {
+        let universe_size = self.universe_size();
+        if let Some(&key) = keys.iter().find(|&&key| key >= universe_size) {
+            panic!("key {key} out of bounds for universe size {universe_size}");
+        }
+        for &key in keys {
+            // Re-inserting the minimum would store it a second time in a
+            // cluster, so duplicates must be filtered out here.
+            if !self.member(key) {
+                self.insert(key);
+            }
+        }
+    }
+
     pub fn universe_size(&self) -> usize {
         u_size(self.universe_power)
     }
@@ -93,6 +121,34 @@ impl VanEmdeBoasTree {
         }
     }
 
+    /// Counts the cluster trees currently allocated, recursively, including
+    /// those inside summary structures. A freshly created or cleared tree
+    /// reports 0.
+    pub fn allocated_clusters(&self) -> usize {
+        let in_summary = self
+            .summary
+            .as_ref()
+            .map_or(0, |summary| summary.allocated_clusters());
+        let in_clusters: usize = self
+            .clusters
+            .iter()
+            .flatten()
+            .map(|cluster| 1 + cluster.allocated_clusters())
+            .sum();
+        in_summary + in_clusters
+    }
+
+    /// Removes every key while keeping the universe size, dropping all
+    /// allocated clusters so their memory is reclaimed.
+    pub fn clear(&mut self) {
+        self.min = None;
+        self.max = None;
+        if self.universe_power > 1 {
+            self.summary = Some(Box::new(Self::new(upper_power(self.universe_power))));
+            self.clusters.iter_mut().for_each(|cluster| *cluster = None);
+        }
+    }
+
     pub fn minimum(&self) -> Option<usize> {
         self.min
     }
@@ -349,4 +405,56 @@ mod tests {
         veb.delete(7);
         assert_eq!(veb.maximum(), Some(6));
     }
+
+    #[test]
+    fn test_from_keys_with_duplicates() {
+        let mut veb = VanEmdeBoasTree::from_keys(4, &[9, 2, 14, 2, 7, 9, 2]);
+        for x in 0..16 {
+            assert_eq!(veb.member(x), [2, 7, 9, 14].contains(&x));
+        }
+        assert_eq!(veb.minimum(), Some(2));
+        assert_eq!(veb.maximum(), Some(14));
+        assert_eq!(veb.successor(2), Some(7));
+
+        // Each key is stored once, so one delete removes it.
+        veb.delete(2);
+        assert!(!veb.member(2));
+        assert_eq!(veb.minimum(), Some(7));
+
+        veb.extend(&[0, 14, 15]);
+        assert_eq!(veb.minimum(), Some(0));
+        assert_eq!(veb.maximum(), Some(15));
+        assert_eq!(veb.predecessor(15), Some(14));
+    }
+
+    #[test]
+    #[should_panic(expected = "out of bounds")]
+    fn test_extend_rejects_out_of_range_key() {
+        let mut veb = VanEmdeBoasTree::new(3);
+        veb.extend(&[1, 8]);
+    }
+
+    #[test]
+    fn test_allocated_clusters_and_clear() {
+        let mut veb = VanEmdeBoasTree::new(8); // universe size 256
+        let baseline = veb.allocated_clusters();
+        assert_eq!(baseline, 0);
+
+        for &value in &[3, 70, 130, 255] {
+            veb.insert(value);
+        }
+        assert!(veb.allocated_clusters() > baseline);
+
+        veb.clear();
+        assert_eq!(veb.allocated_clusters(), baseline);
+        assert!(veb.is_empty());
+        assert_eq!(veb.universe_size(), 256);
+        assert!(!veb.member(70));
+
+        // The cleared tree is fully usable again.
+        veb.insert(200);
+        veb.insert(5);
+        assert_eq!(veb.minimum(), Some(5));
+        assert_eq!(veb.successor(5), Some(200));
+    }
 }
@@ -2,7 +2,9 @@
 //!
 //! This translation follows the CLRS union-find structure, providing
 //! `make_set`, `find_set`, and `union` operations with union by rank and
-//! path compression.
+//! path compression. The cheaper one-pass alternatives to path compression,
+//! path halving and path splitting, can be selected with
+//! [`DisjointSet::with_compression`].
 
 use std::collections::HashMap;
 use std::hash::Hash;
@@ -14,6 +16,23 @@ struct Node<T> {
     value: T,
 }
 
+/// How `find` rewrites parent pointers along the path it walks.
+///
+/// Combined with union by rank, every strategy except `NoCompression` gives
+/// the same O(m α(n)) amortized bound.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CompressionStrategy {
+    /// Point every node on the path directly at the root (two passes).
+    #[default]
+    FullCompression,
+    /// Point every other node on the path at its grandparent (one pass).
+    PathHalving,
+    /// Point every node on the path at its grandparent (one pass).
+    PathSplitting,
+    /// Leave the path unchanged; only union by rank bounds its length.
+    NoCompression,
+}
+
 /// Union-Find structure over values of type `T`.
 #[derive(Debug, Clone, Default)]
 pub struct DisjointSet<T>
@@ -22,6 +41,8 @@ where
 {
     nodes: Vec<Node<T>>,
     index: HashMap<T, usize>,
+    compression: CompressionStrategy,
+    pointer_updates: usize,
 }
 
 impl<T> DisjointSet<T>
@@ -30,12 +51,32 @@ where
 {
     /// Creates an empty disjoint set structure.
     pub fn new() -> Self {
+        Self::with_compression(CompressionStrategy::FullCompression)
+    }
+
+    /// Creates an empty disjoint set structure whose `find` uses `compression`.
+    pub fn with_compression(compression: CompressionStrategy) -> Self {
         Self {
             nodes: Vec::new(),
             index: HashMap::new(),
+            compression,
+            pointer_updates: 0,
         }
     }
 
+    /// Returns the path-rewriting strategy used by `find`.
+    pub fn compression(&self) -> CompressionStrategy {
+        self.compression
+    }
+
+    /// Returns how many parent pointers `find` has rewritten so far.
+    ///
+    /// Links made by `union` are not counted, so this measures only the work
+    /// spent on compressing paths.
+    pub fn pointer_updates(&self) -> usize {
+        self.pointer_updates
+    }
+
     /// Inserts a new singleton set containing `value`.
     ///
     /// Returns `false` if the value was already present.
@@ -54,7 +95,8 @@ where
         true
     }
 
-    /// Finds the representative of the set containing `value`, applying path compression.
+    /// Finds the representative of the set containing `value`, compressing the
+    /// path according to the configured strategy.
     pub fn find_set(&mut self, value: &T) -> Option<T> {
         let id = *self.index.get(value)?;
         let root = self.find(id);
@@ -92,13 +134,52 @@ where
     }
 
     fn find(&mut self, id: usize) -> usize {
+        match self.compression {
+            CompressionStrategy::FullCompression => self.find_compress(id),
+            CompressionStrategy::PathHalving => {
+                let mut x = id;
+                while self.nodes[x].parent != x {
+                    let grandparent = self.nodes[self.nodes[x].parent].parent;
+                    self.set_parent(x, grandparent);
+                    x = grandparent;
+                }
+                x
+            }
+            CompressionStrategy::PathSplitting => {
+                let mut x = id;
+                while self.nodes[x].parent != x {
+                    let parent = self.nodes[x].parent;
+                    self.set_parent(x, self.nodes[parent].parent);
+                    x = parent;
+                }
+                x
+            }
+            CompressionStrategy::NoCompression => {
+                let mut x = id;
+                while self.nodes[x].parent != x {
+                    x = self.nodes[x].parent;
+                }
+                x
+            }
+        }
+    }
+
+    fn find_compress(&mut self, id: usize) -> usize {
         if self.nodes[id].parent != id {
-            let root = self.find(self.nodes[id].parent);
-            self.nodes[id].parent = root;
+            let root = self.find_compress(self.nodes[id].parent);
+            self.set_parent(id, root);
         }
         self.nodes[id].parent
     }
 
+    /// Rewrites the parent of `id` during `find`, counting actual changes.
+    fn set_parent(&mut self, id: usize, parent: usize) {
+        if self.nodes[id].parent != parent {
+            self.nodes[id].parent = parent;
+            self.pointer_updates += 1;
+        }
+    }
+
     fn link(&mut self, x_root: usize, y_root: usize) {
         if self.nodes[x_root].rank > self.nodes[y_root].rank {
             self.nodes[y_root].parent = x_root;
@@ -124,6 +205,8 @@ where
 #[cfg(test)]
 mod tests {
     use super::*;
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
 
     #[test]
     fn test_make_set_and_find() {
@@ -176,4 +259,53 @@ mod tests {
         let unique_roots: std::collections::HashSet<_> = (0..10).map(|id| ds.find(id)).collect();
         assert_eq!(unique_roots.len(), 1);
     }
+
+    #[test]
+    fn test_compression_strategies_agree() {
+        let strategies = [
+            CompressionStrategy::FullCompression,
+            CompressionStrategy::PathHalving,
+            CompressionStrategy::PathSplitting,
+            CompressionStrategy::NoCompression,
+        ];
+        let n = 2048;
+        let mut rng = StdRng::seed_from_u64(21);
+        let operations: Vec<(bool, usize, usize)> = (0..4 * n)
+            .map(|_| (rng.gen_bool(0.4), rng.gen_range(0..n), rng.gen_range(0..n)))
+            .collect();
+
+        let mut answers = Vec::new();
+        let mut updates = Vec::new();
+        for strategy in strategies {
+            let mut ds = DisjointSet::with_compression(strategy);
+            assert_eq!(ds.compression(), strategy);
+            for value in 0..n {
+                ds.make_set(value);
+            }
+
+            let mut found = Vec::new();
+            for &(is_union, x, y) in &operations {
+                if is_union {
+                    ds.union(&x, &y);
+                } else {
+                    found.push(ds.find_set(&x));
+                    found.push(ds.find_set(&y));
+                }
+            }
+            found.push(Some(ds.set_count()));
+            answers.push(found);
+            updates.push(ds.pointer_updates());
+        }
+
+        assert!(answers.iter().all(|found| *found == answers[0]));
+
+        // The one-pass strategies leave paths only partly shortened, so the
+        // amount of rewriting differs even though the answers do not.
+        let [full, halving, splitting, none] = updates[..] else {
+            unreachable!()
+        };
+        assert_eq!(none, 0);
+        assert!(full > 0 && halving > 0 && splitting > 0, "{updates:?}");
+        assert!(halving != full && splitting != full, "{updates:?}");
+    }
 }
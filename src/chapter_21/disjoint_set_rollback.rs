@@ -0,0 +1,242 @@
+//! Disjoint Set with Rollback (Section 21.3 variant)
+//!
+//! A union-find structure whose unions can be undone in LIFO order, as
+//! needed by offline dynamic-connectivity and other backtracking algorithms.
+//! Path compression would rewrite parent pointers in ways that cannot be
+//! cheaply undone, so only union by rank is used; this still keeps every
+//! tree at height O(lg n), so `find` runs in O(lg n) time.
+
+use std::collections::HashMap;
+use std::hash::Hash;
+
+#[derive(Debug, Clone)]
+struct Node<T> {
+    parent: usize,
+    rank: usize,
+    value: T,
+}
+
+/// Record of one successful union, sufficient to undo it.
+#[derive(Debug, Clone, Copy)]
+struct UnionRecord {
+    /// Root that was linked beneath `parent`.
+    child: usize,
+    /// Root that became the parent of `child`.
+    parent: usize,
+    /// Whether the union incremented the rank of `parent`.
+    rank_increased: bool,
+}
+
+/// Union-Find structure supporting rollback of the most recent union.
+#[derive(Debug, Clone, Default)]
+pub struct DisjointSetRollback<T>
+where
+    T: Eq + Hash + Clone,
+{
+    nodes: Vec<Node<T>>,
+    index: HashMap<T, usize>,
+    history: Vec<UnionRecord>,
+}
+
+impl<T> DisjointSetRollback<T>
+where
+    T: Eq + Hash + Clone,
+{
+    /// Creates an empty disjoint set structure.
+    pub fn new() -> Self {
+        Self {
+            nodes: Vec::new(),
+            index: HashMap::new(),
+            history: Vec::new(),
+        }
+    }
+
+    /// Inserts a new singleton set containing `value`.
+    ///
+    /// Returns `false` if the value was already present.
+    pub fn make_set(&mut self, value: T) -> bool {
+        if self.index.contains_key(&value) {
+            return false;
+        }
+
+        let id = self.nodes.len();
+        self.nodes.push(Node {
+            parent: id,
+            rank: 0,
+            value: value.clone(),
+        });
+        self.index.insert(value, id);
+        true
+    }
+
+    /// Finds the representative of the set containing `value` without path compression.
+    pub fn find(&self, value: &T) -> Option<T> {
+        let id = *self.index.get(value)?;
+        Some(self.nodes[self.find_root(id)].value.clone())
+    }
+
+    /// Checks whether two values belong to the same set.
+    pub fn are_connected(&self, x: &T, y: &T) -> bool {
+        match (self.index.get(x), self.index.get(y)) {
+            (Some(&ix), Some(&iy)) => self.find_root(ix) == self.find_root(iy),
+            _ => false,
+        }
+    }
+
+    /// Performs the union of the sets containing `x` and `y` using union by rank.
+    ///
+    /// Returns `true` if the sets were distinct and `false` if they were already merged
+    /// or if either element is missing from the structure. Only successful unions are
+    /// recorded for [`rollback`](Self::rollback).
+    pub fn union(&mut self, x: &T, y: &T) -> bool {
+        let (Some(&x_id), Some(&y_id)) = (self.index.get(x), self.index.get(y)) else {
+            return false;
+        };
+
+        let x_root = self.find_root(x_id);
+        let y_root = self.find_root(y_id);
+
+        if x_root == y_root {
+            return false;
+        }
+
+        let (child, parent) = if self.nodes[x_root].rank > self.nodes[y_root].rank {
+            (y_root, x_root)
+        } else {
+            (x_root, y_root)
+        };
+        let rank_increased = self.nodes[child].rank == self.nodes[parent].rank;
+
+        self.nodes[child].parent = parent;
+        if rank_increased {
+            self.nodes[parent].rank += 1;
+        }
+        self.history.push(UnionRecord {
+            child,
+            parent,
+            rank_increased,
+        });
+        true
+    }
+
+    /// Undoes the most recent successful union, restoring parents and ranks.
+    ///
+    /// Returns `false` if there is no union left to undo.
+    pub fn rollback(&mut self) -> bool {
+        let Some(record) = self.history.pop() else {
+            return false;
+        };
+
+        self.nodes[record.child].parent = record.child;
+        if record.rank_increased {
+            self.nodes[record.parent].rank -= 1;
+        }
+        true
+    }
+
+    /// Returns the number of successful unions that can still be rolled back.
+    pub fn history_len(&self) -> usize {
+        self.history.len()
+    }
+
+    /// Returns the number of disjoint sets currently stored.
+    pub fn set_count(&self) -> usize {
+        self.nodes.len() - self.history.len()
+    }
+
+    fn find_root(&self, mut id: usize) -> usize {
+        while self.nodes[id].parent != id {
+            id = self.nodes[id].parent;
+        }
+        id
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_make_set_and_find() {
+        let mut ds = DisjointSetRollback::new();
+        ds.make_set("a");
+        ds.make_set("b");
+        assert!(!ds.make_set("a"));
+        assert_eq!(ds.find(&"a"), Some("a"));
+        assert_eq!(ds.find(&"b"), Some("b"));
+        assert_eq!(ds.find(&"c"), None);
+    }
+
+    #[test]
+    fn test_rollback_restores_ranks() {
+        let mut ds = DisjointSetRollback::new();
+        for value in 0..4 {
+            ds.make_set(value);
+        }
+
+        let ranks =
+            |ds: &DisjointSetRollback<i32>| ds.nodes.iter().map(|n| n.rank).collect::<Vec<_>>();
+
+        assert!(ds.union(&0, &1));
+        assert!(ds.union(&2, &3));
+        let before = ranks(&ds);
+        let parents_before: Vec<_> = ds.nodes.iter().map(|n| n.parent).collect();
+
+        // Union of two rank-1 trees bumps a rank to 2.
+        assert!(ds.union(&0, &2));
+        assert_eq!(ranks(&ds).iter().max(), Some(&2));
+
+        assert!(ds.rollback());
+        assert_eq!(ranks(&ds), before);
+        assert_eq!(
+            ds.nodes.iter().map(|n| n.parent).collect::<Vec<_>>(),
+            parents_before
+        );
+        assert!(!ds.are_connected(&0, &2));
+        assert!(ds.are_connected(&0, &1));
+        assert!(ds.are_connected(&2, &3));
+    }
+
+    #[test]
+    fn test_interleaved_unions_and_rollbacks() {
+        let mut ds = DisjointSetRollback::new();
+        for value in 1..=5 {
+            ds.make_set(value);
+        }
+
+        assert!(ds.union(&1, &2));
+        assert!(ds.are_connected(&1, &2));
+        assert_eq!(ds.set_count(), 4);
+
+        assert!(ds.union(&3, &4));
+        assert!(ds.are_connected(&3, &4));
+        assert!(!ds.are_connected(&2, &3));
+
+        assert!(ds.rollback());
+        assert!(!ds.are_connected(&3, &4));
+        assert!(ds.are_connected(&1, &2));
+        assert_eq!(ds.set_count(), 4);
+
+        assert!(ds.union(&2, &5));
+        assert!(!ds.union(&1, &5)); // already united, not recorded
+        assert_eq!(ds.history_len(), 2);
+        assert!(ds.are_connected(&1, &5));
+
+        assert!(ds.union(&5, &3));
+        assert!(ds.are_connected(&1, &3));
+        assert_eq!(ds.set_count(), 2);
+
+        assert!(ds.rollback());
+        assert!(!ds.are_connected(&1, &3));
+        assert!(ds.are_connected(&1, &5));
+
+        assert!(ds.rollback());
+        assert!(!ds.are_connected(&1, &5));
+        assert!(ds.are_connected(&1, &2));
+
+        assert!(ds.rollback());
+        assert!(!ds.are_connected(&1, &2));
+        assert_eq!(ds.set_count(), 5);
+        assert!(!ds.rollback());
+    }
+}
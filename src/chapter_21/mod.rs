@@ -2,8 +2,10 @@
 //!
 //! This module provides a safe Rust implementation of the union-find data
 //! structure with union by rank and path compression, following the CLRS
-//! presentation.
+//! presentation, plus a rollback-capable variant for backtracking algorithms.
 
 pub mod disjoint_set;
+pub mod disjoint_set_rollback;
 
 pub use disjoint_set::*;
+pub use disjoint_set_rollback::*;
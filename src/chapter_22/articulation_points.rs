@@ -0,0 +1,164 @@
+use super::Graph;
+
+/// Discovery times and low values from one DFS over an undirected graph,
+/// together with the cut vertices and bridges they reveal (CLRS Problem 22-2).
+struct LowLink {
+    discovery: Vec<Option<usize>>,
+    low: Vec<usize>,
+    is_articulation: Vec<bool>,
+    bridges: Vec<(usize, usize)>,
+    time: usize,
+}
+
+impl LowLink {
+    fn compute(graph: &Graph) -> Self {
+        assert!(
+            !graph.is_directed(),
+            "articulation points and bridges require an undirected graph"
+        );
+
+        let vertex_count = graph.vertex_count();
+        let mut state = LowLink {
+            discovery: vec![None; vertex_count],
+            low: vec![0; vertex_count],
+            is_articulation: vec![false; vertex_count],
+            bridges: Vec::new(),
+            time: 0,
+        };
+
+        for root in 0..vertex_count {
+            if state.discovery[root].is_none() {
+                let children = state.visit(graph, root, None);
+                // The root of a DFS tree has no ancestors to fall back on, so
+                // it separates the graph exactly when it has two subtrees.
+                state.is_articulation[root] = children >= 2;
+            }
+        }
+        state
+    }
+
+    /// Visits `u`, reached from `parent`, and returns its number of DFS
+    /// children.
+    fn visit(&mut self, graph: &Graph, u: usize, parent: Option<usize>) -> usize {
+        self.time += 1;
+        self.discovery[u] = Some(self.time);
+        self.low[u] = self.time;
+
+        let mut children = 0;
+        // Only the tree edge itself is ignored; a parallel edge back to the
+        // parent is a genuine back edge.
+        let mut skipped_parent = false;
+        for v in graph.neighbors_iter(u) {
+            if Some(v) == parent && !skipped_parent {
+                skipped_parent = true;
+                continue;
+            }
+            match self.discovery[v] {
+                Some(discovered) => self.low[u] = self.low[u].min(discovered),
+                None => {
+                    children += 1;
+                    self.visit(graph, v, Some(u));
+                    self.low[u] = self.low[u].min(self.low[v]);
+
+                    let discovered_u = self.time_of(u);
+                    if parent.is_some() && self.low[v] >= discovered_u {
+                        self.is_articulation[u] = true;
+                    }
+                    if self.low[v] > discovered_u {
+                        self.bridges.push((u.min(v), u.max(v)));
+                    }
+                }
+            }
+        }
+        children
+    }
+
+    fn time_of(&self, u: usize) -> usize {
+        self.discovery[u].expect("visited vertex has a discovery time")
+    }
+}
+
+/// Returns the articulation points of an undirected graph in increasing order.
+///
+/// A vertex is an articulation point when removing it disconnects its
+/// component. One DFS computes `low[v]`, the earliest discovery time reachable
+/// from the subtree of `v` using at most one back edge; a non-root vertex `u`
+/// is a cut vertex iff some child `v` has `low[v] >= d[u]`, and a DFS root iff
+/// it has at least two children. Runs in O(V + E).
+///
+/// # Panics
+///
+/// Panics if the graph is directed.
+pub fn articulation_points(graph: &Graph) -> Vec<usize> {
+    LowLink::compute(graph)
+        .is_articulation
+        .iter()
+        .enumerate()
+        .filter_map(|(v, &is_cut)| is_cut.then_some(v))
+        .collect()
+}
+
+/// Returns the bridges of an undirected graph as sorted `(u, v)` pairs with
+/// `u < v`.
+///
+/// An edge is a bridge when removing it disconnects its component; with the
+/// low values of [`articulation_points`], the tree edge `(u, v)` is a bridge
+/// iff `low[v] > d[u]`. Back edges lie on cycles and are never bridges, and
+/// neither are parallel edges. Runs in O(V + E).
+///
+/// # Panics
+///
+/// Panics if the graph is directed.
+pub fn bridges(graph: &Graph) -> Vec<(usize, usize)> {
+    let mut bridges = LowLink::compute(graph).bridges;
+    bridges.sort_unstable();
+    bridges
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn path_graph_cuts_everything() {
+        let mut graph = Graph::new(5, false);
+        for v in 0..4 {
+            graph.add_edge(v, v + 1);
+        }
+        assert_eq!(articulation_points(&graph), vec![1, 2, 3]);
+        assert_eq!(bridges(&graph), vec![(0, 1), (1, 2), (2, 3), (3, 4)]);
+
+        // Starting the DFS in the middle makes the root a cut vertex.
+        let graph = Graph::from_adjacency_list(vec![vec![1, 2], vec![0], vec![0]], false);
+        assert_eq!(articulation_points(&graph), vec![0]);
+        assert_eq!(bridges(&graph), vec![(0, 1), (0, 2)]);
+    }
+
+    #[test]
+    fn cycle_has_no_cuts() {
+        let mut graph = Graph::new(6, false);
+        for v in 0..6 {
+            graph.add_edge(v, (v + 1) % 6);
+        }
+        assert!(articulation_points(&graph).is_empty());
+        assert!(bridges(&graph).is_empty());
+
+        // A doubled edge is not a bridge either.
+        let mut graph = Graph::new(2, false);
+        graph.add_edge(0, 1);
+        graph.add_edge(0, 1);
+        assert!(bridges(&graph).is_empty());
+    }
+
+    #[test]
+    fn two_cycles_joined_by_a_bridge() {
+        // Triangles {0, 1, 2} and {3, 4, 5} joined by edge (2, 3), plus an
+        // isolated vertex 6.
+        let mut graph = Graph::new(7, false);
+        for &(u, v) in &[(0, 1), (1, 2), (2, 0), (3, 4), (4, 5), (5, 3), (2, 3)] {
+            graph.add_edge(u, v);
+        }
+        assert_eq!(articulation_points(&graph), vec![2, 3]);
+        assert_eq!(bridges(&graph), vec![(2, 3)]);
+    }
+}
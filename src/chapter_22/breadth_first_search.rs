@@ -1,6 +1,6 @@
 use std::collections::VecDeque;
 
-use super::Graph;
+use super::{Graph, Neighbors};
 
 /// The outcome of a breadth-first search.
 #[derive(Debug, Clone)]
@@ -35,8 +35,9 @@ impl BfsResult {
 ///
 /// The search computes the shortest-path tree for graphs with unit edge
 /// weights, returning distance estimates and parent pointers for each vertex.
-pub fn breadth_first_search(graph: &Graph, source: usize) -> BfsResult {
-    let vertex_count = graph.vertex_count();
+/// It runs on any graph representation that implements [`Neighbors`].
+pub fn breadth_first_search<G: Neighbors>(graph: &G, source: usize) -> BfsResult {
+    let vertex_count = graph.num_vertices();
     assert!(source < vertex_count, "source vertex out of bounds");
 
     #[derive(Clone, Copy, PartialEq, Eq)]
@@ -59,7 +60,7 @@ pub fn breadth_first_search(graph: &Graph, source: usize) -> BfsResult {
 
     while let Some(u) = queue.pop_front() {
         order.push(u);
-        for v in graph.neighbors_iter(u) {
+        for v in graph.neighbors(u) {
             if color[v] == Color::White {
                 color[v] = Color::Gray;
                 distances[v] = distances[u].map(|d| d + 1);
@@ -78,6 +79,89 @@ pub fn breadth_first_search(graph: &Graph, source: usize) -> BfsResult {
     }
 }
 
+/// Returns the greatest BFS distance from `v` to any vertex.
+///
+/// The result is `None` when some vertex cannot be reached from `v`, since
+/// its distance is infinite. For a directed graph the distances follow the
+/// edge directions.
+pub fn eccentricity(graph: &Graph, v: usize) -> Option<usize> {
+    let result = breadth_first_search(graph, v);
+    result
+        .distances
+        .into_iter()
+        .try_fold(0, |farthest, distance| Some(farthest.max(distance?)))
+}
+
+/// Returns the largest eccentricity of any vertex.
+///
+/// This runs a breadth-first search from every vertex, taking O(V(V + E))
+/// time. It is `None` for a graph with no vertices and for a graph in which
+/// some vertex cannot reach another, such as a disconnected undirected graph.
+pub fn diameter(graph: &Graph) -> Option<usize> {
+    all_eccentricities(graph)?.into_iter().max()
+}
+
+/// Returns the smallest eccentricity of any vertex.
+///
+/// Like [`diameter`], this runs a breadth-first search from every vertex and
+/// is `None` for an empty graph or one in which some vertex cannot reach
+/// another, even if a different vertex reaches everything.
+pub fn radius(graph: &Graph) -> Option<usize> {
+    all_eccentricities(graph)?.into_iter().min()
+}
+
+fn all_eccentricities(graph: &Graph) -> Option<Vec<usize>> {
+    (0..graph.vertex_count())
+        .map(|v| eccentricity(graph, v))
+        .collect()
+}
+
+/// Finds a 2-coloring of an undirected graph, if one exists.
+///
+/// Each component is searched breadth-first from its lowest-numbered vertex,
+/// which gets color `false`; every other vertex gets the color opposite its
+/// BFS parent, so the colors record the parity of BFS distances. The graph is
+/// bipartite exactly when no edge joins two vertices of the same color, and
+/// such an edge closes an odd cycle, in which case `None` is returned.
+///
+/// # Panics
+///
+/// Panics if the graph is directed.
+pub fn two_coloring(graph: &Graph) -> Option<Vec<bool>> {
+    assert!(
+        !graph.is_directed(),
+        "two-coloring requires an undirected graph"
+    );
+
+    let vertex_count = graph.vertex_count();
+    let mut colors: Vec<Option<bool>> = vec![None; vertex_count];
+    let mut queue = VecDeque::new();
+
+    for root in 0..vertex_count {
+        if colors[root].is_some() {
+            continue;
+        }
+        colors[root] = Some(false);
+        queue.push_back(root);
+
+        while let Some(u) = queue.pop_front() {
+            let color_u = colors[u].expect("queued vertices are colored");
+            for v in graph.neighbors_iter(u) {
+                match colors[v] {
+                    None => {
+                        colors[v] = Some(!color_u);
+                        queue.push_back(v);
+                    }
+                    Some(color_v) if color_v == color_u => return None,
+                    Some(_) => {}
+                }
+            }
+        }
+    }
+
+    colors.into_iter().collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -122,4 +206,72 @@ mod tests {
         assert_eq!(result.distances, vec![Some(0), Some(1), Some(2), None]);
         assert_eq!(result.path_to(3), None);
     }
+
+    #[test]
+    fn eccentricity_radius_and_diameter_of_a_path() {
+        let mut path = Graph::new(5, false);
+        for u in 0..4 {
+            path.add_edge(u, u + 1);
+        }
+        let eccentricities: Vec<_> = (0..5).map(|v| eccentricity(&path, v)).collect();
+        assert_eq!(
+            eccentricities,
+            vec![Some(4), Some(3), Some(2), Some(3), Some(4)]
+        );
+        assert_eq!(diameter(&path), Some(4));
+        assert_eq!(radius(&path), Some(2));
+
+        let mut disconnected = Graph::new(3, false);
+        disconnected.add_edge(0, 1);
+        assert_eq!(eccentricity(&disconnected, 0), None);
+        assert_eq!(diameter(&disconnected), None);
+        assert_eq!(radius(&disconnected), None);
+        assert_eq!(diameter(&Graph::new(0, false)), None);
+    }
+
+    fn assert_proper(graph: &Graph, colors: &[bool]) {
+        for u in 0..graph.vertex_count() {
+            for v in graph.neighbors_iter(u) {
+                assert_ne!(colors[u], colors[v], "edge ({u}, {v}) is monochromatic");
+            }
+        }
+    }
+
+    #[test]
+    fn two_coloring_cycles_and_forests() {
+        let cycle = |n: usize| {
+            let mut graph = Graph::new(n, false);
+            for u in 0..n {
+                graph.add_edge(u, (u + 1) % n);
+            }
+            graph
+        };
+
+        let even = cycle(6);
+        let colors = two_coloring(&even).expect("even cycle is bipartite");
+        assert_proper(&even, &colors);
+        assert_eq!(colors, vec![false, true, false, true, false, true]);
+
+        assert_eq!(two_coloring(&cycle(5)), None);
+
+        // Two trees and an isolated vertex, colored component by component.
+        let mut forest = Graph::new(8, false);
+        for &(u, v) in &[(0, 1), (0, 2), (2, 3), (4, 5), (5, 6)] {
+            forest.add_edge(u, v);
+        }
+        let colors = two_coloring(&forest).expect("forests are bipartite");
+        assert_proper(&forest, &colors);
+        assert!(!colors[4] && !colors[7]);
+
+        // An odd cycle in a later component is still found.
+        let mut graph = Graph::new(7, false);
+        for &(u, v) in &[(0, 1), (1, 2), (3, 4), (4, 5), (5, 6), (6, 3), (4, 6)] {
+            graph.add_edge(u, v);
+        }
+        assert_eq!(two_coloring(&graph), None);
+
+        let mut self_loop = Graph::new(1, false);
+        self_loop.add_edge(0, 0);
+        assert_eq!(two_coloring(&self_loop), None);
+    }
 }
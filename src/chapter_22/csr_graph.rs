@@ -0,0 +1,229 @@
+use super::{Graph, Neighbors};
+
+/// A compressed sparse row (CSR) representation of a graph.
+///
+/// All adjacency lists are stored back to back in one flat array, and
+/// `offsets[v]..offsets[v + 1]` is the range holding the neighbors of `v`.
+/// Scanning neighbors then walks contiguous memory, which suits large graphs
+/// that are built once and traversed many times. The graph cannot be changed
+/// after it is built.
+///
+/// A graph built from edges is directed; one converted from a [`Graph`]
+/// keeps that graph's directedness.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CsrGraph {
+    offsets: Vec<usize>,
+    targets: Vec<usize>,
+    directed: bool,
+}
+
+impl CsrGraph {
+    /// Builds a graph with `vertex_count` vertices from directed edges `(u, v)`.
+    ///
+    /// The edges are bucketed by source in two passes, as in counting sort, so
+    /// each vertex lists its neighbors in the order its edges were given. An
+    /// undirected edge must be given in both directions.
+    ///
+    /// # Panics
+    ///
+    /// Panics if an edge names a vertex outside `0..vertex_count`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use clrs::chapter_22::CsrGraph;
+    ///
+    /// let g = CsrGraph::from_edges(3, &[(0, 2), (1, 0), (0, 1)]);
+    /// assert_eq!(g.neighbors(0), &[2, 1]);
+    /// assert_eq!(g.neighbors(2), &[] as &[usize]);
+    /// ```
+    pub fn from_edges(vertex_count: usize, edges: &[(usize, usize)]) -> Self {
+        let mut offsets = vec![0; vertex_count + 1];
+        for &(u, v) in edges {
+            assert!(u < vertex_count, "vertex {} out of bounds", u);
+            assert!(v < vertex_count, "vertex {} out of bounds", v);
+            offsets[u + 1] += 1;
+        }
+        for u in 0..vertex_count {
+            offsets[u + 1] += offsets[u];
+        }
+
+        let mut next = offsets.clone();
+        let mut targets = vec![0; edges.len()];
+        for &(u, v) in edges {
+            targets[next[u]] = v;
+            next[u] += 1;
+        }
+
+        Self {
+            offsets,
+            targets,
+            directed: true,
+        }
+    }
+
+    /// Returns the number of vertices in the graph.
+    pub fn vertex_count(&self) -> usize {
+        self.offsets.len() - 1
+    }
+
+    /// Returns the number of stored directed edges.
+    pub fn edge_count(&self) -> usize {
+        self.targets.len()
+    }
+
+    /// Returns whether the graph is directed.
+    pub fn is_directed(&self) -> bool {
+        self.directed
+    }
+
+    /// Returns the neighbors of vertex `v`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `v` is not a valid vertex index.
+    pub fn neighbors(&self, v: usize) -> &[usize] {
+        &self.targets[self.offsets[v]..self.offsets[v + 1]]
+    }
+}
+
+impl From<&Graph> for CsrGraph {
+    /// Copies the adjacency lists of `graph`, keeping each list's order. An
+    /// undirected graph already stores both directions of every edge.
+    fn from(graph: &Graph) -> Self {
+        let mut offsets = Vec::with_capacity(graph.vertex_count() + 1);
+        let mut targets = Vec::new();
+        offsets.push(0);
+        for u in 0..graph.vertex_count() {
+            targets.extend_from_slice(graph.neighbors(u));
+            offsets.push(targets.len());
+        }
+        Self {
+            offsets,
+            targets,
+            directed: graph.is_directed(),
+        }
+    }
+}
+
+impl Neighbors for CsrGraph {
+    fn num_vertices(&self) -> usize {
+        self.vertex_count()
+    }
+
+    fn neighbors(&self, v: usize) -> impl Iterator<Item = usize> {
+        CsrGraph::neighbors(self, v).iter().copied()
+    }
+
+    fn is_directed(&self) -> bool {
+        CsrGraph::is_directed(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::chapter_22::{breadth_first_search, depth_first_search, topological_sort};
+
+    #[test]
+    fn from_edges_matches_adjacency_lists() {
+        let edges = [
+            (0, 1),
+            (0, 3),
+            (1, 2),
+            (3, 1),
+            (3, 2),
+            (2, 4),
+            (4, 0),
+            (3, 4),
+        ];
+        let csr = CsrGraph::from_edges(6, &edges);
+
+        let mut graph = Graph::new(6, true);
+        for &(u, v) in &edges {
+            graph.add_edge(u, v);
+        }
+
+        assert_eq!(csr.vertex_count(), 6);
+        assert_eq!(csr.edge_count(), edges.len());
+        for v in 0..6 {
+            assert_eq!(csr.neighbors(v), graph.neighbors(v));
+        }
+        assert_eq!(CsrGraph::from(&graph), csr);
+    }
+
+    #[test]
+    fn bfs_agrees_on_both_representations() {
+        let mut graph = Graph::new(8, false);
+        for &(u, v) in &[
+            (0, 1),
+            (0, 4),
+            (1, 5),
+            (5, 2),
+            (5, 6),
+            (2, 6),
+            (2, 3),
+            (6, 7),
+            (3, 7),
+        ] {
+            graph.add_edge(u, v);
+        }
+        let csr = CsrGraph::from(&graph);
+
+        let expected = breadth_first_search(&graph, 1);
+        let result = breadth_first_search(&csr, 1);
+        assert_eq!(result.distances, expected.distances);
+        assert_eq!(result.predecessors, expected.predecessors);
+        assert_eq!(result.order, expected.order);
+        assert_eq!(result.distances[7], Some(3));
+    }
+
+    #[test]
+    fn traversals_agree_on_both_representations() {
+        // CLRS Figure 22.7: dressing order, with the edges of the figure.
+        let edges = [
+            (0, 1),
+            (0, 7),
+            (1, 2),
+            (1, 7),
+            (2, 5),
+            (3, 2),
+            (4, 2),
+            (6, 7),
+        ];
+        let mut graph = Graph::new(9, true);
+        for &(u, v) in &edges {
+            graph.add_edge(u, v);
+        }
+        let csr = CsrGraph::from_edges(9, &edges);
+
+        for source in 0..9 {
+            assert_eq!(
+                breadth_first_search(&csr, source).distances,
+                breadth_first_search(&graph, source).distances
+            );
+        }
+
+        let dfs_graph = depth_first_search(&graph);
+        let dfs_csr = depth_first_search(&csr);
+        assert_eq!(dfs_csr.discovery_times, dfs_graph.discovery_times);
+        assert_eq!(dfs_csr.finish_times, dfs_graph.finish_times);
+        assert_eq!(dfs_csr.forest, dfs_graph.forest);
+
+        let order = topological_sort(&csr).expect("should be a DAG");
+        assert_eq!(Ok(order.clone()), topological_sort(&graph));
+        let position = |v: usize| order.iter().position(|&w| w == v).unwrap();
+        assert!(edges.iter().all(|&(u, v)| position(u) < position(v)));
+    }
+
+    #[test]
+    #[should_panic(expected = "topological sort requires a directed graph")]
+    fn converted_undirected_graph_stays_undirected() {
+        let mut graph = Graph::new(3, false);
+        graph.add_edge(0, 1);
+        graph.add_edge(1, 2);
+        let csr = CsrGraph::from(&graph);
+        assert!(!csr.is_directed());
+        let _ = topological_sort(&csr);
+    }
+}
@@ -1,4 +1,4 @@
-use super::Graph;
+use super::Neighbors;
 
 #[derive(Clone, Copy, PartialEq, Eq)]
 enum Color {
@@ -21,8 +21,9 @@ pub struct DfsResult {
 ///
 /// The DFS runs over every connected component, yielding a depth-first forest of
 /// trees, discovery/finish timestamps, and the order in which vertices finish.
-pub fn depth_first_search(graph: &Graph) -> DfsResult {
-    let vertex_count = graph.vertex_count();
+/// It runs on any graph representation that implements [`Neighbors`].
+pub fn depth_first_search<G: Neighbors>(graph: &G) -> DfsResult {
+    let vertex_count = graph.num_vertices();
 
     let mut color = vec![Color::White; vertex_count];
     let mut discovery_times = vec![None; vertex_count];
@@ -58,8 +59,8 @@ pub fn depth_first_search(graph: &Graph) -> DfsResult {
     }
 }
 
-fn dfs_visit(
-    graph: &Graph,
+fn dfs_visit<G: Neighbors>(
+    graph: &G,
     u: usize,
     color: &mut [Color],
     discovery_times: &mut [Option<usize>],
@@ -74,7 +75,7 @@ fn dfs_visit(
     color[u] = Color::Gray;
     current_tree.push(u);
 
-    for v in graph.neighbors_iter(u) {
+    for v in graph.neighbors(u) {
         if color[v] == Color::White {
             predecessors[v] = Some(u);
             dfs_visit(
@@ -97,9 +98,65 @@ fn dfs_visit(
     finish_order.push(u);
 }
 
+/// Performs depth-first search from `source` with an explicit stack.
+///
+/// Visits exactly the vertices reachable from `source`, like a single call of
+/// DFS-VISIT, and assigns them the same discovery and finish times,
+/// predecessors and finish order that the recursive search would. Each stack
+/// frame keeps the vertex together with its partly consumed neighbor
+/// iterator, so the search depth is limited by memory rather than by the
+/// call stack. The forest holds a single tree; unreached vertices keep
+/// `None` times.
+pub fn dfs_iterative<G: Neighbors>(graph: &G, source: usize) -> DfsResult {
+    let vertex_count = graph.num_vertices();
+    assert!(source < vertex_count, "source vertex out of bounds");
+
+    let mut color = vec![Color::White; vertex_count];
+    let mut discovery_times = vec![None; vertex_count];
+    let mut finish_times = vec![None; vertex_count];
+    let mut predecessors = vec![None; vertex_count];
+    let mut finish_order = Vec::new();
+    let mut tree = vec![source];
+    let mut time = 1usize;
+
+    discovery_times[source] = Some(time);
+    color[source] = Color::Gray;
+    let mut stack = vec![(source, graph.neighbors(source))];
+
+    while let Some((u, neighbors)) = stack.last_mut() {
+        let u = *u;
+        match neighbors.find(|&v| color[v] == Color::White) {
+            Some(v) => {
+                time += 1;
+                discovery_times[v] = Some(time);
+                color[v] = Color::Gray;
+                predecessors[v] = Some(u);
+                tree.push(v);
+                stack.push((v, graph.neighbors(v)));
+            }
+            None => {
+                stack.pop();
+                color[u] = Color::Black;
+                time += 1;
+                finish_times[u] = Some(time);
+                finish_order.push(u);
+            }
+        }
+    }
+
+    DfsResult {
+        discovery_times,
+        finish_times,
+        predecessors,
+        forest: vec![tree],
+        finish_order,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::chapter_22::Graph;
 
     #[test]
     fn dfs_simple_dag() {
@@ -138,4 +195,53 @@ mod tests {
             assert!(discovery[i] < finish[i]);
         }
     }
+
+    #[test]
+    fn dfs_iterative_matches_recursive() {
+        // CLRS Figure 22.4 with u..z as 0..5, plus a vertex 6 unreachable from 0.
+        let mut graph = Graph::new(7, true);
+        for &(u, v) in &[
+            (0, 1),
+            (0, 3),
+            (1, 4),
+            (2, 4),
+            (2, 5),
+            (3, 1),
+            (4, 3),
+            (5, 5),
+            (6, 0),
+        ] {
+            graph.add_edge(u, v);
+        }
+
+        let iterative = dfs_iterative(&graph, 0);
+        let recursive = depth_first_search(&graph);
+        assert_eq!(iterative.forest, vec![vec![0, 1, 4, 3]]);
+        assert_eq!(iterative.finish_order, vec![3, 4, 1, 0]);
+        for v in [0, 1, 3, 4] {
+            assert_eq!(iterative.discovery_times[v], recursive.discovery_times[v]);
+            assert_eq!(iterative.finish_times[v], recursive.finish_times[v]);
+            assert_eq!(iterative.predecessors[v], recursive.predecessors[v]);
+        }
+        for v in [2, 5, 6] {
+            assert_eq!(iterative.discovery_times[v], None);
+            assert_eq!(iterative.finish_times[v], None);
+        }
+    }
+
+    #[test]
+    fn dfs_iterative_handles_deep_paths() {
+        let n = 500_000;
+        let mut graph = Graph::new(n, true);
+        for v in 0..n - 1 {
+            graph.add_edge(v, v + 1);
+        }
+
+        let result = dfs_iterative(&graph, 0);
+        assert!(result.discovery_times.iter().all(Option::is_some));
+        assert_eq!(result.forest[0].len(), n);
+        assert_eq!(result.finish_times[0], Some(2 * n));
+        assert_eq!(result.finish_order[0], n - 1);
+        assert_eq!(result.predecessors[n - 1], Some(n - 2));
+    }
 }
@@ -1,5 +1,113 @@
 use std::fmt;
 
+/// What went wrong on a line of an edge-list string.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseErrorKind {
+    /// The line did not have the expected number of whitespace-separated fields.
+    FieldCount { expected: usize, found: usize },
+    /// A field could not be parsed as a vertex index or weight.
+    InvalidToken(String),
+    /// A vertex index was not in `0..vertex_count`.
+    VertexOutOfRange { vertex: usize, vertex_count: usize },
+}
+
+/// Error returned when parsing a graph from an edge-list string.
+///
+/// `line` is 1-based and counts blank lines, so it matches what an editor shows.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: usize,
+    pub kind: ParseErrorKind,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}: ", self.line)?;
+        match &self.kind {
+            ParseErrorKind::FieldCount { expected, found } => {
+                write!(f, "expected {expected} fields, found {found}")
+            }
+            ParseErrorKind::InvalidToken(token) => write!(f, "invalid token `{token}`"),
+            ParseErrorKind::VertexOutOfRange {
+                vertex,
+                vertex_count,
+            } => write!(
+                f,
+                "vertex {vertex} out of range for a graph with {vertex_count} vertices"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Splits `text` into the non-blank lines of an edge list, checking that each
+/// has exactly `fields` tokens. Yields the 1-based line number with the tokens.
+pub(crate) fn edge_list_lines(
+    text: &str,
+    fields: usize,
+) -> impl Iterator<Item = Result<(usize, Vec<&str>), ParseError>> + '_ {
+    text.lines()
+        .enumerate()
+        .map(|(index, line)| (index + 1, line.split_whitespace().collect::<Vec<_>>()))
+        .filter(|(_, tokens)| !tokens.is_empty())
+        .map(move |(line, tokens)| {
+            if tokens.len() == fields {
+                Ok((line, tokens))
+            } else {
+                Err(ParseError {
+                    line,
+                    kind: ParseErrorKind::FieldCount {
+                        expected: fields,
+                        found: tokens.len(),
+                    },
+                })
+            }
+        })
+}
+
+/// Parses `token` as a vertex index in `0..vertex_count`.
+pub(crate) fn parse_vertex(
+    token: &str,
+    line: usize,
+    vertex_count: usize,
+) -> Result<usize, ParseError> {
+    let vertex: usize = token.parse().map_err(|_| ParseError {
+        line,
+        kind: ParseErrorKind::InvalidToken(token.to_string()),
+    })?;
+    if vertex < vertex_count {
+        Ok(vertex)
+    } else {
+        Err(ParseError {
+            line,
+            kind: ParseErrorKind::VertexOutOfRange {
+                vertex,
+                vertex_count,
+            },
+        })
+    }
+}
+
+/// Read-only access to the out-neighbors of each vertex.
+///
+/// Traversals written against this trait run unchanged on any graph
+/// representation, such as the adjacency lists of [`Graph`] or the flat
+/// arrays of [`CsrGraph`](super::CsrGraph). Vertices are the indices in
+/// `0..num_vertices()`.
+pub trait Neighbors {
+    /// Returns the number of vertices.
+    fn num_vertices(&self) -> usize;
+
+    /// Returns the vertices adjacent to `v`, following edge directions in a
+    /// directed graph.
+    fn neighbors(&self, v: usize) -> impl Iterator<Item = usize>;
+
+    /// Returns whether edges are one-way. An undirected graph lists each
+    /// edge from both ends.
+    fn is_directed(&self) -> bool;
+}
+
 /// An adjacency-list representation of a graph.
 ///
 /// The vertices are identified by indices in `0..vertex_count`. Edges are
@@ -33,6 +141,40 @@ impl Graph {
         }
     }
 
+    /// Parses a graph with `vertex_count` vertices from an edge list.
+    ///
+    /// Each non-blank line of `text` holds one edge `u v` as two
+    /// whitespace-separated vertex indices. Edges are added with
+    /// [`Graph::add_edge`], so undirected graphs get both directions.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ParseError`] naming the offending line when it does not have
+    /// two fields, a field is not a number, or a vertex is out of range.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use clrs::chapter_22::Graph;
+    ///
+    /// let g = Graph::from_edge_list(3, true, "0 1\n1 2\n").unwrap();
+    /// assert_eq!(g.neighbors(1), &[2]);
+    /// ```
+    pub fn from_edge_list(
+        vertex_count: usize,
+        directed: bool,
+        text: &str,
+    ) -> Result<Self, ParseError> {
+        let mut graph = Self::new(vertex_count, directed);
+        for entry in edge_list_lines(text, 2) {
+            let (line, tokens) = entry?;
+            let u = parse_vertex(tokens[0], line, vertex_count)?;
+            let v = parse_vertex(tokens[1], line, vertex_count)?;
+            graph.add_edge(u, v);
+        }
+        Ok(graph)
+    }
+
     /// Returns the number of vertices in the graph.
     pub fn vertex_count(&self) -> usize {
         self.adjacency_list.len()
@@ -58,6 +200,77 @@ impl Graph {
         }
     }
 
+    /// Returns the number of edges leaving `v`.
+    ///
+    /// For an undirected graph this is the degree of `v`, with a self-loop
+    /// counted twice.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `v` is not a valid vertex index.
+    pub fn out_degree(&self, v: usize) -> usize {
+        let neighbors = &self.adjacency_list[v];
+        if self.directed {
+            neighbors.len()
+        } else {
+            let self_loops = neighbors.iter().filter(|&&w| w == v).count();
+            neighbors.len() + self_loops
+        }
+    }
+
+    /// Returns the number of edges entering `v`.
+    ///
+    /// For a directed graph this scans every adjacency list, taking
+    /// O(V + E) time. For an undirected graph it equals [`Graph::out_degree`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `v` is not a valid vertex index.
+    pub fn in_degree(&self, v: usize) -> usize {
+        assert!(v < self.vertex_count(), "vertex {} out of bounds", v);
+        if !self.directed {
+            return self.out_degree(v);
+        }
+        self.adjacency_list
+            .iter()
+            .flatten()
+            .filter(|&&w| w == v)
+            .count()
+    }
+
+    /// Returns the number of edges, counting each undirected edge once.
+    pub fn edge_count(&self) -> usize {
+        let entries: usize = self.adjacency_list.iter().map(Vec::len).sum();
+        if self.directed {
+            entries
+        } else {
+            let self_loops = self
+                .adjacency_list
+                .iter()
+                .enumerate()
+                .map(|(u, neighbors)| neighbors.iter().filter(|&&w| w == u).count())
+                .sum::<usize>();
+            (entries + self_loops) / 2
+        }
+    }
+
+    /// Returns the degrees of all vertices sorted in nonincreasing order.
+    ///
+    /// The degree of a vertex in a directed graph is its in-degree plus its
+    /// out-degree, so in either case the sequence sums to `2 * edge_count()`.
+    pub fn degree_sequence(&self) -> Vec<usize> {
+        let mut degrees: Vec<usize> = (0..self.vertex_count())
+            .map(|v| self.out_degree(v))
+            .collect();
+        if self.directed {
+            for &w in self.adjacency_list.iter().flatten() {
+                degrees[w] += 1;
+            }
+        }
+        degrees.sort_unstable_by(|a, b| b.cmp(a));
+        degrees
+    }
+
     /// Returns the neighbors of vertex `u`.
     ///
     /// # Panics
@@ -101,6 +314,20 @@ impl Graph {
     }
 }
 
+impl Neighbors for Graph {
+    fn num_vertices(&self) -> usize {
+        self.vertex_count()
+    }
+
+    fn neighbors(&self, v: usize) -> impl Iterator<Item = usize> {
+        self.neighbors_iter(v)
+    }
+
+    fn is_directed(&self) -> bool {
+        Graph::is_directed(self)
+    }
+}
+
 impl fmt::Debug for Graph {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.debug_struct("Graph")
@@ -133,6 +360,80 @@ mod tests {
         assert_eq!(g.into_adjacency_list(), vec![vec![1], vec![0, 2], vec![1]]);
     }
 
+    #[test]
+    fn degrees_undirected() {
+        let mut g = Graph::new(5, false);
+        g.add_edge(0, 1);
+        g.add_edge(0, 2);
+        g.add_edge(0, 3);
+        g.add_edge(1, 2);
+        g.add_edge(3, 3);
+
+        assert_eq!(g.edge_count(), 5);
+        for v in 0..5 {
+            assert_eq!(g.in_degree(v), g.out_degree(v));
+        }
+        assert_eq!(g.out_degree(3), 3);
+        assert_eq!(g.out_degree(4), 0);
+
+        let sequence = g.degree_sequence();
+        assert_eq!(sequence, vec![3, 3, 2, 2, 0]);
+        assert_eq!(sequence.iter().sum::<usize>(), 2 * g.edge_count());
+    }
+
+    #[test]
+    fn degrees_directed() {
+        let mut g = Graph::new(4, true);
+        g.add_edge(0, 1);
+        g.add_edge(0, 2);
+        g.add_edge(1, 2);
+        g.add_edge(2, 0);
+        g.add_edge(2, 2);
+
+        assert_eq!(g.out_degree(0), 2);
+        assert_eq!(g.in_degree(0), 1);
+        assert_eq!(g.in_degree(2), 3);
+        assert_eq!(g.in_degree(3), 0);
+
+        let sequence = g.degree_sequence();
+        assert_eq!(sequence, vec![5, 3, 2, 0]);
+        assert_eq!(sequence.iter().sum::<usize>(), 2 * g.edge_count());
+    }
+
+    #[test]
+    fn from_edge_list_parses_lines() {
+        let text = "0 1\n  1\t2\n\n2 0\n";
+        let g = Graph::from_edge_list(3, false, text).expect("valid edge list");
+
+        assert_eq!(
+            g.into_adjacency_list(),
+            vec![vec![1, 2], vec![0, 2], vec![1, 0]]
+        );
+    }
+
+    #[test]
+    fn from_edge_list_reports_line_of_bad_input() {
+        let err = Graph::from_edge_list(3, true, "0 1\n1 x\n").unwrap_err();
+        assert_eq!(err.line, 2);
+        assert_eq!(err.kind, ParseErrorKind::InvalidToken("x".to_string()));
+        assert_eq!(err.to_string(), "line 2: invalid token `x`");
+
+        let err = Graph::from_edge_list(3, true, "0 1\n\n1 3\n").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 3: vertex 3 out of range for a graph with 3 vertices"
+        );
+
+        let err = Graph::from_edge_list(3, true, "0 1 2\n").unwrap_err();
+        assert_eq!(
+            err.kind,
+            ParseErrorKind::FieldCount {
+                expected: 2,
+                found: 3
+            }
+        );
+    }
+
     #[test]
     #[should_panic]
     fn transpose_panics_for_undirected() {
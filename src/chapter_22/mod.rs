@@ -2,16 +2,23 @@
 //!
 //! This chapter introduces fundamental graph traversals and their applications,
 //! including breadth-first search (BFS), depth-first search (DFS), topological
-//! sorting for directed acyclic graphs (DAGs), and the computation of strongly
-//! connected components (SCCs).
+//! sorting for directed acyclic graphs (DAGs), the computation of strongly
+//! connected components (SCCs), and the articulation points and bridges of
+//! undirected graphs. Graphs are stored as adjacency lists or, for large static
+//! graphs, in compressed sparse row form; traversals reach either through the
+//! `Neighbors` trait.
 
+pub mod articulation_points;
 pub mod breadth_first_search;
+pub mod csr_graph;
 pub mod depth_first_search;
 pub mod graph;
 pub mod strongly_connected_components;
 pub mod topological_sort;
 
+pub use articulation_points::*;
 pub use breadth_first_search::*;
+pub use csr_graph::*;
 pub use depth_first_search::*;
 pub use graph::*;
 pub use strongly_connected_components::*;
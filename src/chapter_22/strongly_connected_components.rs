@@ -45,10 +45,99 @@ fn collect_component(graph: &Graph, u: usize, visited: &mut [bool], component: &
     }
 }
 
+/// Computes the strongly connected components of a directed graph using
+/// Tarjan's single-pass algorithm.
+///
+/// One depth-first search assigns each vertex a discovery index and a
+/// low-link value, the smallest index reachable through its DFS subtree plus
+/// at most one back edge to a vertex still on the stack. A vertex whose
+/// low-link equals its own index is the root of a component, which is then
+/// popped off the stack. Components are produced in reverse topological order
+/// of the component graph.
+///
+/// # Panics
+///
+/// Panics if the graph is undirected.
+pub fn tarjan_scc(graph: &Graph) -> Vec<Vec<usize>> {
+    assert!(
+        graph.is_directed(),
+        "strongly connected components require a directed graph"
+    );
+
+    let vertex_count = graph.vertex_count();
+    let mut state = TarjanState {
+        next_index: 0,
+        index: vec![None; vertex_count],
+        low_link: vec![0; vertex_count],
+        on_stack: vec![false; vertex_count],
+        stack: Vec::new(),
+        components: Vec::new(),
+    };
+
+    for u in 0..vertex_count {
+        if state.index[u].is_none() {
+            tarjan_visit(graph, u, &mut state);
+        }
+    }
+
+    state.components
+}
+
+struct TarjanState {
+    next_index: usize,
+    index: Vec<Option<usize>>,
+    low_link: Vec<usize>,
+    on_stack: Vec<bool>,
+    stack: Vec<usize>,
+    components: Vec<Vec<usize>>,
+}
+
+fn tarjan_visit(graph: &Graph, u: usize, state: &mut TarjanState) {
+    state.index[u] = Some(state.next_index);
+    state.low_link[u] = state.next_index;
+    state.next_index += 1;
+    state.stack.push(u);
+    state.on_stack[u] = true;
+
+    for v in graph.neighbors_iter(u) {
+        match state.index[v] {
+            None => {
+                tarjan_visit(graph, v, state);
+                state.low_link[u] = state.low_link[u].min(state.low_link[v]);
+            }
+            Some(v_index) if state.on_stack[v] => {
+                state.low_link[u] = state.low_link[u].min(v_index);
+            }
+            Some(_) => {}
+        }
+    }
+
+    if Some(state.low_link[u]) == state.index[u] {
+        let mut component = Vec::new();
+        loop {
+            let w = state.stack.pop().expect("root is still on the stack");
+            state.on_stack[w] = false;
+            component.push(w);
+            if w == u {
+                break;
+            }
+        }
+        state.components.push(component);
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    fn normalized(mut components: Vec<Vec<usize>>) -> Vec<Vec<usize>> {
+        for component in &mut components {
+            component.sort_unstable();
+        }
+        components.sort_unstable_by_key(|component| component[0]);
+        components
+    }
+
     #[test]
     fn scc_example() {
         let mut graph = Graph::new(8, true);
@@ -84,4 +173,73 @@ mod tests {
 
         assert_eq!(components, vec![vec![0], vec![1], vec![2]]);
     }
+
+    #[test]
+    fn tarjan_agrees_with_kosaraju() {
+        let edge_lists: [(usize, &[(usize, usize)]); 5] = [
+            (
+                8,
+                &[
+                    (0, 1),
+                    (1, 2),
+                    (2, 0),
+                    (1, 3),
+                    (3, 4),
+                    (4, 5),
+                    (5, 3),
+                    (5, 6),
+                    (2, 6),
+                    (6, 7),
+                    (7, 6),
+                ],
+            ),
+            // Self-loops and a vertex with only a self-loop.
+            (5, &[(0, 0), (0, 1), (1, 1), (1, 2), (2, 1), (3, 3), (4, 0)]),
+            // CLRS Figure 22.9.
+            (
+                8,
+                &[
+                    (0, 1),
+                    (1, 2),
+                    (1, 4),
+                    (1, 5),
+                    (2, 3),
+                    (2, 6),
+                    (3, 2),
+                    (3, 7),
+                    (4, 0),
+                    (4, 5),
+                    (5, 6),
+                    (6, 5),
+                    (6, 7),
+                    (7, 7),
+                ],
+            ),
+            // A single directed cycle through every vertex.
+            (6, &[(0, 1), (1, 2), (2, 3), (3, 4), (4, 5), (5, 0)]),
+            // A DAG: every vertex is its own component.
+            (5, &[(0, 1), (0, 2), (1, 3), (2, 3), (3, 4)]),
+        ];
+
+        for (vertex_count, edges) in edge_lists {
+            let mut graph = Graph::new(vertex_count, true);
+            for &(u, v) in edges {
+                graph.add_edge(u, v);
+            }
+            assert_eq!(
+                normalized(tarjan_scc(&graph)),
+                normalized(strongly_connected_components(&graph))
+            );
+        }
+
+        let mut graph = Graph::new(4, true);
+        graph.add_edge(0, 1);
+        graph.add_edge(1, 0);
+        graph.add_edge(1, 2);
+        graph.add_edge(3, 3);
+        assert_eq!(
+            normalized(tarjan_scc(&graph)),
+            vec![vec![0, 1], vec![2], vec![3]]
+        );
+    }
 }
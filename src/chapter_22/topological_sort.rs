@@ -1,4 +1,4 @@
-use super::Graph;
+use super::Neighbors;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 enum Color {
@@ -14,16 +14,18 @@ pub enum TopologicalSortError {
 
 /// Returns a topological ordering of a directed acyclic graph.
 ///
+/// It runs on any graph representation that implements [`Neighbors`].
+///
 /// # Panics
 ///
 /// Panics if the input graph is undirected.
-pub fn topological_sort(graph: &Graph) -> Result<Vec<usize>, TopologicalSortError> {
+pub fn topological_sort<G: Neighbors>(graph: &G) -> Result<Vec<usize>, TopologicalSortError> {
     assert!(
         graph.is_directed(),
         "topological sort requires a directed graph"
     );
 
-    let vertex_count = graph.vertex_count();
+    let vertex_count = graph.num_vertices();
     let mut color = vec![Color::White; vertex_count];
     let mut order = Vec::with_capacity(vertex_count);
 
@@ -37,14 +39,14 @@ pub fn topological_sort(graph: &Graph) -> Result<Vec<usize>, TopologicalSortErro
     Ok(order)
 }
 
-fn dfs_visit(
-    graph: &Graph,
+fn dfs_visit<G: Neighbors>(
+    graph: &G,
     u: usize,
     color: &mut [Color],
     order: &mut Vec<usize>,
 ) -> Result<(), TopologicalSortError> {
     color[u] = Color::Gray;
-    for v in graph.neighbors_iter(u) {
+    for v in graph.neighbors(u) {
         match color[v] {
             Color::White => dfs_visit(graph, v, color, order)?,
             Color::Gray => return Err(TopologicalSortError::NotDag),
@@ -59,6 +61,7 @@ fn dfs_visit(
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::chapter_22::Graph;
 
     #[test]
     fn topo_sort_linear_graph() {
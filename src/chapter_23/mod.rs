@@ -1,12 +1,15 @@
 //! Chapter 23: Minimum Spanning Trees
 //!
 //! This module provides implementations of Kruskal's and Prim's algorithms for
-//! computing minimum spanning trees (MSTs) of weighted, undirected graphs.
+//! computing minimum spanning trees (MSTs) of weighted, undirected graphs, and
+//! a second-best MST search built on Kruskal's result.
 
 pub mod kruskal;
 pub mod prim;
+pub mod second_best_mst;
 pub mod weighted_graph;
 
 pub use kruskal::*;
 pub use prim::*;
+pub use second_best_mst::*;
 pub use weighted_graph::*;
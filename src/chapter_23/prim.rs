@@ -1,11 +1,14 @@
-use std::cmp::Reverse;
-use std::collections::BinaryHeap;
 use std::ops::Add;
 
 use super::{MstEdge, MstResult, WeightedGraph};
+use crate::chapter_06::IndexedMinHeap;
 
 /// Computes an MST using Prim's algorithm starting from `source`.
 ///
+/// Follows MST-PRIM: the queue holds the vertices adjacent to the tree, keyed
+/// by the lightest edge connecting each to it, and that key is lowered with
+/// DECREASE-KEY as the tree grows.
+///
 /// The algorithm returns the spanning tree for the connected component
 /// containing `source`. For disconnected graphs, the result will cover only the
 /// reachable vertices.
@@ -23,28 +26,39 @@ where
         };
     }
 
-    let mut visited = vec![false; vertex_count];
-    let mut heap: BinaryHeap<(Reverse<W>, usize, usize)> = BinaryHeap::new();
+    let mut in_tree = vec![false; vertex_count];
+    let mut parent = vec![source; vertex_count];
+    let mut queue = IndexedMinHeap::with_capacity(vertex_count);
     let mut mst_edges = Vec::new();
     let mut total_weight = W::default();
 
-    visited[source] = true;
-    for (v, weight) in graph.neighbors(source) {
-        heap.push((Reverse(weight), source, v));
-    }
+    queue.push(source, W::default());
 
-    while let Some((Reverse(weight), u, v)) = heap.pop() {
-        if visited[v] {
-            continue;
+    while let Some((u, key)) = queue.pop_min() {
+        in_tree[u] = true;
+        if u != source {
+            mst_edges.push(MstEdge {
+                u: parent[u],
+                v: u,
+                weight: key,
+            });
+            total_weight = total_weight + key;
         }
 
-        visited[v] = true;
-        mst_edges.push(MstEdge { u, v, weight });
-        total_weight = total_weight + weight;
-
-        for (next, next_weight) in graph.neighbors(v) {
-            if !visited[next] {
-                heap.push((Reverse(next_weight), v, next));
+        for (v, weight) in graph.neighbors(u) {
+            if in_tree[v] {
+                continue;
+            }
+            match queue.key(v) {
+                None => {
+                    parent[v] = u;
+                    queue.push(v, weight);
+                }
+                Some(&current) if weight < current => {
+                    parent[v] = u;
+                    queue.decrease_key(v, weight);
+                }
+                Some(_) => {}
             }
         }
     }
@@ -110,4 +124,33 @@ mod tests {
         );
         assert_eq!(mst.total_weight, 3);
     }
+
+    #[test]
+    fn prim_matches_kruskal_on_random_graphs() {
+        use crate::chapter_23::kruskal_mst;
+        use rand::rngs::StdRng;
+        use rand::{Rng, SeedableRng};
+
+        let mut rng = StdRng::seed_from_u64(23);
+        for _ in 0..50 {
+            let n = rng.gen_range(1..30);
+            let mut graph = WeightedGraph::new(n);
+            // A random spanning path keeps the graph connected.
+            for v in 1..n {
+                graph.add_edge(rng.gen_range(0..v), v, rng.gen_range(1..20));
+            }
+            for _ in 0..2 * n {
+                graph.add_edge(
+                    rng.gen_range(0..n),
+                    rng.gen_range(0..n),
+                    rng.gen_range(1..20),
+                );
+            }
+
+            let prim = prim_mst(&graph, rng.gen_range(0..n));
+            let kruskal = kruskal_mst(&graph);
+            assert_eq!(prim.edges.len(), n - 1);
+            assert_eq!(prim.total_weight, kruskal.total_weight);
+        }
+    }
 }
@@ -0,0 +1,169 @@
+use std::collections::HashMap;
+
+use super::{kruskal_mst, WeightedGraph};
+
+/// An edge `(u, v, weight)` with `u < v`.
+type Edge = (usize, usize, i64);
+
+/// Computes a second-best minimum spanning tree (CLRS Problem 23-1).
+///
+/// Starting from the MST found by [`kruskal_mst`], every spanning tree that
+/// differs from it in one edge is obtained by adding a non-tree edge `(u, v)`
+/// and dropping an edge of the tree path between `u` and `v`. The cheapest
+/// such swap drops the heaviest edge on that path, which a walk
+/// of the tree from each vertex finds for all pairs in O(V²) time. A
+/// second-best MST is the cheapest of these swaps; its weight may equal the
+/// MST weight when the minimum spanning tree is not unique.
+///
+/// Returns the tree's edges as `(u, v, weight)` with `u < v`, together with
+/// its total weight. Returns `None` when the graph has fewer than two spanning
+/// trees, that is, when it is disconnected or is itself a tree.
+pub fn second_best_mst(graph: &WeightedGraph<i64>) -> Option<(Vec<Edge>, i64)> {
+    let vertex_count = graph.vertex_count();
+    let mst = kruskal_mst(graph);
+    if mst.edges.len() + 1 != vertex_count {
+        return None;
+    }
+
+    let mut tree = vec![Vec::new(); vertex_count];
+    let mut unused_tree_edges: HashMap<Edge, usize> = HashMap::new();
+    for edge in &mst.edges {
+        tree[edge.u].push((edge.v, edge.weight));
+        tree[edge.v].push((edge.u, edge.weight));
+        *unused_tree_edges
+            .entry((edge.u, edge.v, edge.weight))
+            .or_default() += 1;
+    }
+    let heaviest = heaviest_path_edges(&tree);
+
+    let mut best: Option<(i64, Edge, Edge)> = None;
+    for (u, v, weight) in graph.edges() {
+        // Parallel copies of a tree edge beyond those in the tree are non-tree edges.
+        if let Some(count) = unused_tree_edges.get_mut(&(u, v, weight)) {
+            if *count > 0 {
+                *count -= 1;
+                continue;
+            }
+        }
+        let dropped = heaviest[u][v].expect("u and v are joined by a tree path");
+        let total = mst.total_weight - dropped.2 + weight;
+        if best.is_none_or(|(best_total, _, _)| total < best_total) {
+            best = Some((total, dropped, (u, v, weight)));
+        }
+    }
+
+    let (total, dropped, added) = best?;
+    let mut edges: Vec<_> = mst.edges.iter().map(|e| (e.u, e.v, e.weight)).collect();
+    let position = edges
+        .iter()
+        .position(|&edge| edge == dropped)
+        .expect("the dropped edge is a tree edge");
+    edges[position] = added;
+    Some((edges, total))
+}
+
+/// For every pair of vertices `u`, `v`, finds the heaviest edge on the path
+/// joining them in `tree`, or `None` for `u == v`.
+fn heaviest_path_edges(tree: &[Vec<(usize, i64)>]) -> Vec<Vec<Option<Edge>>> {
+    let vertex_count = tree.len();
+    let mut heaviest: Vec<Vec<Option<Edge>>> = vec![vec![None; vertex_count]; vertex_count];
+
+    for (source, row) in heaviest.iter_mut().enumerate() {
+        let mut stack = vec![(source, usize::MAX)];
+        while let Some((u, parent)) = stack.pop() {
+            for &(v, weight) in &tree[u] {
+                if v == parent {
+                    continue;
+                }
+                let edge = (u.min(v), u.max(v), weight);
+                row[v] = match row[u] {
+                    Some(max_edge) if max_edge.2 >= weight => Some(max_edge),
+                    _ => Some(edge),
+                };
+                stack.push((v, u));
+            }
+        }
+    }
+    heaviest
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn weight_of(edges: &[(usize, usize, i64)]) -> i64 {
+        edges.iter().map(|&(_, _, weight)| weight).sum()
+    }
+
+    #[test]
+    fn second_best_mst_of_small_graph() {
+        //    0 --1-- 1
+        //    |     / |
+        //    4   2   3
+        //    | /     |
+        //    2 --5-- 3
+        let mut graph = WeightedGraph::new(4);
+        graph.add_edge(0, 1, 1);
+        graph.add_edge(0, 2, 4);
+        graph.add_edge(1, 2, 2);
+        graph.add_edge(1, 3, 3);
+        graph.add_edge(2, 3, 5);
+
+        // The MST {0-1, 1-2, 1-3} weighs 6. Adding 0-2 and dropping 1-2 gives
+        // 8, adding 2-3 and dropping 1-3 gives 8 as well; nothing is cheaper.
+        let (edges, total) = second_best_mst(&graph).expect("graph has a cycle");
+        assert_eq!(total, 8);
+        assert_eq!(edges.len(), 3);
+        assert_eq!(weight_of(&edges), total);
+        assert_ne!(total, kruskal_mst(&graph).total_weight);
+    }
+
+    #[test]
+    fn second_best_mst_clrs_figure() {
+        // CLRS Figure 23.1: the MST weighs 37, and swapping b-c for a-h
+        // (both weight 8) gives another tree of the same weight.
+        let mut graph = WeightedGraph::new(9);
+        for &(u, v, w) in &[
+            (0, 1, 4),
+            (0, 7, 8),
+            (1, 7, 11),
+            (1, 2, 8),
+            (7, 8, 7),
+            (7, 6, 1),
+            (2, 8, 2),
+            (8, 6, 6),
+            (2, 5, 4),
+            (6, 5, 2),
+            (2, 3, 7),
+            (3, 5, 14),
+            (3, 4, 9),
+            (5, 4, 10),
+        ] {
+            graph.add_edge(u, v, w);
+        }
+        let (edges, total) = second_best_mst(&graph).expect("graph has cycles");
+        assert_eq!(total, 37);
+        assert_eq!(edges.len(), 8);
+        assert_eq!(weight_of(&edges), 37);
+    }
+
+    #[test]
+    fn second_best_mst_needs_two_spanning_trees() {
+        let mut tree = WeightedGraph::new(3);
+        tree.add_edge(0, 1, 1);
+        tree.add_edge(1, 2, 1);
+        assert_eq!(second_best_mst(&tree), None);
+
+        let mut disconnected = WeightedGraph::new(4);
+        disconnected.add_edge(0, 1, 1);
+        disconnected.add_edge(1, 2, 1);
+        disconnected.add_edge(2, 0, 1);
+        assert_eq!(second_best_mst(&disconnected), None);
+
+        // A parallel edge is a second way to span two vertices.
+        let mut parallel = WeightedGraph::new(2);
+        parallel.add_edge(0, 1, 3);
+        parallel.add_edge(0, 1, 5);
+        assert_eq!(second_best_mst(&parallel), Some((vec![(0, 1, 5)], 5)));
+    }
+}
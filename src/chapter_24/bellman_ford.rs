@@ -1,3 +1,4 @@
+use std::collections::VecDeque;
 use std::ops::Add;
 
 use super::{ShortestPathResult, WeightedDigraph};
@@ -7,6 +8,33 @@ pub enum BellmanFordError {
     NegativeCycle,
 }
 
+/// The shortest-path weight from the source to one vertex.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Distance<W> {
+    /// The shortest path has this weight.
+    Finite(W),
+    /// A negative cycle lies on some path to the vertex, so paths of
+    /// arbitrarily negative weight exist.
+    NegInfinity,
+    /// No path leads from the source to the vertex.
+    Unreachable,
+}
+
+/// Shortest-path weights from one source that stay meaningful in the
+/// presence of negative cycles.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BellmanFordResult<W> {
+    pub source: usize,
+    pub distances: Vec<Distance<W>>,
+}
+
+impl<W: Copy> BellmanFordResult<W> {
+    /// Returns the shortest-path weight to `v`.
+    pub fn distance(&self, v: usize) -> Distance<W> {
+        self.distances[v]
+    }
+}
+
 /// Runs the Bellman-Ford algorithm from `source`.
 ///
 /// Returns the shortest-path estimates when no negative cycles are reachable
@@ -15,79 +43,117 @@ pub fn bellman_ford<W>(
     graph: &WeightedDigraph<W>,
     source: usize,
 ) -> Result<ShortestPathResult<W>, BellmanFordError>
+where
+    W: Copy + PartialOrd + Add<Output = W> + Default,
+{
+    bellman_ford_passes(graph, source).map(|(result, _)| result)
+}
+
+/// Runs Bellman-Ford and also reports how many relaxation passes it made.
+///
+/// A pass relaxes every edge once. The loop stops after at most `V - 1`
+/// passes, or earlier as soon as a pass changes no estimate; that final
+/// quiet pass is included in the count. The negative-cycle check over all
+/// edges always runs afterwards, so the outcome matches the full algorithm.
+pub fn bellman_ford_passes<W>(
+    graph: &WeightedDigraph<W>,
+    source: usize,
+) -> Result<(ShortestPathResult<W>, usize), BellmanFordError>
 where
     W: Copy + PartialOrd + Add<Output = W> + Default,
 {
     let vertex_count = graph.vertex_count();
     assert!(source < vertex_count, "source vertex out of bounds");
 
-    let mut distances: Vec<Option<W>> = vec![None; vertex_count];
-    let mut predecessors = vec![None; vertex_count];
-    distances[source] = Some(W::default());
+    let edges = graph.edges();
+    let mut result = ShortestPathResult::initialize_single_source(vertex_count, source);
+    let passes = relaxation_passes(&edges, &mut result);
 
-    for _ in 0..vertex_count.saturating_sub(1) {
-        let mut updated = false;
-        for (u, v, weight) in graph.edges() {
-            if relax(u, v, weight, &mut distances, &mut predecessors) {
-                updated = true;
-            }
-        }
-        if !updated {
-            break;
-        }
-    }
-
-    for (u, v, weight) in graph.edges() {
-        if let Some(new_distance) = candidate_distance(u, weight, &distances) {
-            if let Some(current) = distances[v] {
-                if new_distance < current {
-                    return Err(BellmanFordError::NegativeCycle);
-                }
+    for &(u, v, weight) in &edges {
+        if let (Some(distance_u), Some(current)) = (result.distances[u], result.distances[v]) {
+            if distance_u + weight < current {
+                return Err(BellmanFordError::NegativeCycle);
             }
         }
     }
 
-    Ok(ShortestPathResult {
-        source,
-        distances,
-        predecessors,
-    })
+    Ok((result, passes))
 }
 
-fn candidate_distance<W>(u: usize, weight: W, distances: &[Option<W>]) -> Option<W>
+/// Runs Bellman-Ford, marking the vertices whose distance is −∞ instead of
+/// failing when a negative cycle is reachable.
+///
+/// After the usual `V - 1` passes, any edge that can still be relaxed leads
+/// into a vertex that is on or reachable from a negative cycle. Those
+/// vertices, and everything reachable from them, get
+/// [`Distance::NegInfinity`]; the other reached vertices keep their finite
+/// distances, which are then exact.
+pub fn bellman_ford_with_neg_inf<W>(
+    graph: &WeightedDigraph<W>,
+    source: usize,
+) -> BellmanFordResult<W>
 where
-    W: Copy + Add<Output = W>,
+    W: Copy + PartialOrd + Add<Output = W> + Default,
 {
-    distances[u].map(|distance_u| distance_u + weight)
+    let vertex_count = graph.vertex_count();
+    assert!(source < vertex_count, "source vertex out of bounds");
+
+    let edges = graph.edges();
+    let mut result = ShortestPathResult::initialize_single_source(vertex_count, source);
+    relaxation_passes(&edges, &mut result);
+
+    let mut negative = vec![false; vertex_count];
+    let mut queue = VecDeque::new();
+    for &(u, v, weight) in &edges {
+        if let (Some(distance_u), Some(current)) = (result.distances[u], result.distances[v]) {
+            if distance_u + weight < current && !negative[v] {
+                negative[v] = true;
+                queue.push_back(v);
+            }
+        }
+    }
+    while let Some(u) = queue.pop_front() {
+        for (v, _) in graph.neighbors(u) {
+            if !negative[v] {
+                negative[v] = true;
+                queue.push_back(v);
+            }
+        }
+    }
+
+    let distances = result
+        .distances
+        .iter()
+        .zip(&negative)
+        .map(|(&distance, &negative)| match distance {
+            None => Distance::Unreachable,
+            Some(_) if negative => Distance::NegInfinity,
+            Some(d) => Distance::Finite(d),
+        })
+        .collect();
+    BellmanFordResult { source, distances }
 }
 
-fn relax<W>(
-    u: usize,
-    v: usize,
-    weight: W,
-    distances: &mut [Option<W>],
-    predecessors: &mut [Option<usize>],
-) -> bool
+/// Relaxes every edge up to `V - 1` times, stopping after the first pass
+/// that changes no estimate, and returns the number of passes made.
+fn relaxation_passes<W>(edges: &[(usize, usize, W)], result: &mut ShortestPathResult<W>) -> usize
 where
-    W: Copy + PartialOrd + Add<Output = W>,
+    W: Copy + PartialOrd + Add<Output = W> + Default,
 {
-    let Some(new_distance) = candidate_distance(u, weight, distances) else {
-        return false;
-    };
-
-    match distances[v] {
-        None => {
-            distances[v] = Some(new_distance);
-            predecessors[v] = Some(u);
-            true
+    let mut passes = 0;
+    for _ in 0..result.distances.len().saturating_sub(1) {
+        passes += 1;
+        let mut updated = false;
+        for &(u, v, weight) in edges {
+            if result.relax(u, v, weight) {
+                updated = true;
+            }
         }
-        Some(current) if new_distance < current => {
-            distances[v] = Some(new_distance);
-            predecessors[v] = Some(u);
-            true
+        if !updated {
+            break;
         }
-        _ => false,
     }
+    passes
 }
 
 #[cfg(test)]
@@ -131,4 +197,85 @@ mod tests {
         let result = bellman_ford(&graph, 0);
         assert_eq!(result, Err(BellmanFordError::NegativeCycle));
     }
+
+    #[test]
+    fn stops_after_a_quiet_pass() {
+        // Edges are relaxed in order of their source vertex, which here follows
+        // the paths, so the first pass settles everything and the second pass
+        // confirms convergence.
+        let mut graph = WeightedDigraph::new(6);
+        graph.add_edge(0, 1, 3);
+        graph.add_edge(1, 2, -1);
+        graph.add_edge(2, 3, 4);
+        graph.add_edge(3, 4, 2);
+        graph.add_edge(0, 4, 10);
+
+        let (result, passes) = bellman_ford_passes(&graph, 0).expect("no negative cycle");
+        assert_eq!(passes, 2);
+        assert_eq!(Ok(result.clone()), bellman_ford(&graph, 0));
+        assert_eq!(result.distance(4), Some(8));
+        assert_eq!(result.distance(5), None);
+
+        // A path running against the relaxation order needs all V - 1 passes.
+        let mut graph = WeightedDigraph::new(4);
+        graph.add_edge(3, 2, 1);
+        graph.add_edge(2, 1, 1);
+        graph.add_edge(1, 0, 1);
+        let (result, passes) = bellman_ford_passes(&graph, 3).expect("no negative cycle");
+        assert_eq!(passes, 3);
+        assert_eq!(result.distance(0), Some(3));
+
+        let mut cyclic = WeightedDigraph::new(3);
+        cyclic.add_edge(0, 1, 1);
+        cyclic.add_edge(1, 2, -1);
+        cyclic.add_edge(2, 0, -1);
+        assert_eq!(
+            bellman_ford_passes(&cyclic, 0),
+            Err(BellmanFordError::NegativeCycle)
+        );
+    }
+
+    #[test]
+    fn marks_vertices_behind_a_negative_cycle() {
+        // 0 -> 1 -> 2 <-> 3 is a negative cycle feeding 4; 5 hangs off the
+        // source, 6 is unreachable and only leads into the cycle.
+        let mut graph = WeightedDigraph::new(7);
+        graph.add_edge(0, 1, 4);
+        graph.add_edge(1, 2, 1);
+        graph.add_edge(2, 3, -3);
+        graph.add_edge(3, 2, 1);
+        graph.add_edge(3, 4, 2);
+        graph.add_edge(0, 5, 7);
+        graph.add_edge(5, 1, -6);
+        graph.add_edge(6, 3, 1);
+
+        assert_eq!(
+            bellman_ford(&graph, 0),
+            Err(BellmanFordError::NegativeCycle)
+        );
+        let result = bellman_ford_with_neg_inf(&graph, 0);
+        assert_eq!(
+            result.distances,
+            vec![
+                Distance::Finite(0),
+                Distance::Finite(1),
+                Distance::NegInfinity,
+                Distance::NegInfinity,
+                Distance::NegInfinity,
+                Distance::Finite(7),
+                Distance::Unreachable,
+            ]
+        );
+        assert_eq!(result.distance(5), Distance::Finite(7));
+
+        // No cycle is reachable from 4, so every reached vertex is finite.
+        let from_four = bellman_ford_with_neg_inf(&graph, 4);
+        assert_eq!(from_four.distance(4), Distance::Finite(0));
+        assert_eq!(from_four.distance(0), Distance::Unreachable);
+
+        // From 5 the cycle lies beyond 1, whose distance stays finite.
+        let from_five = bellman_ford_with_neg_inf(&graph, 5);
+        assert_eq!(from_five.distance(1), Distance::Finite(-6));
+        assert_eq!(from_five.distance(3), Distance::NegInfinity);
+    }
 }
@@ -20,28 +20,91 @@ where
     assert!(source < vertex_count, "source vertex out of bounds");
 
     let order = topological_order(graph).ok_or(DagShortestPathsError::NotDag)?;
+    Ok(dag_shortest_paths_with_order(graph, source, &order))
+}
+
+/// Computes shortest paths in a DAG by relaxing along a given topological order.
+///
+/// The order is trusted, so one call to [`topological_order`] can serve many
+/// sources. In debug builds it is checked to be a permutation of the vertices
+/// in which every edge points forward; in release builds an invalid order
+/// silently yields wrong distances.
+pub fn dag_shortest_paths_with_order<W>(
+    graph: &WeightedDigraph<W>,
+    source: usize,
+    topo_order: &[usize],
+) -> ShortestPathResult<W>
+where
+    W: Copy + Add<Output = W> + PartialOrd + Default,
+{
+    let vertex_count = graph.vertex_count();
+    assert!(source < vertex_count, "source vertex out of bounds");
+    debug_assert!(
+        is_topological_order(graph, topo_order),
+        "topo_order is not a topological order of the graph"
+    );
 
-    let mut distances = vec![None; vertex_count];
-    let mut predecessors = vec![None; vertex_count];
-    distances[source] = Some(W::default());
+    let mut result = ShortestPathResult::initialize_single_source(vertex_count, source);
 
-    for &u in &order {
-        if distances[u].is_none() {
+    for &u in topo_order {
+        if result.distances[u].is_none() {
             continue;
         }
         for (v, weight) in graph.neighbors(u) {
-            relax(u, v, weight, &mut distances, &mut predecessors);
+            result.relax(u, v, weight);
+        }
+    }
+
+    result
+}
+
+/// Runs a dynamic program over a DAG, visiting vertices in topological order.
+///
+/// Every vertex starts with a clone of `init`. Each vertex `u` is then taken
+/// in topological order and `relax(u, v, weight, &value[u], &mut value[v])`
+/// is called for each of its out-edges, so by the time `u` is reached every
+/// edge into it has been relaxed and its value is final. This is the
+/// traversal behind [`dag_shortest_paths`], with the relaxation step left to
+/// the caller: counting paths, longest paths and similar DAG recurrences are
+/// all expressed by choosing `init` and `relax`.
+///
+/// Returns the final value of every vertex, or an error if the graph has a
+/// cycle.
+pub fn dp_over_dag<W, T, F>(
+    graph: &WeightedDigraph<W>,
+    init: T,
+    mut relax: F,
+) -> Result<Vec<T>, DagShortestPathsError>
+where
+    W: Copy,
+    T: Clone,
+    F: FnMut(usize, usize, W, &T, &mut T),
+{
+    let order = topological_order(graph).ok_or(DagShortestPathsError::NotDag)?;
+    let mut values = vec![init; graph.vertex_count()];
+
+    for &u in &order {
+        for (v, weight) in graph.neighbors(u) {
+            // u != v because a self-loop would be a cycle.
+            let (from, to) = if u < v {
+                let (head, tail) = values.split_at_mut(v);
+                (&head[u], &mut tail[0])
+            } else {
+                let (head, tail) = values.split_at_mut(u);
+                (&tail[0], &mut head[v])
+            };
+            relax(u, v, weight, from, to);
         }
     }
 
-    Ok(ShortestPathResult {
-        source,
-        distances,
-        predecessors,
-    })
+    Ok(values)
 }
 
-fn topological_order<W>(graph: &WeightedDigraph<W>) -> Option<Vec<usize>>
+/// Returns a topological order of the vertices, or `None` if the graph has a cycle.
+///
+/// Vertices are emitted as their remaining in-degree drops to zero (Kahn's
+/// algorithm), in O(V + E) time.
+pub fn topological_order<W>(graph: &WeightedDigraph<W>) -> Option<Vec<usize>>
 where
     W: Copy,
 {
@@ -75,31 +138,23 @@ where
     }
 }
 
-fn relax<W>(
-    u: usize,
-    v: usize,
-    weight: W,
-    distances: &mut [Option<W>],
-    predecessors: &mut [Option<usize>],
-) where
-    W: Copy + Add<Output = W> + PartialOrd,
+fn is_topological_order<W>(graph: &WeightedDigraph<W>, order: &[usize]) -> bool
+where
+    W: Copy,
 {
-    let Some(distance_u) = distances[u] else {
-        return;
-    };
-    let candidate = distance_u + weight;
-
-    match distances[v] {
-        None => {
-            distances[v] = Some(candidate);
-            predecessors[v] = Some(u);
-        }
-        Some(current) if candidate < current => {
-            distances[v] = Some(candidate);
-            predecessors[v] = Some(u);
+    let vertex_count = graph.vertex_count();
+    let mut position = vec![None; vertex_count];
+    for (index, &v) in order.iter().enumerate() {
+        if v >= vertex_count || position[v].is_some() {
+            return false;
         }
-        _ => {}
+        position[v] = Some(index);
     }
+    order.len() == vertex_count
+        && graph
+            .edges()
+            .iter()
+            .all(|&(u, v, _)| position[u] < position[v])
 }
 
 #[cfg(test)]
@@ -139,4 +194,86 @@ mod tests {
         let result = dag_shortest_paths(&graph, 0);
         assert_eq!(result, Err(DagShortestPathsError::NotDag));
     }
+
+    fn figure_24_5() -> WeightedDigraph<i32> {
+        let mut graph = WeightedDigraph::new(6);
+        for &(u, v, w) in &[
+            (0, 1, 5),
+            (0, 2, 3),
+            (1, 2, 2),
+            (1, 3, 6),
+            (2, 3, 7),
+            (2, 4, 4),
+            (2, 5, 2),
+            (3, 4, -1),
+            (3, 5, 1),
+            (4, 5, -2),
+        ] {
+            graph.add_edge(u, v, w);
+        }
+        graph
+    }
+
+    #[test]
+    fn reuses_one_topological_order() {
+        let graph = figure_24_5();
+        let order = topological_order(&graph).expect("should be a DAG");
+        for source in 0..graph.vertex_count() {
+            assert_eq!(
+                Ok(dag_shortest_paths_with_order(&graph, source, &order)),
+                dag_shortest_paths(&graph, source)
+            );
+        }
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "not a topological order")]
+    fn rejects_invalid_order_in_debug_builds() {
+        let graph = figure_24_5();
+        dag_shortest_paths_with_order(&graph, 0, &[1, 0, 2, 3, 4, 5]);
+    }
+
+    #[test]
+    fn dp_over_dag_counts_and_longest_paths() {
+        //   0 -> 1 -> 3 -> 5
+        //   |    |    ^    ^
+        //   v    v    |    |
+        //   2 -> 4 ---+----+
+        let mut graph = WeightedDigraph::new(6);
+        for &(u, v, w) in &[
+            (0, 1, 1),
+            (0, 2, 4),
+            (1, 3, 2),
+            (1, 4, 1),
+            (2, 4, 3),
+            (3, 5, 1),
+            (4, 3, 2),
+            (4, 5, 6),
+        ] {
+            graph.add_edge(u, v, w);
+        }
+
+        // Paths from 0: the source contributes one path of its own along
+        // each out-edge; every other vertex passes on the paths reaching it.
+        let source = 0;
+        let paths = dp_over_dag(&graph, 0u64, |u, _, _, from, to| {
+            *to += if u == source { 1 } else { *from };
+        })
+        .expect("graph is a DAG");
+        assert_eq!(paths, vec![0, 1, 1, 3, 2, 5]);
+
+        // Longest path into each vertex from anywhere.
+        let longest = dp_over_dag(&graph, 0i64, |_, _, weight, from, to| {
+            *to = (*to).max(from + weight);
+        })
+        .unwrap();
+        assert_eq!(longest, vec![0, 1, 4, 9, 7, 13]);
+
+        graph.add_edge(5, 0, 1);
+        assert_eq!(
+            dp_over_dag(&graph, (), |_, _, _, _, _| {}),
+            Err(DagShortestPathsError::NotDag)
+        );
+    }
 }
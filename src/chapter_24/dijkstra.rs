@@ -1,8 +1,8 @@
-use std::cmp::Reverse;
-use std::collections::BinaryHeap;
 use std::ops::Add;
 
-use super::{ShortestPathResult, WeightedDigraph};
+use super::{bellman_ford, BellmanFordError, ShortestPathResult, WeightedDigraph};
+use crate::chapter_06::IndexedMinHeap;
+use crate::chapter_19::{FibNodeHandle, FibonacciHeap};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum DijkstraError {
@@ -11,6 +11,9 @@ pub enum DijkstraError {
 
 /// Computes shortest paths from `source` using Dijkstra's algorithm.
 ///
+/// Vertices enter the queue when first reached and are moved forward with
+/// DECREASE-KEY whenever an edge relaxation shortens their estimate.
+///
 /// Returns an error if a negative-weight edge is present in the graph.
 pub fn dijkstra<W>(
     graph: &WeightedDigraph<W>,
@@ -22,49 +25,90 @@ where
     let vertex_count = graph.vertex_count();
     assert!(source < vertex_count, "source vertex out of bounds");
 
-    for (_, _, weight) in graph.edges() {
-        if weight < W::default() {
-            return Err(DijkstraError::NegativeEdgeWeight);
+    if graph.has_negative_edge() {
+        return Err(DijkstraError::NegativeEdgeWeight);
+    }
+
+    let mut result = ShortestPathResult::initialize_single_source(vertex_count, source);
+    let mut queue = IndexedMinHeap::with_capacity(vertex_count);
+    queue.push(source, W::default());
+
+    while let Some((u, distance_u)) = queue.pop_min() {
+        for (v, weight) in graph.neighbors(u) {
+            if result.relax(u, v, weight) {
+                let distance_v = distance_u + weight;
+                if queue.contains(v) {
+                    queue.decrease_key(v, distance_v);
+                } else {
+                    queue.push(v, distance_v);
+                }
+            }
         }
     }
 
-    let mut distances = vec![None; vertex_count];
-    let mut predecessors = vec![None; vertex_count];
-    let mut visited = vec![false; vertex_count];
-    let mut heap: BinaryHeap<(Reverse<W>, usize)> = BinaryHeap::new();
+    Ok(result)
+}
 
-    distances[source] = Some(W::default());
-    heap.push((Reverse(W::default()), source));
+/// Computes shortest paths from `source` using Dijkstra's algorithm with a
+/// Fibonacci-heap priority queue.
+///
+/// Every vertex is inserted when first reached, and DECREASE-KEY runs in O(1)
+/// amortized time, so the whole search takes O(V lg V + E) time (CLRS Section
+/// 24.3). The results are the same as those of [`dijkstra`].
+///
+/// Returns an error if a negative-weight edge is present in the graph.
+pub fn dijkstra_fib<W>(
+    graph: &WeightedDigraph<W>,
+    source: usize,
+) -> Result<ShortestPathResult<W>, DijkstraError>
+where
+    W: Copy + Ord + Add<Output = W> + Default,
+{
+    let vertex_count = graph.vertex_count();
+    assert!(source < vertex_count, "source vertex out of bounds");
 
-    while let Some((Reverse(distance_u), u)) = heap.pop() {
-        if visited[u] {
-            continue;
-        }
-        visited[u] = true;
+    if graph.has_negative_edge() {
+        return Err(DijkstraError::NegativeEdgeWeight);
+    }
 
+    let mut result = ShortestPathResult::initialize_single_source(vertex_count, source);
+    let mut queue = FibonacciHeap::new();
+    let mut handles: Vec<Option<FibNodeHandle<W, usize>>> = vec![None; vertex_count];
+    handles[source] = Some(queue.insert(W::default(), source));
+
+    while let Some((distance_u, u)) = queue.extract_min() {
         for (v, weight) in graph.neighbors(u) {
-            let candidate = distance_u + weight;
-            match distances[v] {
-                None => {
-                    distances[v] = Some(candidate);
-                    predecessors[v] = Some(u);
-                    heap.push((Reverse(candidate), v));
-                }
-                Some(current) if candidate < current => {
-                    distances[v] = Some(candidate);
-                    predecessors[v] = Some(u);
-                    heap.push((Reverse(candidate), v));
+            if result.relax(u, v, weight) {
+                let distance_v = distance_u + weight;
+                // A settled vertex is never relaxed again, so an existing
+                // handle always refers to a node still in the heap.
+                match &handles[v] {
+                    Some(handle) => queue.decrease_key(handle, distance_v),
+                    None => handles[v] = Some(queue.insert(distance_v, v)),
                 }
-                _ => {}
             }
         }
     }
 
-    Ok(ShortestPathResult {
-        source,
-        distances,
-        predecessors,
-    })
+    Ok(result)
+}
+
+/// Computes shortest paths from `source`, using Dijkstra's algorithm when all
+/// edge weights are non-negative and falling back to Bellman-Ford otherwise.
+///
+/// Returns an error only if a negative cycle is reachable from the source.
+pub fn single_source_shortest_paths<W>(
+    graph: &WeightedDigraph<W>,
+    source: usize,
+) -> Result<ShortestPathResult<W>, BellmanFordError>
+where
+    W: Copy + Ord + Add<Output = W> + Default,
+{
+    if graph.has_negative_edge() {
+        bellman_ford(graph, source)
+    } else {
+        Ok(dijkstra(graph, source).expect("weights were checked to be non-negative"))
+    }
 }
 
 #[cfg(test)]
@@ -102,4 +146,146 @@ mod tests {
         let result = dijkstra(&graph, 0);
         assert_eq!(result, Err(DijkstraError::NegativeEdgeWeight));
     }
+
+    #[test]
+    fn single_source_routes_on_negative_edges() {
+        let mut graph = WeightedDigraph::new(4);
+        graph.add_edge(0, 1, 4);
+        graph.add_edge(0, 2, 1);
+        graph.add_edge(2, 1, 2);
+        graph.add_edge(1, 3, 1);
+
+        let routed = single_source_shortest_paths(&graph, 0).expect("no negative cycle");
+        assert_eq!(Ok(routed.clone()), dijkstra(&graph, 0));
+        assert_eq!(routed.distance(3), Some(4));
+
+        // A negative edge rules out Dijkstra, so Bellman-Ford takes over.
+        graph.add_edge(2, 3, -5);
+        assert_eq!(dijkstra(&graph, 0), Err(DijkstraError::NegativeEdgeWeight));
+        let routed = single_source_shortest_paths(&graph, 0).expect("no negative cycle");
+        assert_eq!(Ok(routed.clone()), bellman_ford(&graph, 0));
+        assert_eq!(routed.distance(3), Some(-4));
+
+        graph.add_edge(3, 2, 1);
+        assert_eq!(
+            single_source_shortest_paths(&graph, 0),
+            Err(BellmanFordError::NegativeCycle)
+        );
+    }
+
+    #[test]
+    fn dijkstra_matches_bellman_ford_on_random_graphs() {
+        use rand::rngs::StdRng;
+        use rand::{Rng, SeedableRng};
+
+        let mut rng = StdRng::seed_from_u64(24);
+        for _ in 0..50 {
+            let n = rng.gen_range(1..30);
+            let mut graph = WeightedDigraph::new(n);
+            for _ in 0..3 * n {
+                graph.add_edge(
+                    rng.gen_range(0..n),
+                    rng.gen_range(0..n),
+                    rng.gen_range(0..20),
+                );
+            }
+
+            let source = rng.gen_range(0..n);
+            let fast = dijkstra(&graph, source).expect("weights are non-negative");
+            let reference = bellman_ford(&graph, source).expect("no negative cycle");
+            assert_eq!(fast.distances, reference.distances);
+            for v in 0..n {
+                if let Some(path) = fast.path_to(v) {
+                    let length: i64 = path
+                        .windows(2)
+                        .map(|w| {
+                            graph
+                                .neighbors(w[0])
+                                .filter(|&(x, _)| x == w[1])
+                                .map(|(_, weight)| weight)
+                                .min()
+                                .unwrap()
+                        })
+                        .sum();
+                    assert_eq!(Some(length), fast.distance(v));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn dijkstra_fib_matches_dijkstra() {
+        use rand::rngs::StdRng;
+        use rand::{Rng, SeedableRng};
+
+        let mut rng = StdRng::seed_from_u64(19);
+        for _ in 0..50 {
+            let n = rng.gen_range(1..40);
+            let mut graph = WeightedDigraph::new(n);
+            for _ in 0..4 * n {
+                graph.add_edge(
+                    rng.gen_range(0..n),
+                    rng.gen_range(0..n),
+                    rng.gen_range(0..20),
+                );
+            }
+            let source = rng.gen_range(0..n);
+            let expected = dijkstra(&graph, source).expect("weights are non-negative");
+            let result = dijkstra_fib(&graph, source).expect("weights are non-negative");
+            assert_eq!(result.distances, expected.distances);
+        }
+
+        let mut graph = WeightedDigraph::new(2);
+        graph.add_edge(0, 1, -1);
+        assert_eq!(
+            dijkstra_fib(&graph, 0),
+            Err(DijkstraError::NegativeEdgeWeight)
+        );
+    }
+
+    #[test]
+    fn shortest_path_tree_matches_distances() {
+        // CLRS Figure 24.6, plus a vertex 5 that cannot be reached.
+        let mut graph = WeightedDigraph::new(6);
+        for &(u, v, w) in &[
+            (0, 1, 10),
+            (0, 3, 5),
+            (1, 2, 1),
+            (1, 3, 2),
+            (2, 4, 4),
+            (3, 1, 3),
+            (3, 2, 9),
+            (3, 4, 2),
+            (4, 0, 7),
+            (4, 2, 6),
+            (5, 0, 1),
+        ] {
+            graph.add_edge(u, v, w);
+        }
+
+        let result = dijkstra(&graph, 0).expect("non-negative weights");
+        let tree = result.shortest_path_tree();
+        let reachable = result.distances.iter().flatten().count();
+        assert_eq!(reachable, 5);
+        assert_eq!(tree.edges().len(), reachable - 1);
+
+        // Every tree edge is a graph edge, and summing weights down the tree
+        // reproduces each distance.
+        let mut tree_distances = vec![None; 6];
+        tree_distances[0] = Some(0);
+        let mut stack = vec![0];
+        while let Some(u) = stack.pop() {
+            for (v, w) in tree.neighbors(u) {
+                assert!(graph.neighbors(u).any(|edge| edge == (v, w)));
+                assert_eq!(tree_distances[v], None, "each vertex has one parent");
+                tree_distances[v] = Some(tree_distances[u].unwrap() + w);
+                stack.push(v);
+            }
+        }
+        assert_eq!(tree_distances, result.distances);
+        assert_eq!(
+            tree.edges().iter().map(|&(_, _, w)| w).sum::<i32>(),
+            3 + 5 + 1 + 2
+        );
+    }
 }
@@ -1,5 +1,9 @@
 use std::fmt;
 use std::ops::{Add, Sub};
+use std::str::FromStr;
+
+use crate::chapter_22::graph::{edge_list_lines, parse_vertex};
+use crate::chapter_22::{ParseError, ParseErrorKind};
 
 /// A weighted directed graph represented by adjacency lists.
 ///
@@ -54,6 +58,61 @@ where
     }
 }
 
+impl<W> WeightedDigraph<W>
+where
+    W: Copy + PartialOrd + Default,
+{
+    /// Returns the smallest edge weight, or `None` if the graph has no edges.
+    pub fn min_edge_weight(&self) -> Option<W> {
+        self.adjacency_list
+            .iter()
+            .flatten()
+            .map(|&(_, weight)| weight)
+            .fold(None, |min, weight| match min {
+                Some(current) if current <= weight => Some(current),
+                _ => Some(weight),
+            })
+    }
+
+    /// Returns whether any edge has a negative weight, i.e. whether Dijkstra's
+    /// algorithm is inapplicable.
+    pub fn has_negative_edge(&self) -> bool {
+        self.adjacency_list
+            .iter()
+            .flatten()
+            .any(|&(_, weight)| weight < W::default())
+    }
+}
+
+impl<W> WeightedDigraph<W>
+where
+    W: Copy + FromStr,
+{
+    /// Parses a graph with `vertex_count` vertices from an edge list.
+    ///
+    /// Each non-blank line of `text` holds one edge `u v w`: two vertex
+    /// indices followed by a weight, separated by whitespace.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ParseError`] naming the offending line when it does not have
+    /// three fields, a field fails to parse, or a vertex is out of range.
+    pub fn from_edge_list(vertex_count: usize, text: &str) -> Result<Self, ParseError> {
+        let mut graph = Self::new(vertex_count);
+        for entry in edge_list_lines(text, 3) {
+            let (line, tokens) = entry?;
+            let u = parse_vertex(tokens[0], line, vertex_count)?;
+            let v = parse_vertex(tokens[1], line, vertex_count)?;
+            let weight = tokens[2].parse().map_err(|_| ParseError {
+                line,
+                kind: ParseErrorKind::InvalidToken(tokens[2].to_string()),
+            })?;
+            graph.add_edge(u, v, weight);
+        }
+        Ok(graph)
+    }
+}
+
 impl<W> WeightedDigraph<W>
 where
     W: Copy + Add<Output = W> + Sub<Output = W>,
@@ -91,6 +150,10 @@ where
 }
 
 /// Result of running a shortest-path algorithm.
+///
+/// Shared by `dijkstra`, `bellman_ford`, and `dag_shortest_paths`, which all
+/// start from `initialize_single_source` and update estimates through `relax`.
+/// Unreachable vertices have a `None` distance.
 #[derive(Debug, Clone, PartialEq)]
 pub struct ShortestPathResult<W> {
     pub source: usize,
@@ -98,10 +161,58 @@ pub struct ShortestPathResult<W> {
     pub predecessors: Vec<Option<usize>>,
 }
 
+impl<W> ShortestPathResult<W>
+where
+    W: Copy + PartialOrd + Add<Output = W> + Default,
+{
+    /// Creates the initial estimates of INITIALIZE-SINGLE-SOURCE (CLRS 24).
+    ///
+    /// The source has distance zero and every other vertex is unreached.
+    pub fn initialize_single_source(vertex_count: usize, source: usize) -> Self {
+        let mut distances = vec![None; vertex_count];
+        distances[source] = Some(W::default());
+        Self {
+            source,
+            distances,
+            predecessors: vec![None; vertex_count],
+        }
+    }
+
+    /// Relaxes edge `(u, v)` with the given `weight` (RELAX, CLRS 24).
+    ///
+    /// Returns `true` if the estimate for `v` improved.
+    pub fn relax(&mut self, u: usize, v: usize, weight: W) -> bool {
+        let Some(distance_u) = self.distances[u] else {
+            return false;
+        };
+        let candidate = distance_u + weight;
+
+        let improves = match self.distances[v] {
+            None => true,
+            Some(current) => candidate < current,
+        };
+        if improves {
+            self.distances[v] = Some(candidate);
+            self.predecessors[v] = Some(u);
+        }
+        improves
+    }
+}
+
 impl<W> ShortestPathResult<W>
 where
     W: Copy,
 {
+    /// Returns the shortest-path distance to `v`, or `None` if it is unreachable.
+    pub fn distance(&self, v: usize) -> Option<W> {
+        self.distances.get(v).copied().flatten()
+    }
+
+    /// Returns the predecessor of `v` on its shortest path, if any.
+    pub fn predecessor(&self, v: usize) -> Option<usize> {
+        self.predecessors.get(v).copied().flatten()
+    }
+
     /// Reconstructs the shortest path from the source to `target`, if it exists.
     pub fn path_to(&self, target: usize) -> Option<Vec<usize>> {
         if self.distances.get(target)?.is_none() {
@@ -121,6 +232,30 @@ where
     }
 }
 
+impl<W> ShortestPathResult<W>
+where
+    W: Copy + Sub<Output = W>,
+{
+    /// Builds the shortest-path tree as a graph of predecessor edges.
+    ///
+    /// The tree holds one edge `(predecessor(v), v)` for each reachable
+    /// vertex other than the source, so it has `reachable - 1` edges and every
+    /// reachable vertex is reached from the source along tree edges only.
+    /// Each edge's weight is recovered as `d[v] - d[predecessor(v)]`, which
+    /// equals the weight of the graph edge once the algorithm has finished
+    /// because the final relaxation of `v` set `d[v] = d[u] + w(u, v)`.
+    pub fn shortest_path_tree(&self) -> WeightedDigraph<W> {
+        let mut tree = WeightedDigraph::new(self.distances.len());
+        for (v, &predecessor) in self.predecessors.iter().enumerate() {
+            if let (Some(u), Some(distance_v)) = (predecessor, self.distances[v]) {
+                let distance_u = self.distances[u].expect("a predecessor is reachable");
+                tree.add_edge(u, v, distance_v - distance_u);
+            }
+        }
+        tree
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -139,6 +274,45 @@ mod tests {
         assert_eq!(edges, vec![(0, 1, 5), (0, 2, 2), (1, 2, 1)]);
     }
 
+    #[test]
+    fn from_edge_list_parses_weights() {
+        let text = "0 1 5\n0 2 -2\n\n1 2 1\n";
+        let graph: WeightedDigraph<i32> =
+            WeightedDigraph::from_edge_list(3, text).expect("valid edge list");
+        assert_eq!(graph.edges(), vec![(0, 1, 5), (0, 2, -2), (1, 2, 1)]);
+
+        let err = WeightedDigraph::<i32>::from_edge_list(3, "0 1 5\n1 2 heavy\n").unwrap_err();
+        assert_eq!(err.line, 2);
+        assert_eq!(err.to_string(), "line 2: invalid token `heavy`");
+
+        let err = WeightedDigraph::<i32>::from_edge_list(3, "0 7 1\n").unwrap_err();
+        assert_eq!(
+            err.kind,
+            ParseErrorKind::VertexOutOfRange {
+                vertex: 7,
+                vertex_count: 3
+            }
+        );
+    }
+
+    #[test]
+    fn negative_edge_queries() {
+        let empty: WeightedDigraph<i64> = WeightedDigraph::new(3);
+        assert_eq!(empty.min_edge_weight(), None);
+        assert!(!empty.has_negative_edge());
+
+        let mut graph = WeightedDigraph::new(3);
+        graph.add_edge(0, 1, 4);
+        graph.add_edge(1, 2, 0);
+        graph.add_edge(2, 0, 7);
+        assert_eq!(graph.min_edge_weight(), Some(0));
+        assert!(!graph.has_negative_edge());
+
+        graph.add_edge(2, 2, -3);
+        assert_eq!(graph.min_edge_weight(), Some(-3));
+        assert!(graph.has_negative_edge());
+    }
+
     #[test]
     fn reweight_applies_potentials() {
         let mut graph = WeightedDigraph::new(2);
@@ -148,4 +322,48 @@ mod tests {
         let reweighted = graph.reweight(&[1, 4]);
         assert_eq!(reweighted.edges(), vec![(0, 1, 0), (1, 0, 4)]);
     }
+
+    #[test]
+    fn relax_updates_only_on_improvement() {
+        let mut result: ShortestPathResult<i32> =
+            ShortestPathResult::initialize_single_source(3, 0);
+        assert_eq!(result.distance(0), Some(0));
+        assert_eq!(result.distance(1), None);
+
+        assert!(!result.relax(1, 2, 1)); // 1 is unreached
+        assert!(result.relax(0, 1, 5));
+        assert!(!result.relax(0, 1, 5));
+        assert!(result.relax(0, 1, 3));
+        assert_eq!(result.distance(1), Some(3));
+        assert_eq!(result.predecessor(1), Some(0));
+        assert_eq!(result.predecessor(0), None);
+    }
+
+    #[test]
+    fn all_algorithms_agree_on_dag() {
+        use crate::chapter_24::{bellman_ford, dag_shortest_paths, dijkstra};
+
+        let mut graph = WeightedDigraph::new(7);
+        graph.add_edge(0, 1, 4);
+        graph.add_edge(0, 2, 1);
+        graph.add_edge(2, 1, 2);
+        graph.add_edge(1, 3, 1);
+        graph.add_edge(2, 3, 5);
+        graph.add_edge(3, 4, 3);
+        graph.add_edge(2, 4, 9);
+        graph.add_edge(5, 4, 1); // 5 is unreachable from 0; 6 is isolated
+
+        let from_dijkstra = dijkstra(&graph, 0).expect("non-negative weights");
+        let from_bellman_ford = bellman_ford(&graph, 0).expect("no negative cycle");
+        let from_dag = dag_shortest_paths(&graph, 0).expect("graph is a DAG");
+
+        assert_eq!(from_dijkstra, from_bellman_ford);
+        assert_eq!(from_dijkstra, from_dag);
+
+        assert_eq!(from_dag.distance(4), Some(7));
+        assert_eq!(from_dag.predecessor(4), Some(3));
+        assert_eq!(from_dag.path_to(4), Some(vec![0, 2, 1, 3, 4]));
+        assert_eq!(from_dag.distance(5), None);
+        assert_eq!(from_dag.path_to(6), None);
+    }
 }
@@ -0,0 +1,118 @@
+use std::ops::{Add, Sub};
+
+use super::{floyd_warshall, johnson, JohnsonError, MatrixGraph, NegativeCycle};
+use crate::chapter_24::WeightedDigraph;
+
+/// Shortest-path weights `d[i][j]`, with `None` for unreachable pairs.
+pub type DistanceMatrix<W> = Vec<Vec<Option<W>>>;
+
+/// The algorithm chosen by [`all_pairs_shortest_paths`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AllPairsAlgorithm {
+    Johnson,
+    FloydWarshall,
+}
+
+/// Computes all-pairs shortest paths, picking the algorithm from the edge density.
+///
+/// Johnson's algorithm runs in O(V E lg V) and Floyd-Warshall in O(V^3), so
+/// Johnson's is used when `E lg V < V^2` and Floyd-Warshall otherwise. Both
+/// produce the same distance matrix; the chosen algorithm is returned with it.
+pub fn all_pairs_shortest_paths<W>(
+    graph: &MatrixGraph<W>,
+) -> Result<(AllPairsAlgorithm, DistanceMatrix<W>), NegativeCycle>
+where
+    W: Copy + Ord + Add<Output = W> + Sub<Output = W> + Default,
+{
+    let n = graph.vertex_count();
+    let sparse = to_weighted_digraph(graph);
+    let edge_count = sparse.edges().len();
+    let lg_n = n.max(2).ilog2() as usize;
+
+    if edge_count * lg_n < n * n {
+        match johnson(&sparse) {
+            Ok(distances) => Ok((AllPairsAlgorithm::Johnson, distances)),
+            // Johnson's algorithm does not say where the cycle is, so let
+            // Floyd-Warshall find a vertex on it.
+            Err(JohnsonError::NegativeCycle) => {
+                floyd_warshall(graph).map(|distances| (AllPairsAlgorithm::FloydWarshall, distances))
+            }
+        }
+    } else {
+        floyd_warshall(graph).map(|distances| (AllPairsAlgorithm::FloydWarshall, distances))
+    }
+}
+
+/// Converts the matrix into adjacency lists, dropping the implicit zero-weight
+/// self loops on the diagonal.
+fn to_weighted_digraph<W>(graph: &MatrixGraph<W>) -> WeightedDigraph<W>
+where
+    W: Copy + PartialEq + Default,
+{
+    let n = graph.vertex_count();
+    let mut digraph = WeightedDigraph::new(n);
+    for (u, row) in graph.weights().iter().enumerate() {
+        for (v, weight) in row.iter().enumerate() {
+            match *weight {
+                Some(weight) if u != v || weight != W::default() => digraph.add_edge(u, v, weight),
+                _ => {}
+            }
+        }
+    }
+    digraph
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn dispatcher_matches_both_algorithms() {
+        // CLRS Figure 25.4: 9 edges on 5 vertices is sparse.
+        let mut sparse: MatrixGraph<i64> = MatrixGraph::new(5);
+        for &(u, v, w) in &[
+            (0, 1, 3),
+            (0, 2, 8),
+            (0, 3, -4),
+            (1, 3, 7),
+            (1, 2, 1),
+            (2, 1, 4),
+            (3, 2, -5),
+            (3, 4, 2),
+            (4, 1, 6),
+        ] {
+            sparse.set_edge(u, v, w);
+        }
+
+        let (algorithm, distances) = all_pairs_shortest_paths(&sparse).expect("no cycle");
+        assert_eq!(algorithm, AllPairsAlgorithm::Johnson);
+        assert_eq!(distances, floyd_warshall(&sparse).unwrap());
+        assert_eq!(distances, johnson(&to_weighted_digraph(&sparse)).unwrap());
+
+        // A complete digraph is dense.
+        let mut dense: MatrixGraph<i64> = MatrixGraph::new(5);
+        for u in 0..5 {
+            for v in 0..5 {
+                if u != v {
+                    dense.set_edge(u, v, ((u * 7 + v * 3) % 11) as i64 + 1);
+                }
+            }
+        }
+
+        let (algorithm, distances) = all_pairs_shortest_paths(&dense).expect("no cycle");
+        assert_eq!(algorithm, AllPairsAlgorithm::FloydWarshall);
+        assert_eq!(distances, floyd_warshall(&dense).unwrap());
+        assert_eq!(distances, johnson(&to_weighted_digraph(&dense)).unwrap());
+    }
+
+    #[test]
+    fn dispatcher_reports_negative_cycle() {
+        let mut graph: MatrixGraph<i64> = MatrixGraph::new(6);
+        graph.set_edge(0, 1, 1);
+        graph.set_edge(1, 2, -1);
+        graph.set_edge(2, 0, -1);
+
+        let err = all_pairs_shortest_paths(&graph).unwrap_err();
+        assert!(err.vertex < 3);
+    }
+}
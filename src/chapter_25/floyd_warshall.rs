@@ -1,9 +1,30 @@
+use std::fmt;
 use std::ops::Add;
 
 use super::MatrixGraph;
 
+/// Error reported when the graph contains a negative-weight cycle.
+///
+/// `vertex` lies on such a cycle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NegativeCycle {
+    pub vertex: usize,
+}
+
+impl fmt::Display for NegativeCycle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "negative-weight cycle through vertex {}", self.vertex)
+    }
+}
+
+impl std::error::Error for NegativeCycle {}
+
 /// Runs the Floyd-Warshall algorithm to compute all-pairs shortest paths.
-pub fn floyd_warshall<W>(graph: &MatrixGraph<W>) -> Vec<Vec<Option<W>>>
+///
+/// A negative-weight cycle shows up as a negative diagonal entry `D[i][i]`
+/// once the algorithm completes (CLRS Exercise 25.2-6); in that case the
+/// first such `i` is returned in the error.
+pub fn floyd_warshall<W>(graph: &MatrixGraph<W>) -> Result<Vec<Vec<Option<W>>>, NegativeCycle>
 where
     W: Copy + PartialOrd + Add<Output = W> + Default,
 {
@@ -25,7 +46,12 @@ where
         }
     }
 
-    dist
+    let zero = W::default();
+    if let Some(vertex) = (0..n).find(|&i| dist[i][i].is_some_and(|d| d < zero)) {
+        return Err(NegativeCycle { vertex });
+    }
+
+    Ok(dist)
 }
 
 #[cfg(test)]
@@ -40,7 +66,7 @@ mod tests {
         graph.set_edge(1, 2, 3);
         graph.set_edge(2, 0, 2);
 
-        let result = floyd_warshall(&graph);
+        let result = floyd_warshall(&graph).expect("no negative cycle");
 
         assert_eq!(
             result,
@@ -51,4 +77,22 @@ mod tests {
             ]
         );
     }
+
+    #[test]
+    fn floyd_warshall_detects_negative_cycle() {
+        let mut graph: MatrixGraph<i64> = MatrixGraph::new(3);
+        graph.set_edge(0, 1, 1);
+        graph.set_edge(1, 2, -1);
+        graph.set_edge(2, 0, -1);
+
+        let err = floyd_warshall(&graph).unwrap_err();
+        assert!(err.vertex < 3);
+        assert_eq!(err, NegativeCycle { vertex: 0 });
+
+        // The same triangle with a non-negative total weight is fine.
+        graph.set_edge(2, 0, 0);
+        let dist = floyd_warshall(&graph).expect("no negative cycle");
+        assert_eq!(dist[0][2], Some(0));
+        assert_eq!(dist[2][1], Some(1));
+    }
 }
@@ -1,11 +1,11 @@
 use std::ops::{Add, Sub};
 
-use super::MatrixGraph;
+use super::{DistanceMatrix, MatrixGraph};
 use crate::chapter_24::{
     bellman_ford,
     bellman_ford::BellmanFordError,
-    dijkstra,
     dijkstra::DijkstraError,
+    dijkstra_fib,
     weighted_digraph::{ShortestPathResult, WeightedDigraph},
 };
 
@@ -15,7 +15,30 @@ pub enum JohnsonError {
 }
 
 /// Runs Johnson's algorithm to compute all-pairs shortest paths on a sparse graph.
+///
+/// Bellman-Ford on the graph extended with a super-source yields the
+/// potentials `h` that make every edge weight non-negative, after which
+/// [`dijkstra_fib`] runs once from each vertex of the reweighted graph, for
+/// O(V² lg V + VE) time in total.
 pub fn johnson<W>(graph: &WeightedDigraph<W>) -> Result<Vec<Vec<Option<W>>>, JohnsonError>
+where
+    W: Copy + Ord + PartialOrd + Add<Output = W> + Sub<Output = W> + Default,
+{
+    johnson_counted(graph).map(|(distances, _)| distances)
+}
+
+/// Runs Johnson's algorithm and reports how many times it ran Dijkstra's
+/// algorithm, which is `|V|` whenever no negative cycle is found.
+pub fn johnson_num_dijkstra_calls<W>(graph: &WeightedDigraph<W>) -> Result<usize, JohnsonError>
+where
+    W: Copy + Ord + PartialOrd + Add<Output = W> + Sub<Output = W> + Default,
+{
+    johnson_counted(graph).map(|(_, calls)| calls)
+}
+
+fn johnson_counted<W>(
+    graph: &WeightedDigraph<W>,
+) -> Result<(DistanceMatrix<W>, usize), JohnsonError>
 where
     W: Copy + Ord + PartialOrd + Add<Output = W> + Sub<Output = W> + Default,
 {
@@ -44,16 +67,18 @@ where
     let reweighted = graph.reweight(&h);
 
     let mut distances = vec![vec![None; n]; n];
+    let mut dijkstra_calls = 0;
     for u in 0..n {
-        let result = dijkstra(&reweighted, u).map_err(|err| match err {
+        let result = dijkstra_fib(&reweighted, u).map_err(|err| match err {
             DijkstraError::NegativeEdgeWeight => {
                 unreachable!("reweighting guarantees non-negative edges")
             }
         })?;
+        dijkstra_calls += 1;
         convert_distances(u, &h, &result, &mut distances);
     }
 
-    Ok(distances)
+    Ok((distances, dijkstra_calls))
 }
 
 fn convert_distances<W>(
@@ -146,4 +171,42 @@ mod tests {
         let result = johnson(&graph);
         assert_eq!(result, Err(JohnsonError::NegativeCycle));
     }
+
+    #[test]
+    fn johnson_runs_dijkstra_once_per_vertex() {
+        use rand::rngs::StdRng;
+        use rand::{Rng, SeedableRng};
+
+        use crate::chapter_24::dijkstra;
+        use crate::chapter_25::floyd_warshall;
+
+        let mut rng = StdRng::seed_from_u64(25);
+        for _ in 0..20 {
+            let n = rng.gen_range(1..25);
+            let mut graph = WeightedDigraph::new(n);
+            let mut matrix = MatrixGraph::new(n);
+            // Edges only run from lower to higher vertices, so negative
+            // weights cannot close a cycle.
+            for _ in 0..3 * n {
+                let u = rng.gen_range(0..n);
+                let v = rng.gen_range(0..n);
+                if u < v && matrix.weights()[u][v].is_none() {
+                    let weight = rng.gen_range(-10..20);
+                    graph.add_edge(u, v, weight);
+                    matrix.set_edge(u, v, weight);
+                }
+            }
+
+            let distances = johnson(&graph).expect("graph is acyclic");
+            assert_eq!(floyd_warshall(&matrix).ok(), Some(distances.clone()));
+            assert_eq!(johnson_num_dijkstra_calls(&graph), Ok(n));
+
+            // Rows for non-negative graphs agree with plain Dijkstra as well.
+            if graph.edges().iter().all(|&(_, _, w)| w >= 0) {
+                for (u, row) in distances.iter().enumerate() {
+                    assert_eq!(row, &dijkstra(&graph, u).unwrap().distances);
+                }
+            }
+        }
+    }
 }
@@ -35,6 +35,36 @@ where
         self.weights[u][v] = Some(weight);
     }
 
+    /// Removes the directed edge `(u, v)`, returning its weight if it existed.
+    ///
+    /// Removing a self loop `(u, u)` restores the default zero-weight entry on
+    /// the diagonal, matching a freshly created vertex.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `u` or `v` are out of bounds.
+    pub fn remove_edge(&mut self, u: usize, v: usize) -> Option<W> {
+        assert!(u < self.vertex_count(), "vertex {} out of bounds", u);
+        assert!(v < self.vertex_count(), "vertex {} out of bounds", v);
+        let replacement = if u == v { Some(W::default()) } else { None };
+        std::mem::replace(&mut self.weights[u][v], replacement)
+    }
+
+    /// Appends a new vertex with no incident edges and returns its index.
+    ///
+    /// Every existing row gains a `None` column, and the new row is `None`
+    /// except for a zero-weight self loop, so the matrix stays square.
+    pub fn add_vertex(&mut self) -> usize {
+        let index = self.vertex_count();
+        for row in &mut self.weights {
+            row.push(None);
+        }
+        let mut row = vec![None; index + 1];
+        row[index] = Some(W::default());
+        self.weights.push(row);
+        index
+    }
+
     /// Returns a reference to the weight matrix.
     pub fn weights(&self) -> &[Vec<Option<W>>] {
         &self.weights
@@ -71,4 +101,36 @@ mod tests {
             ]
         );
     }
+
+    #[test]
+    fn add_vertex_and_edges() {
+        use crate::chapter_25::floyd_warshall;
+
+        let mut graph: MatrixGraph<i64> = MatrixGraph::new(2);
+        graph.set_edge(0, 1, 4);
+
+        assert_eq!(graph.add_vertex(), 2);
+        assert_eq!(graph.add_vertex(), 3);
+        assert_eq!(graph.vertex_count(), 4);
+        assert!(graph.weights().iter().all(|row| row.len() == 4));
+        assert_eq!(graph.weights()[3], vec![None, None, None, Some(0)]);
+
+        graph.set_edge(1, 3, 5);
+        graph.set_edge(0, 2, 1);
+        graph.set_edge(2, 3, 2);
+        graph.set_edge(3, 0, -1);
+
+        let dist = floyd_warshall(&graph).expect("no negative cycle");
+        assert_eq!(dist[0][3], Some(3));
+        assert_eq!(dist[1][2], Some(5));
+
+        assert_eq!(graph.remove_edge(2, 3), Some(2));
+        assert_eq!(graph.remove_edge(2, 3), None);
+        let dist = floyd_warshall(&graph).expect("no negative cycle");
+        assert_eq!(dist[0][3], Some(9));
+        assert_eq!(dist[2][3], None);
+
+        assert_eq!(graph.remove_edge(1, 1), Some(0));
+        assert_eq!(graph.weights()[1][1], Some(0));
+    }
 }
@@ -3,11 +3,13 @@
 //! This module collects dynamic-programming and reweighting techniques for
 //! computing shortest paths between every pair of vertices.
 
+pub mod all_pairs;
 pub mod floyd_warshall;
 pub mod johnson;
 pub mod matrix_graph;
 pub mod transitive_closure;
 
+pub use all_pairs::*;
 pub use floyd_warshall::*;
 pub use johnson::*;
 pub use matrix_graph::*;
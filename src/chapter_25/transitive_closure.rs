@@ -25,6 +25,23 @@ pub fn transitive_closure(graph: &MatrixGraph<bool>) -> Vec<Vec<bool>> {
     closure
 }
 
+/// Lists, for each vertex, the vertices reachable from it.
+///
+/// Entry `v` is the sorted list of every `u` with a path from `v` to `u`,
+/// including `v` itself. The lists are read off the rows of the
+/// [`transitive_closure`] matrix.
+pub fn reachable_sets(graph: &MatrixGraph<bool>) -> Vec<Vec<usize>> {
+    transitive_closure(graph)
+        .into_iter()
+        .map(|row| {
+            row.into_iter()
+                .enumerate()
+                .filter_map(|(u, reachable)| reachable.then_some(u))
+                .collect()
+        })
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -47,4 +64,23 @@ mod tests {
             ]
         );
     }
+
+    #[test]
+    fn reachable_sets_on_chain() {
+        let mut graph: MatrixGraph<bool> = MatrixGraph::new(4);
+        graph.set_edge(0, 1, true);
+        graph.set_edge(1, 2, true);
+        graph.set_edge(2, 3, true);
+
+        let sets = reachable_sets(&graph);
+        assert_eq!(sets[0], vec![0, 1, 2, 3]);
+        assert_eq!(sets[1], vec![1, 2, 3]);
+        assert_eq!(sets[3], vec![3]);
+
+        // Closing the cycle makes every vertex reach every other.
+        graph.set_edge(3, 0, true);
+        assert!(reachable_sets(&graph)
+            .iter()
+            .all(|set| *set == vec![0, 1, 2, 3]));
+    }
 }
@@ -0,0 +1,162 @@
+use std::collections::VecDeque;
+use std::ops::{Add, AddAssign, Sub, SubAssign};
+
+use super::FlowNetwork;
+
+/// Computes the maximum flow using Dinic's blocking-flow algorithm.
+///
+/// Each phase runs a BFS from the source to label vertices with their
+/// residual distance, keeps only the edges that go from one level to the
+/// next, and saturates that level graph with a blocking flow found by
+/// repeated DFS. A per-vertex cursor skips edges already found to be useless
+/// in the phase, so a blocking flow takes O(VE) time. The source-sink
+/// distance grows every phase, which bounds the number of phases by V - 1 and
+/// the total running time by O(V^2 E), compared with O(V E^2) for
+/// [`edmonds_karp`](super::edmonds_karp).
+pub fn dinic<W>(network: &mut FlowNetwork<W>, source: usize, sink: usize) -> W
+where
+    W: Copy + Ord + Default + AddAssign + SubAssign + Add<Output = W> + Sub<Output = W>,
+{
+    assert!(source < network.vertex_count(), "source out of bounds");
+    assert!(sink < network.vertex_count(), "sink out of bounds");
+    assert!(source != sink, "source and sink must differ");
+
+    let mut max_flow = W::default();
+    while let Some(level) = level_graph(network, source, sink) {
+        let mut cursor = vec![0; network.vertex_count()];
+        while let Some(pushed) = blocking_path(network, source, sink, None, &level, &mut cursor) {
+            max_flow += pushed;
+        }
+    }
+    max_flow
+}
+
+/// Labels each vertex with its BFS distance from `source` in the residual
+/// network, or returns `None` when the sink is unreachable.
+fn level_graph<W>(network: &FlowNetwork<W>, source: usize, sink: usize) -> Option<Vec<usize>>
+where
+    W: Copy + PartialOrd + Default + Sub<Output = W>,
+{
+    let mut level = vec![usize::MAX; network.vertex_count()];
+    let mut queue = VecDeque::new();
+    level[source] = 0;
+    queue.push_back(source);
+
+    while let Some(u) = queue.pop_front() {
+        for &edge_index in network.adjacency(u) {
+            let to = network.edges()[edge_index].to;
+            if level[to] == usize::MAX && network.residual_capacity(edge_index) > W::default() {
+                level[to] = level[u] + 1;
+                queue.push_back(to);
+            }
+        }
+    }
+
+    (level[sink] != usize::MAX).then_some(level)
+}
+
+/// Pushes flow along one level-graph path from `u` to `sink`, limited by
+/// `limit` (`None` meaning unbounded), and returns the amount pushed.
+fn blocking_path<W>(
+    network: &mut FlowNetwork<W>,
+    u: usize,
+    sink: usize,
+    limit: Option<W>,
+    level: &[usize],
+    cursor: &mut [usize],
+) -> Option<W>
+where
+    W: Copy + Ord + Default + AddAssign + SubAssign + Sub<Output = W>,
+{
+    if u == sink {
+        return limit;
+    }
+
+    while cursor[u] < network.adjacency(u).len() {
+        let edge_index = network.adjacency(u)[cursor[u]];
+        let to = network.edges()[edge_index].to;
+        let residual = network.residual_capacity(edge_index);
+        if level[to] == level[u] + 1 && residual > W::default() {
+            let bound = limit.map_or(residual, |limit| limit.min(residual));
+            if let Some(pushed) = blocking_path(network, to, sink, Some(bound), level, cursor) {
+                network.augment_edge(edge_index, pushed);
+                return Some(pushed);
+            }
+        }
+        // The edge is saturated or leads to a dead end for this phase.
+        cursor[u] += 1;
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::chapter_26::edmonds_karp;
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+
+    fn random_network(rng: &mut StdRng, n: usize, edge_count: usize) -> FlowNetwork<i64> {
+        let mut network = FlowNetwork::new(n);
+        for _ in 0..edge_count {
+            network.add_edge(
+                rng.gen_range(0..n),
+                rng.gen_range(0..n),
+                rng.gen_range(1..100),
+            );
+        }
+        network
+    }
+
+    #[test]
+    fn dinic_example() {
+        // CLRS Figure 26.1
+        let mut network: FlowNetwork<i32> = FlowNetwork::new(6);
+        network.add_edge(0, 1, 16);
+        network.add_edge(0, 2, 13);
+        network.add_edge(1, 2, 10);
+        network.add_edge(1, 3, 12);
+        network.add_edge(2, 1, 4);
+        network.add_edge(2, 4, 14);
+        network.add_edge(3, 2, 9);
+        network.add_edge(3, 5, 20);
+        network.add_edge(4, 3, 7);
+        network.add_edge(4, 5, 4);
+
+        assert_eq!(dinic(&mut network, 0, 5), 23);
+        // The flow left in the network is a valid maximum flow.
+        let out_of_source: i32 = network
+            .adjacency(0)
+            .iter()
+            .map(|&e| network.edges()[e].flow)
+            .sum();
+        assert_eq!(out_of_source, 23);
+    }
+
+    #[test]
+    fn dinic_matches_edmonds_karp_on_random_networks() {
+        let mut rng = StdRng::seed_from_u64(9);
+        for _ in 0..100 {
+            let n = rng.gen_range(2..15);
+            let edge_count = rng.gen_range(0..5 * n);
+            let network = random_network(&mut rng, n, edge_count);
+            let source = rng.gen_range(0..n);
+            let sink = (source + rng.gen_range(1..n)) % n;
+            assert_eq!(
+                dinic(&mut network.clone(), source, sink),
+                edmonds_karp(&mut network.clone(), source, sink)
+            );
+        }
+    }
+
+    #[test]
+    fn dinic_on_dense_network() {
+        let mut rng = StdRng::seed_from_u64(90);
+        let n = 150;
+        let network = random_network(&mut rng, n, n * n / 2);
+        assert_eq!(
+            dinic(&mut network.clone(), 0, n - 1),
+            edmonds_karp(&mut network.clone(), 0, n - 1)
+        );
+    }
+}
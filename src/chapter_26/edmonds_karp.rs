@@ -1,4 +1,3 @@
-use std::cmp::min;
 use std::collections::VecDeque;
 use std::ops::{Add, AddAssign, Sub, SubAssign};
 
@@ -16,22 +15,7 @@ where
     let mut max_flow = W::default();
 
     while let Some(path) = bfs(network, source, sink) {
-        let mut residual_capacity = W::default();
-        let mut first = true;
-        for edge_index in &path {
-            let capacity = network.residual_capacity(*edge_index);
-            residual_capacity = if first {
-                first = false;
-                capacity
-            } else {
-                min(residual_capacity, capacity)
-            };
-        }
-
-        max_flow += residual_capacity;
-        for edge_index in path {
-            network.augment_edge(edge_index, residual_capacity);
-        }
+        max_flow += network.augment_path(&path);
     }
 
     max_flow
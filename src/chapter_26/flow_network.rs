@@ -88,6 +88,75 @@ where
         self.edges[edge_index].flow += amount;
         self.edges[reverse_index].flow -= amount;
     }
+
+    /// Pushes the bottleneck residual capacity along `path` and returns it.
+    ///
+    /// `path` lists edge indices from source to sink; an empty path carries
+    /// no flow.
+    pub fn augment_path(&mut self, path: &[usize]) -> W
+    where
+        W: Ord + std::ops::Sub<Output = W> + std::ops::AddAssign + std::ops::SubAssign,
+    {
+        let Some(bottleneck) = path.iter().map(|&edge| self.residual_capacity(edge)).min() else {
+            return W::default();
+        };
+        for &edge_index in path {
+            self.augment_edge(edge_index, bottleneck);
+        }
+        bottleneck
+    }
+}
+
+/// Builds a flow network whose vertices, not only edges, have capacities.
+///
+/// Vertex `v` has capacity `vertex_caps[v]`, where a negative entry means
+/// unlimited. Each capacitated vertex is split in two: `v` itself keeps the
+/// incoming edges and a new vertex `n + k` takes the outgoing ones, joined by
+/// an edge of the vertex's capacity. The returned mapping gives, for every
+/// original vertex, the vertex its outgoing edges now leave from (`v` itself
+/// when it was not split). A maximum flow from `s` to `t` that respects every
+/// vertex capacity, including those of `s` and `t`, runs from `s` to
+/// `mapping[t]`.
+///
+/// # Panics
+/// Panics if an edge has an endpoint outside `0..vertex_caps.len()`.
+///
+/// # Example
+/// ```
+/// use clrs::chapter_26::{edmonds_karp, with_vertex_capacities};
+/// // Two routes from 0 to 3, both passing through vertex 1, which caps
+/// // the flow at 4 although the edges would carry 20.
+/// let edges = [(0, 1, 10), (0, 2, 10), (2, 1, 10), (1, 3, 20)];
+/// let (mut network, mapping) = with_vertex_capacities(&edges, &[-1, 4, -1, -1]);
+/// assert_eq!(mapping, vec![0, 4, 2, 3]);
+/// assert_eq!(edmonds_karp(&mut network, 0, mapping[3]), 4);
+/// ```
+pub fn with_vertex_capacities(
+    edges: &[(usize, usize, i64)],
+    vertex_caps: &[i64],
+) -> (FlowNetwork<i64>, Vec<usize>) {
+    let vertex_count = vertex_caps.len();
+    let mut mapping: Vec<usize> = (0..vertex_count).collect();
+    let mut split_count = 0;
+    for (v, &cap) in vertex_caps.iter().enumerate() {
+        if cap >= 0 {
+            mapping[v] = vertex_count + split_count;
+            split_count += 1;
+        }
+    }
+
+    let mut network = FlowNetwork::new(vertex_count + split_count);
+    for (v, &cap) in vertex_caps.iter().enumerate() {
+        if cap >= 0 {
+            network.add_edge(v, mapping[v], cap);
+        }
+    }
+    for &(u, v, capacity) in edges {
+        assert!(u < vertex_count, "vertex {} out of bounds", u);
+        assert!(v < vertex_count, "vertex {} out of bounds", v);
+        network.add_edge(mapping[u], v, capacity);
+    }
+    (network, mapping)
 }
 
 impl<W> fmt::Debug for FlowNetwork<W>
@@ -122,4 +191,45 @@ mod tests {
         assert_eq!(network.edges()[forward].reverse, reverse);
         assert_eq!(network.edges()[reverse].reverse, forward);
     }
+
+    #[test]
+    fn vertex_capacity_limits_max_flow() {
+        use crate::chapter_26::edmonds_karp;
+
+        // Edge capacities allow 20 units through vertex 1 and one more along
+        // 0 -> 2 -> 4 -> 3; a capacity of 4 on vertex 1 leaves 4 + 1.
+        let edges = [
+            (0, 1, 15),
+            (0, 2, 15),
+            (2, 1, 15),
+            (1, 3, 20),
+            (2, 4, 1),
+            (4, 3, 1),
+        ];
+
+        let mut plain = FlowNetwork::new(5);
+        for &(u, v, capacity) in &edges {
+            plain.add_edge(u, v, capacity);
+        }
+        assert_eq!(edmonds_karp(&mut plain, 0, 3), 21);
+
+        let (mut network, mapping) = with_vertex_capacities(&edges, &[-1, 4, -1, -1, -1]);
+        assert_eq!(mapping, vec![0, 5, 2, 3, 4]);
+        assert_eq!(network.vertex_count(), 6);
+        assert_eq!(edmonds_karp(&mut network, 0, mapping[3]), 5);
+
+        // Capacities on the source and sink limit everything passing them.
+        let (mut network, mapping) = with_vertex_capacities(&edges, &[3, -1, -1, -1, -1]);
+        assert_eq!(edmonds_karp(&mut network, 0, mapping[3]), 3);
+        let (mut network, mapping) = with_vertex_capacities(&edges, &[-1, -1, -1, 7, -1]);
+        assert_eq!(edmonds_karp(&mut network, 0, mapping[3]), 7);
+    }
+
+    #[test]
+    #[should_panic(expected = "vertex 2 out of bounds")]
+    fn vertex_capacities_reject_edge_into_split_vertex() {
+        // Vertex 2 exists in the expanded network as the out-node of vertex
+        // 0, but is not one of the two original vertices.
+        with_vertex_capacities(&[(1, 2, 5)], &[3, -1]);
+    }
 }
@@ -0,0 +1,150 @@
+use std::ops::{Add, AddAssign, Sub, SubAssign};
+
+use super::FlowNetwork;
+
+/// Computes the maximum flow using Ford-Fulkerson with depth-first search.
+///
+/// This is the basic FORD-FULKERSON method, taking whichever augmenting path
+/// a depth-first search of the residual network finds first. With integer
+/// capacities each augmentation raises the flow by at least 1, so the method
+/// terminates after at most |f*| iterations and agrees with
+/// [`edmonds_karp`](super::edmonds_karp). That bound depends on the flow
+/// value rather than the graph size, however: on networks such as CLRS
+/// Figure 26.7, where a unit-capacity edge joins two high-capacity paths, an
+/// unlucky search can keep augmenting across that edge by 1 unit at a time
+/// and need millions of iterations. Edmonds-Karp's shortest augmenting paths
+/// avoid this and take O(VE) iterations.
+pub fn ford_fulkerson_dfs<W>(network: &mut FlowNetwork<W>, source: usize, sink: usize) -> W
+where
+    W: Copy + Ord + Default + AddAssign + SubAssign + Add<Output = W> + Sub<Output = W>,
+{
+    assert!(source < network.vertex_count(), "source out of bounds");
+    assert!(sink < network.vertex_count(), "sink out of bounds");
+    assert!(source != sink, "source and sink must differ");
+
+    let mut max_flow = W::default();
+    while let Some(path) = dfs(network, source, sink) {
+        max_flow += network.augment_path(&path);
+    }
+    max_flow
+}
+
+/// Finds a source-to-sink path of positive residual capacity by iterative DFS.
+fn dfs<W>(network: &FlowNetwork<W>, source: usize, sink: usize) -> Option<Vec<usize>>
+where
+    W: Copy + PartialOrd + Default + Sub<Output = W>,
+{
+    let mut parent_edge: Vec<Option<usize>> = vec![None; network.vertex_count()];
+    let mut visited = vec![false; network.vertex_count()];
+    // Vertices are marked when popped, not when pushed, so the search goes as
+    // deep as possible along the first residual edge of each vertex.
+    let mut stack = vec![(source, None)];
+
+    while let Some((u, via)) = stack.pop() {
+        if visited[u] {
+            continue;
+        }
+        visited[u] = true;
+        parent_edge[u] = via;
+        if u == sink {
+            break;
+        }
+        // Push in reverse so that neighbours are explored in adjacency order.
+        for &edge_index in network.adjacency(u).iter().rev() {
+            let to = network.edges()[edge_index].to;
+            if !visited[to] && network.residual_capacity(edge_index) > W::default() {
+                stack.push((to, Some(edge_index)));
+            }
+        }
+    }
+
+    if !visited[sink] {
+        return None;
+    }
+
+    let mut path = Vec::new();
+    let mut current = sink;
+    while current != source {
+        let edge_index = parent_edge[current].expect("visited vertices have a parent edge");
+        path.push(edge_index);
+        current = network.edges()[network.edges()[edge_index].reverse].to;
+    }
+    path.reverse();
+    Some(path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::chapter_26::edmonds_karp;
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+
+    fn network_from(vertex_count: usize, edges: &[(usize, usize, i64)]) -> FlowNetwork<i64> {
+        let mut network = FlowNetwork::new(vertex_count);
+        for &(u, v, capacity) in edges {
+            network.add_edge(u, v, capacity);
+        }
+        network
+    }
+
+    fn assert_agrees(network: &FlowNetwork<i64>, source: usize, sink: usize) -> i64 {
+        let dfs_flow = ford_fulkerson_dfs(&mut network.clone(), source, sink);
+        let bfs_flow = edmonds_karp(&mut network.clone(), source, sink);
+        assert_eq!(dfs_flow, bfs_flow);
+        dfs_flow
+    }
+
+    #[test]
+    fn ford_fulkerson_matches_edmonds_karp() {
+        // CLRS Figure 26.1
+        let clrs = network_from(
+            6,
+            &[
+                (0, 1, 16),
+                (0, 2, 13),
+                (1, 2, 10),
+                (1, 3, 12),
+                (2, 1, 4),
+                (2, 4, 14),
+                (3, 2, 9),
+                (3, 5, 20),
+                (4, 3, 7),
+                (4, 5, 4),
+            ],
+        );
+        assert_eq!(assert_agrees(&clrs, 0, 5), 23);
+
+        // CLRS Figure 26.7: the unit edge 1 -> 2 is DFS's first choice out of
+        // vertex 1, so the first path found is s -> 1 -> 2 -> t.
+        let bad_case = network_from(
+            4,
+            &[
+                (0, 1, 1_000_000),
+                (0, 2, 1_000_000),
+                (1, 2, 1),
+                (1, 3, 1_000_000),
+                (2, 3, 1_000_000),
+            ],
+        );
+        assert_eq!(assert_agrees(&bad_case, 0, 3), 2_000_000);
+
+        let disconnected = network_from(4, &[(0, 1, 5), (2, 3, 5)]);
+        assert_eq!(assert_agrees(&disconnected, 0, 3), 0);
+
+        let mut rng = StdRng::seed_from_u64(26);
+        for _ in 0..50 {
+            let n = rng.gen_range(2..12);
+            let edges: Vec<(usize, usize, i64)> = (0..rng.gen_range(0..4 * n))
+                .map(|_| {
+                    (
+                        rng.gen_range(0..n),
+                        rng.gen_range(0..n),
+                        rng.gen_range(1..30),
+                    )
+                })
+                .collect();
+            assert_agrees(&network_from(n, &edges), 0, n - 1);
+        }
+    }
+}
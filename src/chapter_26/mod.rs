@@ -3,10 +3,14 @@
 //! This module translates the CLRS treatment of flow networks, including
 //! augmenting-path and preflow-push algorithms.
 
+pub mod dinic;
 pub mod edmonds_karp;
 pub mod flow_network;
+pub mod ford_fulkerson;
 pub mod relabel_to_front;
 
+pub use dinic::*;
 pub use edmonds_karp::*;
 pub use flow_network::*;
+pub use ford_fulkerson::*;
 pub use relabel_to_front::*;